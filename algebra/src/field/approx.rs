@@ -0,0 +1,248 @@
+use num_complex::Complex;
+
+/// Equality up to an absolute difference.
+pub trait AbsDiffEq
+{
+    /// The type the tolerance is measured in, which is real for complex scalars.
+    type Epsilon: Copy;
+
+    fn default_epsilon() -> Self::Epsilon;
+
+    /// Whether `|self - other| <= epsilon`.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool;
+}
+
+/// Equality up to a difference relative to the larger magnitude.
+pub trait RelativeEq: AbsDiffEq
+{
+    fn default_max_relative() -> Self::Epsilon;
+
+    /// Whether `|self - other| <= max_relative * max(|self|, |other|)`, or the
+    /// absolute difference is within `epsilon` for values close to zero.
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool;
+}
+
+/// Equality up to a number of representable values in between.
+pub trait UlpsEq: AbsDiffEq
+{
+    fn default_max_ulps() -> u32;
+
+    /// Whether at most `max_ulps` floats lie between the two, or the absolute
+    /// difference is within `epsilon` for values close to zero.
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool;
+}
+
+/// How close two scalars must be to compare equal.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Tolerance<E>
+{
+    Absolute(E),
+    Relative { epsilon: E, max_relative: E },
+    Ulps { epsilon: E, max_ulps: u32 }
+}
+
+impl<E> Tolerance<E>
+{
+    pub fn absolute(epsilon: E) -> Self
+    {
+        Tolerance::Absolute(epsilon)
+    }
+
+    /// A relative tolerance with the default absolute floor of `T`.
+    pub fn relative<T>(max_relative: E) -> Self
+    where
+        T: AbsDiffEq<Epsilon=E>
+    {
+        Tolerance::Relative { epsilon: T::default_epsilon(), max_relative }
+    }
+
+    /// A ULPs tolerance with the default absolute floor of `T`.
+    pub fn ulps<T>(max_ulps: u32) -> Self
+    where
+        T: AbsDiffEq<Epsilon=E>
+    {
+        Tolerance::Ulps { epsilon: T::default_epsilon(), max_ulps }
+    }
+}
+
+/// Approximate equality under any kind of `Tolerance`.
+pub trait ApproxEq: RelativeEq + UlpsEq
+{
+    /// The default relative tolerance of the type.
+    fn default_tolerance() -> Tolerance<Self::Epsilon>
+    {
+        Tolerance::Relative { epsilon: Self::default_epsilon(), max_relative: Self::default_max_relative() }
+    }
+
+    fn approx_eq(&self, other: &Self, tolerance: Tolerance<Self::Epsilon>) -> bool
+    {
+        match tolerance {
+            Tolerance::Absolute(epsilon) => self.abs_diff_eq(other, epsilon),
+            Tolerance::Relative { epsilon, max_relative } => self.relative_eq(other, epsilon, max_relative),
+            Tolerance::Ulps { epsilon, max_ulps } => self.ulps_eq(other, epsilon, max_ulps)
+        }
+    }
+}
+
+impl<T> ApproxEq for T
+where
+    T: RelativeEq + UlpsEq
+{
+}
+
+macro_rules! approx {
+    ($($primitive:ident: $bits:ident),*) => {
+        $(
+            impl AbsDiffEq for $primitive
+            {
+                type Epsilon = $primitive;
+
+                fn default_epsilon() -> Self::Epsilon
+                {
+                    $primitive::EPSILON
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool
+                {
+                    (self - other).abs() <= epsilon
+                }
+            }
+
+            impl RelativeEq for $primitive
+            {
+                fn default_max_relative() -> Self::Epsilon
+                {
+                    $primitive::EPSILON
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool
+                {
+                    // Also catches infinities of the same sign; any other
+                    // infinity is infinitely far away.
+                    if self == other {
+                        return true
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false
+                    }
+                    let difference: $primitive = (self - other).abs();
+                    if difference <= epsilon {
+                        return true
+                    }
+                    difference <= self.abs().max(other.abs()) * max_relative
+                }
+            }
+
+            impl UlpsEq for $primitive
+            {
+                fn default_max_ulps() -> u32
+                {
+                    4
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool
+                {
+                    if self.abs_diff_eq(other, epsilon) {
+                        return true
+                    }
+                    // Floats of opposite signs are only equal as zeros, caught above,
+                    // and an infinity is not one ulp away from `MAX`.
+                    if self.is_infinite() || other.is_infinite() {
+                        return self == other
+                    }
+                    if self.is_nan() || other.is_nan() || self.is_sign_positive() != other.is_sign_positive() {
+                        return false
+                    }
+                    let (l, r): ($bits, $bits) = (self.to_bits(), other.to_bits());
+                    l.max(r) - l.min(r) <= max_ulps as $bits
+                }
+            }
+
+            impl AbsDiffEq for Complex<$primitive>
+            {
+                type Epsilon = $primitive;
+
+                fn default_epsilon() -> Self::Epsilon
+                {
+                    $primitive::EPSILON
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool
+                {
+                    (self - other).norm() <= epsilon
+                }
+            }
+
+            impl RelativeEq for Complex<$primitive>
+            {
+                fn default_max_relative() -> Self::Epsilon
+                {
+                    $primitive::EPSILON
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool
+                {
+                    if self == other {
+                        return true
+                    }
+                    if self.is_infinite() || other.is_infinite() {
+                        return false
+                    }
+                    let difference: $primitive = (self - other).norm();
+                    if difference <= epsilon {
+                        return true
+                    }
+                    difference <= self.norm().max(other.norm()) * max_relative
+                }
+            }
+
+            impl UlpsEq for Complex<$primitive>
+            {
+                fn default_max_ulps() -> u32
+                {
+                    4
+                }
+
+                /// Compares the real and imaginary parts separately.
+                fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool
+                {
+                    self.re.ulps_eq(&other.re, epsilon, max_ulps) && self.im.ulps_eq(&other.im, epsilon, max_ulps)
+                }
+            }
+        )*
+    };
+}
+
+approx!{f32: u32, f64: u64}
+
+/// Asserts that two scalars are approximately equal, by default relative to
+/// their magnitude, printing both on failure.
+#[macro_export]
+macro_rules! assert_approx_eq
+{
+    ($lhs:expr, $rhs:expr) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                fn default_tolerance<T: $crate::ApproxEq>(_: &T) -> $crate::Tolerance<T::Epsilon>
+                {
+                    T::default_tolerance()
+                }
+                $crate::assert_approx_eq!(*lhs, *rhs, default_tolerance(lhs))
+            }
+        }
+    };
+
+    ($lhs:expr, $rhs:expr, $tolerance:expr) => {
+        match (&$lhs, &$rhs) {
+            (lhs, rhs) => {
+                let tolerance = $tolerance;
+                if !$crate::ApproxEq::approx_eq(lhs, rhs, tolerance) {
+                    panic!(
+                        "assertion failed: `(left ≈ right)`\n  left: `{:?}`,\n right: `{:?}`,\n tolerance: `{:?}`",
+                        lhs, rhs, tolerance
+                    )
+                }
+            }
+        }
+    };
+}
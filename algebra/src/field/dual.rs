@@ -0,0 +1,491 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "laws")]
+use crate::laws::Shrink;
+
+use super::{AbelianGroup, AbsDiffEq, AdditiveIdentity, ComplexField, Field, Group, Magma, Monoid, MultiplicativeIdentity, RealField, RelativeEq, Ring, Semigroup, Semiring, UlpsEq, impl_assign_ops, impl_structures, two};
+
+/// A dual number `a + bε` with `ε² = 0`, for forward-mode automatic
+/// differentiation.
+///
+/// Evaluating a function at `x + ε` yields `f(x) + f'(x)ε`, so any pipeline
+/// generic over its scalar, such as the ops of a `vspace!`, is differentiated
+/// by running it over `Dual<F>`. Dual numbers form a ring but not a field:
+/// division is only defined when the divisor has a non-zero value.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Dual<F>
+{
+    pub value: F,
+    pub derivative: F
+}
+
+/// A hyper-dual number `a + bε₁ + cε₂ + dε₁ε₂` with `ε₁² = ε₂² = 0`, which
+/// carries exact second derivatives.
+///
+/// Evaluating a function at `x + ε₁ + ε₂` yields `f'(x)` in both first parts
+/// and `f''(x)` in the `ε₁ε₂` part; seeding different variables in `ε₁` and
+/// `ε₂` yields a mixed partial derivative, as `hessian` does.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct HyperDual<F>
+{
+    pub value: F,
+    pub first: F,
+    pub second: F,
+    pub mixed: F
+}
+
+impl<F> Dual<F>
+where
+    F: Ring + Copy
+{
+    pub fn new(value: F, derivative: F) -> Self
+    {
+        Dual { value, derivative }
+    }
+
+    /// A constant, whose derivative vanishes.
+    pub fn constant(value: F) -> Self
+    {
+        Dual::new(value, F::additive_identity())
+    }
+
+    /// The variable being differentiated by, with unit derivative.
+    pub fn variable(value: F) -> Self
+    {
+        Dual::new(value, F::multiplicative_identity())
+    }
+
+    /// Applies a function with value `f` and derivative `df` at `self.value`
+    /// by the chain rule.
+    fn chain(self, f: F, df: F) -> Self
+    {
+        Dual::new(f, df * self.derivative)
+    }
+}
+
+impl<F> Dual<F>
+where
+    F: ComplexField
+{
+    pub fn exp(self) -> Self
+    {
+        let exp: F = self.value.exp();
+        self.chain(exp, exp)
+    }
+
+    /// The principal square root, whose derivative diverges at zero.
+    pub fn sqrt(self) -> Self
+    {
+        let sqrt: F = self.value.sqrt();
+        self.chain(sqrt, F::multiplicative_identity() / (two::<F>() * sqrt))
+    }
+
+    /// Conjugates both parts, as the derivative is taken by a real parameter.
+    pub fn conjugate(self) -> Self
+    {
+        Dual::new(self.value.conjugate(), self.derivative.conjugate())
+    }
+
+    pub fn real(self) -> Dual<F::Real>
+    {
+        Dual::new(self.value.real(), self.derivative.real())
+    }
+}
+
+impl<R> Dual<R>
+where
+    R: RealField
+{
+    pub fn sin(self) -> Self
+    {
+        self.chain(self.value.sin(), self.value.cos())
+    }
+
+    pub fn cos(self) -> Self
+    {
+        self.chain(self.value.cos(), -self.value.sin())
+    }
+
+    /// The natural logarithm.
+    pub fn ln(self) -> Self
+    {
+        self.chain(self.value.ln(), R::multiplicative_identity() / self.value)
+    }
+
+    /// Embeds a real dual number in a complex scalar, such as a gate angle in
+    /// the amplitudes of a state.
+    pub fn to_complex<C>(self) -> Dual<C>
+    where
+        C: ComplexField<Real=R>
+    {
+        Dual::new(C::from_real(self.value), C::from_real(self.derivative))
+    }
+}
+
+impl<F> HyperDual<F>
+where
+    F: Ring + Copy
+{
+    pub fn new(value: F, first: F, second: F, mixed: F) -> Self
+    {
+        HyperDual { value, first, second, mixed }
+    }
+
+    pub fn constant(value: F) -> Self
+    {
+        let zero: F = F::additive_identity();
+        HyperDual::new(value, zero, zero, zero)
+    }
+
+    /// The variable being differentiated by, seeded in both `ε₁` and `ε₂`.
+    pub fn variable(value: F) -> Self
+    {
+        let one: F = F::multiplicative_identity();
+        HyperDual::new(value, one, one, F::additive_identity())
+    }
+
+    /// Applies a function with value `f`, derivative `df` and second
+    /// derivative `ddf` at `self.value` by the chain rule.
+    fn chain(self, f: F, df: F, ddf: F) -> Self
+    {
+        HyperDual::new(f, df * self.first, df * self.second, df * self.mixed + ddf * self.first * self.second)
+    }
+}
+
+impl<F> HyperDual<F>
+where
+    F: ComplexField
+{
+    pub fn exp(self) -> Self
+    {
+        let exp: F = self.value.exp();
+        self.chain(exp, exp, exp)
+    }
+
+    pub fn sqrt(self) -> Self
+    {
+        let sqrt: F = self.value.sqrt();
+        let df: F = F::multiplicative_identity() / (two::<F>() * sqrt);
+        self.chain(sqrt, df, -df / (two::<F>() * self.value))
+    }
+}
+
+impl<R> HyperDual<R>
+where
+    R: RealField
+{
+    pub fn sin(self) -> Self
+    {
+        let (sin, cos): (R, R) = (self.value.sin(), self.value.cos());
+        self.chain(sin, cos, -sin)
+    }
+
+    pub fn cos(self) -> Self
+    {
+        let (sin, cos): (R, R) = (self.value.sin(), self.value.cos());
+        self.chain(cos, -sin, -cos)
+    }
+
+    pub fn ln(self) -> Self
+    {
+        let recip: R = R::multiplicative_identity() / self.value;
+        self.chain(self.value.ln(), recip, -recip * recip)
+    }
+}
+
+/// The derivative of `f` at `x`.
+pub fn derivative<F, G>(f: G, x: F) -> F
+where
+    F: Ring + Copy,
+    G: Fn(Dual<F>) -> Dual<F>
+{
+    f(Dual::variable(x)).derivative
+}
+
+/// The gradient of `f` at `x`, by one forward pass per variable.
+pub fn gradient<F, G>(f: G, x: &[F]) -> Vec<F>
+where
+    F: Ring + Copy,
+    G: Fn(&[Dual<F>]) -> Dual<F>
+{
+    (0..x.len()).map(|k| f(&seed(x, k)).derivative).collect()
+}
+
+/// The Jacobian of `f` at `x`, with a row for each output and a column for
+/// each variable.
+pub fn jacobian<F, G>(f: G, x: &[F]) -> Vec<Vec<F>>
+where
+    F: Ring + Copy,
+    G: Fn(&[Dual<F>]) -> Vec<Dual<F>>
+{
+    let columns: Vec<Vec<Dual<F>>> = (0..x.len()).map(|k| f(&seed(x, k))).collect();
+    let outputs: usize = match columns.first() {
+        Some(column) => column.len(),
+        None => f(&[]).len()
+    };
+    (0..outputs)
+        .map(|i| columns.iter().map(|column| column[i].derivative).collect())
+        .collect()
+}
+
+/// The Hessian of `f` at `x`, by one forward pass for each pair of variables.
+pub fn hessian<F, G>(f: G, x: &[F]) -> Vec<Vec<F>>
+where
+    F: Ring + Copy,
+    G: Fn(&[HyperDual<F>]) -> HyperDual<F>
+{
+    let n: usize = x.len();
+    let mixed = |i: usize, j: usize| -> F {
+        let seeded: Vec<HyperDual<F>> = x
+            .iter()
+            .enumerate()
+            .map(|(k, &xk)| {
+                let mut var: HyperDual<F> = HyperDual::constant(xk);
+                if k == i {
+                    var.first = F::multiplicative_identity();
+                }
+                if k == j {
+                    var.second = F::multiplicative_identity();
+                }
+                var
+            })
+            .collect();
+        f(&seeded).mixed
+    };
+    // Only the upper triangle is evaluated, as the Hessian is symmetric.
+    let upper: Vec<Vec<F>> = (0..n).map(|i| (i..n).map(|j| mixed(i, j)).collect()).collect();
+    (0..n)
+        .map(|i| (0..n).map(|j| if i <= j { upper[i][j - i] } else { upper[j][i - j] }).collect())
+        .collect()
+}
+
+/// The point `x` with variable `k` seeded and the rest held constant.
+fn seed<F>(x: &[F], k: usize) -> Vec<Dual<F>>
+where
+    F: Ring + Copy
+{
+    x.iter()
+        .enumerate()
+        .map(|(i, &xi)| if i == k { Dual::variable(xi) } else { Dual::constant(xi) })
+        .collect()
+}
+
+impl<F: Ring + Copy> Add for Dual<F>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        Dual::new(self.value + rhs.value, self.derivative + rhs.derivative)
+    }
+}
+
+impl<F: Ring + Copy> Sub for Dual<F>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        Dual::new(self.value - rhs.value, self.derivative - rhs.derivative)
+    }
+}
+
+impl<F: Ring + Copy> Neg for Dual<F>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        Dual::new(-self.value, -self.derivative)
+    }
+}
+
+impl<F: Ring + Copy> Mul for Dual<F>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        Dual::new(self.value * rhs.value, self.value * rhs.derivative + self.derivative * rhs.value)
+    }
+}
+
+impl<F: Field + Copy> Div for Dual<F>
+{
+    type Output = Self;
+
+    /// Divides by the value of `rhs`, which must be non-zero.
+    fn div(self, rhs: Self) -> Self
+    {
+        let value: F = self.value / rhs.value;
+        Dual::new(value, (self.derivative - value * rhs.derivative) / rhs.value)
+    }
+}
+
+impl<F: Ring + Copy> Add for HyperDual<F>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        HyperDual::new(self.value + rhs.value, self.first + rhs.first, self.second + rhs.second, self.mixed + rhs.mixed)
+    }
+}
+
+impl<F: Ring + Copy> Sub for HyperDual<F>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        HyperDual::new(self.value - rhs.value, self.first - rhs.first, self.second - rhs.second, self.mixed - rhs.mixed)
+    }
+}
+
+impl<F: Ring + Copy> Neg for HyperDual<F>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        HyperDual::new(-self.value, -self.first, -self.second, -self.mixed)
+    }
+}
+
+impl<F: Ring + Copy> Mul for HyperDual<F>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        HyperDual::new(
+            self.value * rhs.value,
+            self.value * rhs.first + self.first * rhs.value,
+            self.value * rhs.second + self.second * rhs.value,
+            self.value * rhs.mixed + self.first * rhs.second + self.second * rhs.first + self.mixed * rhs.value
+        )
+    }
+}
+
+impl<F: Field + Copy> Div for HyperDual<F>
+{
+    type Output = Self;
+
+    /// Multiplies by the reciprocal of `rhs`, whose value must be non-zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self
+    {
+        let recip: F = F::multiplicative_identity() / rhs.value;
+        self * rhs.chain(recip, -recip * recip, two::<F>() * recip * recip * recip)
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<F> Shrink for Dual<F>
+where
+    F: Shrink + Copy
+{
+    /// Shrinks the value, then the derivative.
+    fn shrink(&self) -> Vec<Self>
+    {
+        let values = self.value.shrink().into_iter().map(|value| Dual { value, ..*self });
+        let derivatives = self.derivative.shrink().into_iter().map(|derivative| Dual { derivative, ..*self });
+        values.chain(derivatives).collect()
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<F> Shrink for HyperDual<F>
+where
+    F: Shrink + Copy
+{
+    fn shrink(&self) -> Vec<Self>
+    {
+        let mut shrunk: Vec<Self> = Vec::new();
+        shrunk.extend(self.value.shrink().into_iter().map(|value| HyperDual { value, ..*self }));
+        shrunk.extend(self.first.shrink().into_iter().map(|first| HyperDual { first, ..*self }));
+        shrunk.extend(self.second.shrink().into_iter().map(|second| HyperDual { second, ..*self }));
+        shrunk.extend(self.mixed.shrink().into_iter().map(|mixed| HyperDual { mixed, ..*self }));
+        shrunk
+    }
+}
+
+macro_rules! dual {
+    ($($dual:ident { $($part:ident),* }),*) => {
+        $(
+            impl_assign_ops!{
+                copy [F: Ring + Copy] $dual<F>;
+                AddAssign, add_assign, add;
+                SubAssign, sub_assign, sub;
+                MulAssign, mul_assign, mul
+            }
+            impl_assign_ops!{copy [F: Field + Copy] $dual<F>; DivAssign, div_assign, div}
+
+            impl<F: Ring + Copy> AdditiveIdentity for $dual<F>
+            {
+                type Output = Self;
+
+                fn additive_identity() -> Self::Output
+                {
+                    $dual::constant(F::additive_identity())
+                }
+            }
+
+            impl<F: Ring + Copy> MultiplicativeIdentity for $dual<F>
+            {
+                type Output = Self;
+
+                fn multiplicative_identity() -> Self::Output
+                {
+                    $dual::constant(F::multiplicative_identity())
+                }
+            }
+
+            impl_structures!{[F: Ring + Copy] $dual<F>; Magma, Semigroup, Monoid, Group, AbelianGroup, Semiring, Ring}
+
+            /// Compares every part against the same tolerance.
+            impl<F: AbsDiffEq> AbsDiffEq for $dual<F>
+            {
+                type Epsilon = F::Epsilon;
+
+                fn default_epsilon() -> Self::Epsilon
+                {
+                    F::default_epsilon()
+                }
+
+                fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool
+                {
+                    $(self.$part.abs_diff_eq(&other.$part, epsilon))&&*
+                }
+            }
+
+            impl<F: RelativeEq> RelativeEq for $dual<F>
+            {
+                fn default_max_relative() -> Self::Epsilon
+                {
+                    F::default_max_relative()
+                }
+
+                fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool
+                {
+                    $(self.$part.relative_eq(&other.$part, epsilon, max_relative))&&*
+                }
+            }
+
+            impl<F: UlpsEq> UlpsEq for $dual<F>
+            {
+                fn default_max_ulps() -> u32
+                {
+                    F::default_max_ulps()
+                }
+
+                fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool
+                {
+                    $(self.$part.ulps_eq(&other.$part, epsilon, max_ulps))&&*
+                }
+            }
+        )*
+    };
+}
+
+dual!{Dual { value, derivative }, HyperDual { value, first, second, mixed }}
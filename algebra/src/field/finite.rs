@@ -0,0 +1,461 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "laws")]
+use crate::laws::Shrink;
+
+use super::{AbelianGroup, AdditiveIdentity, Field, Group, Magma, Monoid, MultiplicativeIdentity, Ring, Semigroup, Semiring, impl_assign_ops, impl_structures};
+
+/// The prime field `GF(p)` of integers modulo `P`.
+///
+/// `P` is checked to be prime at compile time wherever the field is used.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf<const P: u64>(u64);
+
+/// The binary field `GF(2)`, whose packed vectors `vspace!` generates.
+pub type Gf2 = Gf<2>;
+
+/// The binary extension field `GF(2ⁿ)` of polynomials over `GF(2)` modulo the
+/// irreducible polynomial `MODULUS` of degree `n < 64`.
+///
+/// Bit `k` of `MODULUS` and of an element is the coefficient of `xᵏ`.
+/// Irreducibility is not checked: with a reducible modulus some non-zero
+/// elements have no `inverse`.
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Gf2n<const MODULUS: u64>(u64);
+
+/// `GF(2⁸)` modulo `x⁸ + x⁴ + x³ + x + 1`, the field of AES and Reed-Solomon codes.
+pub type Gf256 = Gf2n<0x11b>;
+
+const fn mul_mod(a: u64, b: u64, m: u64) -> u64
+{
+    ((a as u128 * b as u128) % m as u128) as u64
+}
+
+const fn pow_mod(mut base: u64, mut exponent: u64, m: u64) -> u64
+{
+    let mut acc: u64 = 1 % m;
+    base %= m;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            acc = mul_mod(acc, base, m);
+        }
+        base = mul_mod(base, base, m);
+        exponent >>= 1;
+    }
+    acc
+}
+
+/// Miller-Rabin with the first twelve primes as bases, which is deterministic
+/// for every `u64`.
+const fn is_prime(n: u64) -> bool
+{
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+    if n < 2 {
+        return false
+    }
+    let mut idx: usize = 0;
+    while idx < BASES.len() {
+        if n.is_multiple_of(BASES[idx]) {
+            return n == BASES[idx]
+        }
+        idx += 1;
+    }
+    let (mut d, mut s): (u64, u32) = (n - 1, 0);
+    while d.is_multiple_of(2) {
+        d /= 2;
+        s += 1;
+    }
+    idx = 0;
+    while idx < BASES.len() {
+        let mut x: u64 = pow_mod(BASES[idx], d, n);
+        if x != 1 && x != n - 1 {
+            let mut round: u32 = 1;
+            while x != n - 1 {
+                if round == s {
+                    return false
+                }
+                x = mul_mod(x, x, n);
+                round += 1;
+            }
+        }
+        idx += 1;
+    }
+    true
+}
+
+impl<const P: u64> Gf<P>
+{
+    const PRIME: () = assert!(is_prime(P), "the modulus of `Gf<P>` must be prime");
+
+    pub const ZERO: Self = Self::new(0);
+
+    pub const ONE: Self = Self::new(1);
+
+    /// The residue of `value` modulo `P`.
+    pub const fn new(value: u64) -> Self
+    {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::PRIME;
+        Gf(value % P)
+    }
+
+    pub const fn modulus() -> u64
+    {
+        P
+    }
+
+    /// The representative in `0..P`.
+    pub const fn value(self) -> u64
+    {
+        self.0
+    }
+
+    pub fn pow(self, exponent: u64) -> Self
+    {
+        Gf(pow_mod(self.0, exponent, P))
+    }
+
+    /// The multiplicative inverse by the extended Euclidean algorithm, or
+    /// `None` for zero.
+    pub fn inverse(self) -> Option<Self>
+    {
+        let (mut r0, mut r1): (i128, i128) = (P as i128, self.0 as i128);
+        let (mut t0, mut t1): (i128, i128) = (0, 1);
+        while r1 != 0 {
+            let q: i128 = r0 / r1;
+            (r0, r1) = (r1, r0 - q * r1);
+            (t0, t1) = (t1, t0 - q * t1);
+        }
+        if r0 == 1 { Some(Gf(t0.rem_euclid(P as i128) as u64)) } else { None }
+    }
+}
+
+impl<const P: u64> From<u64> for Gf<P>
+{
+    fn from(value: u64) -> Self
+    {
+        Self::new(value)
+    }
+}
+
+impl<const P: u64> From<i64> for Gf<P>
+{
+    fn from(value: i64) -> Self
+    {
+        Self::new((value as i128).rem_euclid(P as i128) as u64)
+    }
+}
+
+impl<const P: u64> Add for Gf<P>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        Gf(((self.0 as u128 + rhs.0 as u128) % P as u128) as u64)
+    }
+}
+
+impl<const P: u64> Sub for Gf<P>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        if self.0 >= rhs.0 { Gf(self.0 - rhs.0) } else { Gf(P - (rhs.0 - self.0)) }
+    }
+}
+
+impl<const P: u64> Neg for Gf<P>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        Gf::ZERO - self
+    }
+}
+
+impl<const P: u64> Mul for Gf<P>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        Gf(mul_mod(self.0, rhs.0, P))
+    }
+}
+
+impl<const P: u64> Div for Gf<P>
+{
+    type Output = Self;
+
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self
+    {
+        self * rhs.inverse().expect("division by zero in GF(p)")
+    }
+}
+
+impl<const P: u64> fmt::Debug for Gf<P>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{} (mod {})", self.0, P)
+    }
+}
+
+impl<const P: u64> fmt::Display for Gf<P>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<const P: u64> Shrink for Gf<P>
+{
+    fn shrink(&self) -> Vec<Self>
+    {
+        self.0.shrink().into_iter().map(Gf).collect()
+    }
+}
+
+/// The degree of a non-zero polynomial over `GF(2)`.
+const fn degree(polynomial: u64) -> u32
+{
+    63 - polynomial.leading_zeros()
+}
+
+/// The product of two polynomials over `GF(2)` whose degrees sum to less than 64.
+fn clmul(mut a: u64, mut b: u64) -> u64
+{
+    let mut acc: u64 = 0;
+    while b != 0 {
+        if b & 1 == 1 {
+            acc ^= a;
+        }
+        a <<= 1;
+        b >>= 1;
+    }
+    acc
+}
+
+impl<const MODULUS: u64> Gf2n<MODULUS>
+{
+    const DEGREE: u32 = {
+        assert!(MODULUS > 1, "the modulus of `Gf2n` must have positive degree");
+        degree(MODULUS)
+    };
+
+    pub const ZERO: Self = Gf2n(0);
+
+    pub const ONE: Self = Gf2n(1);
+
+    /// The residue of the polynomial `bits` modulo `MODULUS`.
+    pub fn new(bits: u64) -> Self
+    {
+        let mut bits: u64 = bits;
+        while bits != 0 && degree(bits) >= Self::DEGREE {
+            bits ^= MODULUS << (degree(bits) - Self::DEGREE);
+        }
+        Gf2n(bits)
+    }
+
+    /// The degree `n` of the extension, so the field has `2ⁿ` elements.
+    pub const fn degree() -> u32
+    {
+        Self::DEGREE
+    }
+
+    /// The coefficients of the representative of degree less than `n`.
+    pub const fn bits(self) -> u64
+    {
+        self.0
+    }
+
+    pub fn pow(self, mut exponent: u64) -> Self
+    {
+        let (mut base, mut acc): (Self, Self) = (self, Self::ONE);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc *= base;
+            }
+            base = base * base;
+            exponent >>= 1;
+        }
+        acc
+    }
+
+    /// The multiplicative inverse by the extended Euclidean algorithm over
+    /// `GF(2)[x]`, or `None` for zero and for elements sharing a factor with a
+    /// reducible modulus.
+    pub fn inverse(self) -> Option<Self>
+    {
+        let (mut r0, mut r1): (u64, u64) = (MODULUS, self.0);
+        let (mut t0, mut t1): (u64, u64) = (0, 1);
+        while r1 != 0 {
+            let (mut q, mut r): (u64, u64) = (0, r0);
+            while r != 0 && degree(r) >= degree(r1) {
+                let shift: u32 = degree(r) - degree(r1);
+                q ^= 1 << shift;
+                r ^= r1 << shift;
+            }
+            (r0, r1) = (r1, r);
+            (t0, t1) = (t1, t0 ^ clmul(q, t1));
+        }
+        if r0 == 1 { Some(Gf2n(t0)) } else { None }
+    }
+}
+
+impl<const MODULUS: u64> From<u64> for Gf2n<MODULUS>
+{
+    fn from(bits: u64) -> Self
+    {
+        Self::new(bits)
+    }
+}
+
+impl<const MODULUS: u64> Add for Gf2n<MODULUS>
+{
+    type Output = Self;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn add(self, rhs: Self) -> Self
+    {
+        Gf2n(self.0 ^ rhs.0)
+    }
+}
+
+impl<const MODULUS: u64> Sub for Gf2n<MODULUS>
+{
+    type Output = Self;
+
+    /// Subtraction is addition in characteristic two.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn sub(self, rhs: Self) -> Self
+    {
+        Gf2n(self.0 ^ rhs.0)
+    }
+}
+
+impl<const MODULUS: u64> Neg for Gf2n<MODULUS>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        self
+    }
+}
+
+impl<const MODULUS: u64> Mul for Gf2n<MODULUS>
+{
+    type Output = Self;
+
+    /// Shift-and-add multiplication, reducing by `MODULUS` whenever the
+    /// shifted factor reaches degree `n`.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self
+    {
+        let (mut a, mut b, mut acc): (u64, u64, u64) = (self.0, rhs.0, 0);
+        while b != 0 {
+            if b & 1 == 1 {
+                acc ^= a;
+            }
+            b >>= 1;
+            a <<= 1;
+            if (a >> Self::DEGREE) & 1 == 1 {
+                a ^= MODULUS;
+            }
+        }
+        Gf2n(acc)
+    }
+}
+
+impl<const MODULUS: u64> Div for Gf2n<MODULUS>
+{
+    type Output = Self;
+
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self
+    {
+        self * rhs.inverse().expect("division by zero in GF(2^n)")
+    }
+}
+
+impl<const MODULUS: u64> fmt::Debug for Gf2n<MODULUS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{:#b} (mod {:#b})", self.0, MODULUS)
+    }
+}
+
+impl<const MODULUS: u64> fmt::Display for Gf2n<MODULUS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{:#b}", self.0)
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<const MODULUS: u64> Shrink for Gf2n<MODULUS>
+{
+    /// Clears one set coefficient at a time.
+    fn shrink(&self) -> Vec<Self>
+    {
+        (0..64)
+            .filter(|k| (self.0 >> k) & 1 == 1)
+            .map(|k| Gf2n(self.0 & !(1 << k)))
+            .collect()
+    }
+}
+
+macro_rules! finite {
+    (
+        $($field:ident<const $param:ident>),*
+    ) => {
+        $(
+            impl_assign_ops!{
+                copy [const $param: u64] $field<$param>;
+                AddAssign, add_assign, add;
+                SubAssign, sub_assign, sub;
+                MulAssign, mul_assign, mul;
+                DivAssign, div_assign, div
+            }
+
+            impl<const $param: u64> AdditiveIdentity for $field<$param>
+            {
+                type Output = Self;
+
+                fn additive_identity() -> Self::Output
+                {
+                    Self::ZERO
+                }
+            }
+
+            impl<const $param: u64> MultiplicativeIdentity for $field<$param>
+            {
+                type Output = Self;
+
+                fn multiplicative_identity() -> Self::Output
+                {
+                    Self::ONE
+                }
+            }
+
+            impl_structures!{
+                [const $param: u64] $field<$param>;
+                Magma, Semigroup, Monoid, Group, AbelianGroup, Semiring, Ring, Field
+            }
+        )*
+    };
+}
+
+finite!{Gf<const P>, Gf2n<const MODULUS>}
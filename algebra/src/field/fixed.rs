@@ -0,0 +1,412 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "laws")]
+use crate::laws::Shrink;
+
+use super::{AbelianGroup, AdditiveIdentity, Field, Group, Magma, Monoid, MultiplicativeIdentity, Ring, Semigroup, Semiring, impl_assign_ops, impl_structures};
+
+/// A signed primitive that can hold the bits of a `Fixed`.
+///
+/// Every intermediate result is computed exactly in `i128`, which holds the
+/// product of any two `i64`, and then brought back into range.
+pub trait FixedInteger: Copy + Ord + Hash + fmt::Debug + Default
+{
+    const BITS: u32;
+
+    const MIN: i128;
+
+    const MAX: i128;
+
+    fn to_i128(self) -> i128;
+
+    /// The low `BITS` bits of `value`, as two's complement.
+    fn wrapping_from(value: i128) -> Self;
+}
+
+macro_rules! fixed_integers {
+    ($($primitive:ty),*) => {
+        $(
+            impl FixedInteger for $primitive
+            {
+                const BITS: u32 = <$primitive>::BITS;
+
+                const MIN: i128 = <$primitive>::MIN as i128;
+
+                const MAX: i128 = <$primitive>::MAX as i128;
+
+                fn to_i128(self) -> i128
+                {
+                    self as i128
+                }
+
+                fn wrapping_from(value: i128) -> Self
+                {
+                    value as $primitive
+                }
+            }
+        )*
+    };
+}
+
+fixed_integers!{
+    i8, i16, i32, i64
+}
+
+/// What a `Fixed` does with a result outside its range.
+pub trait Overflow: Copy + Ord + Hash + fmt::Debug + Default
+{
+    fn narrow<I: FixedInteger>(value: i128) -> I;
+}
+
+/// Clamps results to the smallest or largest representable value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Saturating;
+
+/// Keeps the low bits of results, as two's complement integers do.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Wrapping;
+
+impl Overflow for Saturating
+{
+    fn narrow<I: FixedInteger>(value: i128) -> I
+    {
+        I::wrapping_from(value.clamp(I::MIN, I::MAX))
+    }
+}
+
+impl Overflow for Wrapping
+{
+    fn narrow<I: FixedInteger>(value: i128) -> I
+    {
+        I::wrapping_from(value)
+    }
+}
+
+/// How a conversion picks between the two nearest representable values.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rounding
+{
+    /// To the nearer, and to the one with an even last bit on a tie.
+    Nearest,
+    /// Towards negative infinity.
+    Down,
+    /// Towards positive infinity.
+    Up,
+    TowardZero
+}
+
+/// A binary fixed-point number `bits / 2^FRAC` stored in the integer `I`.
+///
+/// The arithmetic is integer arithmetic, so results are bit-for-bit the same
+/// on every platform and in every evaluation order the operations allow.
+/// Products and quotients are rounded to nearest, ties to even; results out of
+/// range saturate or wrap according to `O`. Division by zero panics.
+///
+/// The ring and field axioms only hold where nothing is rounded or clamped.
+/// Saturation breaks associativity of addition, as `(max + 1) - 1` is below
+/// `max + (1 - 1)`, and rounded products break associativity and
+/// distributivity of multiplication; wrapping keeps addition exact.
+#[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<I, const FRAC: u32, O = Saturating>
+{
+    bits: I,
+    overflow: PhantomData<O>
+}
+
+/// Divides `numerator` by a positive `denominator`, rounding to nearest with
+/// ties to even.
+fn divide(numerator: i128, denominator: i128) -> i128
+{
+    let (quotient, remainder): (i128, i128) = (numerator.div_euclid(denominator), numerator.rem_euclid(denominator));
+    match (2 * remainder).cmp(&denominator) {
+        Ordering::Greater => quotient + 1,
+        Ordering::Equal => quotient + quotient.rem_euclid(2),
+        Ordering::Less => quotient
+    }
+}
+
+/// Whether `rounding` keeps the lower of two candidates around `value`.
+fn rounds_down(rounding: Rounding, value: i128) -> bool
+{
+    rounding == Rounding::Down || (rounding == Rounding::TowardZero && value > 0)
+}
+
+/// Whether `rounding` keeps the upper of two candidates around `value`.
+fn rounds_up(rounding: Rounding, value: i128) -> bool
+{
+    rounding == Rounding::Up || (rounding == Rounding::TowardZero && value < 0)
+}
+
+impl<I, const FRAC: u32, O> Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    const VALID: () = assert!(FRAC < I::BITS, "a Fixed needs an integer bit for the sign");
+
+    pub fn from_bits(bits: I) -> Self
+    {
+        #[allow(clippy::let_unit_value)]
+        let _: () = Self::VALID;
+        Fixed { bits, overflow: PhantomData }
+    }
+
+    pub fn to_bits(self) -> I
+    {
+        self.bits
+    }
+
+    /// The integer `value`, saturated or wrapped into range.
+    pub fn from_integer(value: i64) -> Self
+    {
+        Fixed::from_wide(i128::from(value) << FRAC)
+    }
+
+    /// The smallest positive value, `2^-FRAC`.
+    pub fn epsilon() -> Self
+    {
+        Fixed::from_wide(1)
+    }
+
+    pub fn min_value() -> Self
+    {
+        Fixed::from_wide(I::MIN)
+    }
+
+    pub fn max_value() -> Self
+    {
+        Fixed::from_wide(I::MAX)
+    }
+
+    /// Rounds `value` to a multiple of `2^-FRAC`, then saturates or wraps.
+    ///
+    /// Panics on infinities and NaN.
+    pub fn from_f64(value: f64, rounding: Rounding) -> Self
+    {
+        assert!(value.is_finite(), "{} is not a finite number", value);
+        // Scaling by a power of two is exact, so this is the only rounding.
+        let scaled: f64 = value * 2f64.powi(FRAC as i32);
+        let rounded: f64 = match rounding {
+            Rounding::Nearest => scaled.round_ties_even(),
+            Rounding::Down => scaled.floor(),
+            Rounding::Up => scaled.ceil(),
+            Rounding::TowardZero => scaled.trunc()
+        };
+        // Beyond i128 every float is a multiple of 2^75, so ±2^126 stands in
+        // for it: the low bits vanish and the sign saturates the same way.
+        let wide: i128 = if rounded.abs() < 2f64.powi(127) { rounded as i128 } else { (rounded.signum() as i128) << 126 };
+        Fixed::from_wide(wide)
+    }
+
+    /// Rounds `value` to a multiple of `2^-FRAC`, then saturates or wraps.
+    ///
+    /// Panics on infinities and NaN.
+    pub fn from_f32(value: f32, rounding: Rounding) -> Self
+    {
+        Fixed::from_f64(f64::from(value), rounding)
+    }
+
+    /// The nearest `f64`, exact when `I` has at most 53 bits.
+    pub fn to_f64(self) -> f64
+    {
+        self.to_f64_rounded(Rounding::Nearest)
+    }
+
+    /// The `f64` on the side of `self` that `rounding` picks.
+    pub fn to_f64_rounded(self, rounding: Rounding) -> f64
+    {
+        let bits: i128 = self.wide();
+        let nearest: f64 = bits as f64;
+        // Only the integer conversion rounds; scaling by a power of two is exact.
+        let rounded: f64 = match (nearest as i128).cmp(&bits) {
+            Ordering::Greater if rounds_down(rounding, bits) => nearest.next_down(),
+            Ordering::Less if rounds_up(rounding, bits) => nearest.next_up(),
+            _ => nearest
+        };
+        rounded / 2f64.powi(FRAC as i32)
+    }
+
+    /// The nearest `f32`, exact when `I` has at most 24 bits.
+    pub fn to_f32(self) -> f32
+    {
+        self.to_f32_rounded(Rounding::Nearest)
+    }
+
+    /// The `f32` on the side of `self` that `rounding` picks.
+    pub fn to_f32_rounded(self, rounding: Rounding) -> f32
+    {
+        let bits: i128 = self.wide();
+        let nearest: f32 = bits as f32;
+        let rounded: f32 = match (nearest as i128).cmp(&bits) {
+            Ordering::Greater if rounds_down(rounding, bits) => nearest.next_down(),
+            Ordering::Less if rounds_up(rounding, bits) => nearest.next_up(),
+            _ => nearest
+        };
+        rounded / 2f32.powi(FRAC as i32)
+    }
+
+    pub fn abs(self) -> Self
+    {
+        if self.bits.to_i128() < 0 { -self } else { self }
+    }
+
+    fn from_wide(value: i128) -> Self
+    {
+        Fixed::from_bits(O::narrow(value))
+    }
+
+    fn wide(self) -> i128
+    {
+        self.bits.to_i128()
+    }
+}
+
+impl<I, const FRAC: u32, O> fmt::Display for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<I, const FRAC: u32, O> fmt::Debug for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<I, const FRAC: u32, O> Add for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        Fixed::from_wide(self.wide() + rhs.wide())
+    }
+}
+
+impl<I, const FRAC: u32, O> Sub for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        Fixed::from_wide(self.wide() - rhs.wide())
+    }
+}
+
+impl<I, const FRAC: u32, O> Neg for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    type Output = Self;
+
+    /// Only the minimum overflows, to the maximum or to itself.
+    fn neg(self) -> Self
+    {
+        Fixed::from_wide(-self.wide())
+    }
+}
+
+impl<I, const FRAC: u32, O> Mul for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        Fixed::from_wide(divide(self.wide() * rhs.wide(), 1 << FRAC))
+    }
+}
+
+impl<I, const FRAC: u32, O> Div for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    type Output = Self;
+
+    /// Panics if `rhs` is zero.
+    fn div(self, rhs: Self) -> Self
+    {
+        assert!(rhs.wide() != 0, "division by zero");
+        let (numerator, denominator): (i128, i128) = (self.wide() << FRAC, rhs.wide());
+        let quotient: i128 = if denominator < 0 { divide(-numerator, -denominator) } else { divide(numerator, denominator) };
+        Fixed::from_wide(quotient)
+    }
+}
+
+impl_assign_ops!{
+    copy [I: FixedInteger, const FRAC: u32, O: Overflow] Fixed<I, FRAC, O>;
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div
+}
+
+impl_structures!{
+    [I: FixedInteger, const FRAC: u32, O: Overflow] Fixed<I, FRAC, O>;
+    Magma, Semigroup, Monoid, Semiring, Group, AbelianGroup, Ring, Field
+}
+
+impl<I, const FRAC: u32, O> AdditiveIdentity for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    type Output = Self;
+
+    fn additive_identity() -> Self::Output
+    {
+        Fixed::from_wide(0)
+    }
+}
+
+impl<I, const FRAC: u32, O> MultiplicativeIdentity for Fixed<I, FRAC, O>
+where
+    I: FixedInteger,
+    O: Overflow
+{
+    type Output = Self;
+
+    /// One, which only saturates or wraps when `FRAC` leaves no integer bit
+    /// besides the sign.
+    fn multiplicative_identity() -> Self::Output
+    {
+        Fixed::from_integer(1)
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<I, const FRAC: u32, O> Shrink for Fixed<I, FRAC, O>
+where
+    I: FixedInteger + Shrink,
+    O: Overflow
+{
+    /// Shrinks the bits, towards zero.
+    fn shrink(&self) -> Vec<Self>
+    {
+        self.bits.shrink().into_iter().map(Fixed::from_bits).collect()
+    }
+}
@@ -1,3 +1,5 @@
+use num_complex::Complex;
+
 pub trait AdditiveIdentity
 {
     type Output;
@@ -5,41 +7,20 @@ pub trait AdditiveIdentity
     fn additive_identity() -> Self::Output;
 }
 
-#[macro_use]
+pub trait MultiplicativeIdentity
+{
+    type Output;
+
+    fn multiplicative_identity() -> Self::Output;
+}
+
 macro_rules! primitives {
     (
         $($primitive:ty),*
     ) => {
         $(
-            impl AdditiveIdentity for $primitive
-            {
-                type Output = $primitive;
-
-                fn additive_identity() -> Self::Output
-                {
-                    0
-                }
-            }   
-            
-            impl<'a> AdditiveIdentity for &'a $primitive
-            {
-                type Output = $primitive;
-
-                fn additive_identity() -> Self::Output
-                {
-                    0
-                }
-            } 
-
-            impl<'a> AdditiveIdentity for &'a mut $primitive
-            {
-                type Output = $primitive;
-
-                fn additive_identity() -> Self::Output
-                {
-                    0
-                }
-            } 
+            primitives!(@identity AdditiveIdentity, additive_identity, $primitive, 0);
+            primitives!(@identity MultiplicativeIdentity, multiplicative_identity, $primitive, 1);
         )*
     };
 
@@ -47,36 +28,52 @@ macro_rules! primitives {
         @float $($primitive:ty),*
     ) => {
         $(
-            impl AdditiveIdentity for $primitive
-            {
-                type Output = $primitive;
-
-                fn additive_identity() -> Self::Output
-                {
-                    0.0
-                }
-            }   
-            
-            impl<'a> AdditiveIdentity for &'a $primitive
+            primitives!(@identity AdditiveIdentity, additive_identity, $primitive, 0.0);
+            primitives!(@identity MultiplicativeIdentity, multiplicative_identity, $primitive, 1.0);
+        )*
+    };
+
+    (
+        @complex $($primitive:ty),*
+    ) => {
+        $(
+            primitives!(@identity AdditiveIdentity, additive_identity, Complex<$primitive>, Complex::new(0.0, 0.0));
+            primitives!(@identity MultiplicativeIdentity, multiplicative_identity, Complex<$primitive>, Complex::new(1.0, 0.0));
+        )*
+    };
+
+    (
+        @identity $identity:ident, $method:ident, $primitive:ty, $value:expr
+    ) => {
+        impl $identity for $primitive
+        {
+            type Output = $primitive;
+
+            fn $method() -> Self::Output
             {
-                type Output = $primitive;
+                $value
+            }
+        }
 
-                fn additive_identity() -> Self::Output
-                {
-                    0.0
-                }
-            } 
+        impl<'a> $identity for &'a $primitive
+        {
+            type Output = $primitive;
 
-            impl<'a> AdditiveIdentity for &'a mut $primitive
+            fn $method() -> Self::Output
             {
-                type Output = $primitive;
+                $value
+            }
+        }
 
-                fn additive_identity() -> Self::Output
-                {
-                    0.0
-                }
-            } 
-        )*
+        impl<'a> $identity for &'a mut $primitive
+        {
+            type Output = $primitive;
+
+            fn $method() -> Self::Output
+            {
+                $value
+            }
+        }
     };
 }
 
@@ -86,3 +83,5 @@ primitives!{
 }
 
 primitives!{@float f32, f64}
+
+primitives!{@complex f32, f64}
@@ -0,0 +1,254 @@
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+#[cfg(feature = "laws")]
+use crate::laws::Shrink;
+
+use super::{AbelianGroup, AdditiveIdentity, Group, Magma, Monoid, MultiplicativeIdentity, RealField, Ring, Semigroup, Semiring, impl_assign_ops, impl_structures};
+
+/// A closed interval `[lower, upper]` of reals, for rigorous enclosures.
+///
+/// Every operation rounds its bounds outward, so the exact result of the
+/// operation on any reals in the operands lies in the result. A dot product
+/// over an interval-valued `vspace!` thereby bounds the exact dot product.
+///
+/// The ring axioms only hold as enclosures: `x - x` contains zero but is wider
+/// than it unless `x` is a point, and multiplication is sub-distributive.
+#[derive(Clone, Copy, PartialEq)]
+pub struct Interval<F>
+{
+    lower: F,
+    upper: F
+}
+
+impl<F> Interval<F>
+where
+    F: RealField
+{
+    /// Panics unless `lower <= upper`, which also rejects NaN.
+    pub fn new(lower: F, upper: F) -> Self
+    {
+        assert!(lower <= upper, "the interval [{:?}, {:?}] is empty", lower, upper);
+        Interval { lower, upper }
+    }
+
+    /// The degenerate interval `[value, value]`.
+    pub fn point(value: F) -> Self
+    {
+        Interval::new(value, value)
+    }
+
+    /// The interval one ulp either side of `value`, which encloses every real
+    /// that rounds to it, such as the decimal `0.1`.
+    pub fn around(value: F) -> Self
+    {
+        Interval::new(value.next_down(), value.next_up())
+    }
+
+    pub fn lower(&self) -> F
+    {
+        self.lower
+    }
+
+    pub fn upper(&self) -> F
+    {
+        self.upper
+    }
+
+    /// An upper bound on `upper - lower`.
+    pub fn width(&self) -> F
+    {
+        (self.upper - self.lower).next_up()
+    }
+
+    /// A point near the centre, not necessarily exactly in the middle.
+    pub fn midpoint(&self) -> F
+    {
+        let two: F = F::multiplicative_identity() + F::multiplicative_identity();
+        (self.lower / two + self.upper / two).max(self.lower).min(self.upper)
+    }
+
+    /// The largest absolute value in the interval.
+    pub fn magnitude(&self) -> F
+    {
+        self.lower.abs().max(self.upper.abs())
+    }
+
+    pub fn is_point(&self) -> bool
+    {
+        self.lower == self.upper
+    }
+
+    pub fn contains(&self, value: F) -> bool
+    {
+        self.lower <= value && value <= self.upper
+    }
+
+    /// Whether `other` lies within `self`.
+    pub fn encloses(&self, other: &Self) -> bool
+    {
+        self.lower <= other.lower && other.upper <= self.upper
+    }
+
+    pub fn intersects(&self, other: &Self) -> bool
+    {
+        self.lower <= other.upper && other.lower <= self.upper
+    }
+
+    pub fn intersection(&self, other: &Self) -> Option<Self>
+    {
+        if self.intersects(other) {
+            Some(Interval::new(self.lower.max(other.lower), self.upper.min(other.upper)))
+        } else {
+            None
+        }
+    }
+
+    /// The smallest interval containing both.
+    pub fn hull(&self, other: &Self) -> Self
+    {
+        Interval::new(self.lower.min(other.lower), self.upper.max(other.upper))
+    }
+
+    /// Encloses the square roots of the non-negative part of the interval.
+    ///
+    /// Panics if the interval is entirely negative.
+    pub fn sqrt(self) -> Self
+    {
+        let zero: F = F::additive_identity();
+        assert!(self.upper >= zero, "square root of the negative interval {:?}", self);
+        let lower: F = if self.lower <= zero { zero } else { self.lower.sqrt().next_down().max(zero) };
+        Interval::new(lower, self.upper.sqrt().next_up())
+    }
+
+    /// Encloses `1 / x` for every `x` in the interval.
+    ///
+    /// Panics if the interval contains zero.
+    pub fn recip(self) -> Self
+    {
+        assert!(!self.contains(F::additive_identity()), "reciprocal of {:?}, which contains zero", self);
+        let one: F = F::multiplicative_identity();
+        Interval::new((one / self.upper).next_down(), (one / self.lower).next_up())
+    }
+
+    /// Rounds bounds computed to nearest outward by an ulp each.
+    fn outward(lower: F, upper: F) -> Self
+    {
+        Interval::new(lower.next_down(), upper.next_up())
+    }
+}
+
+impl<F: RealField> Add for Interval<F>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        Interval::outward(self.lower + rhs.lower, self.upper + rhs.upper)
+    }
+}
+
+impl<F: RealField> Sub for Interval<F>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        Interval::outward(self.lower - rhs.upper, self.upper - rhs.lower)
+    }
+}
+
+impl<F: RealField> Neg for Interval<F>
+{
+    type Output = Self;
+
+    /// Negation is exact.
+    fn neg(self) -> Self
+    {
+        Interval::new(-self.upper, -self.lower)
+    }
+}
+
+impl<F: RealField> Mul for Interval<F>
+{
+    type Output = Self;
+
+    /// A zero bound times an infinite one is taken as zero, since every real
+    /// in the interval is finite.
+    fn mul(self, rhs: Self) -> Self
+    {
+        let zero: F = F::additive_identity();
+        let corner = |l: F, r: F| -> F { if l == zero || r == zero { zero } else { l * r } };
+        let products: [F; 4] = [
+            corner(self.lower, rhs.lower),
+            corner(self.lower, rhs.upper),
+            corner(self.upper, rhs.lower),
+            corner(self.upper, rhs.upper)
+        ];
+        let lower: F = products.iter().skip(1).fold(products[0], |acc, &p| acc.min(p));
+        let upper: F = products.iter().skip(1).fold(products[0], |acc, &p| acc.max(p));
+        Interval::outward(lower, upper)
+    }
+}
+
+impl<F: RealField> Div for Interval<F>
+{
+    type Output = Self;
+
+    /// Panics if `rhs` contains zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self
+    {
+        self * rhs.recip()
+    }
+}
+
+impl<F: RealField> fmt::Debug for Interval<F>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "[{:?}, {:?}]", self.lower, self.upper)
+    }
+}
+
+impl<F: RealField> AdditiveIdentity for Interval<F>
+{
+    type Output = Self;
+
+    fn additive_identity() -> Self::Output
+    {
+        Interval::point(F::additive_identity())
+    }
+}
+
+impl<F: RealField> MultiplicativeIdentity for Interval<F>
+{
+    type Output = Self;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Interval::point(F::multiplicative_identity())
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<F: RealField + Shrink> Shrink for Interval<F>
+{
+    /// Shrinks one bound at a time, keeping the interval non-empty.
+    fn shrink(&self) -> Vec<Self>
+    {
+        let lower = self.lower.shrink().into_iter().filter(|&lower| lower <= self.upper).map(|lower| Interval { lower, ..*self });
+        let upper = self.upper.shrink().into_iter().filter(|&upper| self.lower <= upper).map(|upper| Interval { upper, ..*self });
+        lower.chain(upper).collect()
+    }
+}
+
+impl_assign_ops!{
+    copy [F: RealField] Interval<F>;
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div
+}
+
+impl_structures!{[F: RealField] Interval<F>; Magma, Semigroup, Monoid, Group, AbelianGroup, Semiring, Ring}
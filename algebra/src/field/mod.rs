@@ -1,2 +1,32 @@
 mod identities;
 pub use identities::*;
+
+mod structure;
+pub use structure::*;
+
+mod scalar;
+pub use scalar::*;
+
+mod approx;
+pub use approx::*;
+
+mod finite;
+pub use finite::*;
+
+mod rational;
+pub use rational::*;
+
+mod dual;
+pub use dual::*;
+
+mod interval;
+pub use interval::*;
+
+mod quaternion;
+pub use quaternion::*;
+
+mod multiprecision;
+pub use multiprecision::*;
+
+mod fixed;
+pub use fixed::*;
@@ -0,0 +1,525 @@
+use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+use num_bigint::BigInt;
+
+#[cfg(feature = "laws")]
+use crate::laws::Shrink;
+
+use super::{AbelianGroup, AdditiveIdentity, Field, Group, Integer, Magma, Monoid, MultiplicativeIdentity, Ring, Semigroup, Semiring, impl_assign_ops, impl_structures};
+
+/// A binary floating-point number with a `PRECISION`-bit significand.
+///
+/// Every operation is correctly rounded to nearest, ties to even, as in IEEE
+/// 754. The exponent is unbounded, so there is no overflow, underflow,
+/// infinity or NaN; division by zero panics. The value is `mantissa · 2^exponent`
+/// with a mantissa of exactly `PRECISION` bits, or zero.
+///
+/// `BigFloat` is not `Copy`, so it works with the ring and field bounds of the
+/// `vspace!` ops but not with the `ComplexField` kernels.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct BigFloat<const PRECISION: usize>
+{
+    mantissa: BigInt,
+    exponent: i64
+}
+
+/// The precision of IEEE 754 binary128, 34 decimal digits.
+pub type Quad = BigFloat<113>;
+
+/// The precision of IEEE 754 binary256, 71 decimal digits.
+pub type Octuple = BigFloat<237>;
+
+/// The error of parsing a string that is not a decimal number.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParseBigFloatError;
+
+/// The largest power of ten, counting the digits after the point, that a
+/// parsed decimal may be scaled by; `10^1000000` has about 3.3 million bits.
+const MAX_DECIMAL_EXPONENT: i64 = 1_000_000;
+
+/// `base^exponent`, by repeated squaring.
+fn power(base: u32, mut exponent: usize) -> BigInt
+{
+    let mut result: BigInt = BigInt::from(1);
+    let mut square: BigInt = BigInt::from(base);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result *= &square;
+        }
+        exponent >>= 1;
+        if exponent > 0 {
+            square = &square * &square;
+        }
+    }
+    result
+}
+
+impl<const PRECISION: usize> BigFloat<PRECISION>
+{
+    const VALID: () = assert!(PRECISION >= 2, "a BigFloat needs at least two bits of precision");
+
+    pub fn zero() -> Self
+    {
+        BigFloat { mantissa: BigInt::from(0), exponent: 0 }
+    }
+
+    pub fn precision() -> usize
+    {
+        PRECISION
+    }
+
+    /// The difference between `1` and the next representable value, `2^(1 - PRECISION)`.
+    pub fn epsilon() -> Self
+    {
+        BigFloat::round(BigInt::from(1), 1 - PRECISION as i64, false)
+    }
+
+    /// The correctly rounded quotient of two integers.
+    ///
+    /// Panics if the denominator is zero.
+    pub fn from_ratio(numerator: BigInt, denominator: BigInt) -> Self
+    {
+        BigFloat::quotient(numerator, denominator, 0)
+    }
+
+    pub fn is_zero(&self) -> bool
+    {
+        self.mantissa.is_zero()
+    }
+
+    pub fn is_negative(&self) -> bool
+    {
+        self.mantissa.is_negative()
+    }
+
+    pub fn abs(self) -> Self
+    {
+        if self.is_negative() { -self } else { self }
+    }
+
+    /// The correctly rounded square root.
+    ///
+    /// Panics if the value is negative.
+    pub fn sqrt(self) -> Self
+    {
+        assert!(!self.is_negative(), "square root of the negative number {}", self);
+        if self.is_zero() {
+            return self
+        }
+        // Enough bits that the integer root has two more than the precision,
+        // with an even exponent left over.
+        let mut shift: i64 = 2 * PRECISION as i64 + 4;
+        if (self.exponent - shift) % 2 != 0 {
+            shift += 1;
+        }
+        let radicand: BigInt = self.mantissa << shift as usize;
+        let root: BigInt = radicand.sqrt();
+        let sticky: bool = &root * &root != radicand;
+        BigFloat::round(root, (self.exponent - shift) / 2, sticky)
+    }
+
+    /// The nearest `f64`, which is exact when the value has at most 53
+    /// significant bits and is in the normal range.
+    pub fn to_f64(&self) -> f64
+    {
+        if self.is_zero() {
+            return 0.0
+        }
+        let rounded: BigFloat<53> = BigFloat::round(self.mantissa.clone(), self.exponent, false);
+        let mantissa: f64 = rounded.mantissa.to_string().parse().unwrap();
+        match rounded.exponent {
+            exponent if exponent > 1100 => mantissa * f64::INFINITY,
+            exponent if exponent < -1200 => mantissa * 0.0,
+            // Two factors, as 2^exponent alone may not be representable.
+            exponent => mantissa * 2f64.powi((exponent / 2) as i32) * 2f64.powi((exponent - exponent / 2) as i32)
+        }
+    }
+
+    /// Rounds `mantissa · 2^exponent` to the precision.
+    ///
+    /// `sticky` marks that the exact magnitude is strictly between `|mantissa|`
+    /// and `|mantissa| + 1` units, which needs at least two bits beyond the
+    /// precision to round correctly.
+    fn round(mantissa: BigInt, mut exponent: i64, sticky: bool) -> Self
+    {
+        #[allow(clippy::let_unit_value)]
+        let _: () = Self::VALID;
+        if mantissa.is_zero() {
+            return BigFloat::zero()
+        }
+        let negative: bool = mantissa.is_negative();
+        let mut magnitude: BigInt = if negative { -mantissa } else { mantissa };
+        let bits: usize = magnitude.bits();
+        if bits <= PRECISION {
+            magnitude <<= PRECISION - bits;
+            exponent -= (PRECISION - bits) as i64;
+        } else {
+            let shift: usize = bits - PRECISION;
+            let mut truncated: BigInt = &magnitude >> shift;
+            let remainder: BigInt = magnitude - (&truncated << shift);
+            let half: BigInt = BigInt::from(1) << (shift - 1);
+            let odd: bool = !(&truncated % 2u32).is_zero();
+            let up: bool = match remainder.cmp(&half) {
+                Ordering::Greater => true,
+                Ordering::Equal => sticky || odd,
+                Ordering::Less => false
+            };
+            exponent += shift as i64;
+            if up {
+                truncated += 1u32;
+                if truncated.bits() > PRECISION {
+                    truncated >>= 1;
+                    exponent += 1;
+                }
+            }
+            magnitude = truncated;
+        }
+        BigFloat { mantissa: if negative { -magnitude } else { magnitude }, exponent }
+    }
+
+    /// The correctly rounded `numerator / denominator · 2^exponent`.
+    fn quotient(numerator: BigInt, denominator: BigInt, exponent: i64) -> Self
+    {
+        assert!(!denominator.is_zero(), "division by zero");
+        let negative: bool = numerator.is_negative() != denominator.is_negative();
+        let (numerator, denominator): (BigInt, BigInt) = (numerator.abs(), denominator.abs());
+        // Scale so that the integer quotient has two more bits than the precision.
+        let shift: i64 = PRECISION as i64 + 2 + denominator.bits() as i64 - numerator.bits() as i64;
+        let (numerator, denominator): (BigInt, BigInt) = if shift >= 0 {
+            (numerator << shift as usize, denominator)
+        } else {
+            (numerator, denominator << (-shift) as usize)
+        };
+        let (quotient, remainder): (BigInt, BigInt) = numerator.div_rem_floor(&denominator);
+        let quotient: BigInt = if negative { -quotient } else { quotient };
+        BigFloat::round(quotient, exponent - shift, !remainder.is_zero())
+    }
+
+    /// The exact value as a ratio of integers.
+    fn to_ratio(&self) -> (BigInt, BigInt)
+    {
+        if self.exponent >= 0 {
+            (self.mantissa.clone() << self.exponent as usize, BigInt::from(1))
+        } else {
+            (self.mantissa.clone(), BigInt::from(1) << (-self.exponent) as usize)
+        }
+    }
+}
+
+trait Abs
+{
+    fn abs(self) -> Self;
+}
+
+impl Abs for BigInt
+{
+    fn abs(self) -> Self
+    {
+        if self.is_negative() { -self } else { self }
+    }
+}
+
+impl<const PRECISION: usize> From<i64> for BigFloat<PRECISION>
+{
+    fn from(value: i64) -> Self
+    {
+        BigFloat::round(BigInt::from(value), 0, false)
+    }
+}
+
+impl<const PRECISION: usize> From<BigInt> for BigFloat<PRECISION>
+{
+    fn from(value: BigInt) -> Self
+    {
+        BigFloat::round(value, 0, false)
+    }
+}
+
+impl<const PRECISION: usize> From<f64> for BigFloat<PRECISION>
+{
+    /// Exact when the precision is at least 53 bits.
+    ///
+    /// Panics on infinities and NaN.
+    fn from(value: f64) -> Self
+    {
+        assert!(value.is_finite(), "{} is not a finite number", value);
+        let bits: u64 = value.to_bits();
+        let biased: i64 = ((bits >> 52) & 0x7ff) as i64;
+        let fraction: i64 = (bits & ((1 << 52) - 1)) as i64;
+        let (mantissa, exponent): (i64, i64) = if biased == 0 { (fraction, -1074) } else { (fraction | (1 << 52), biased - 1075) };
+        let mantissa: i64 = if value.is_sign_negative() { -mantissa } else { mantissa };
+        BigFloat::round(BigInt::from(mantissa), exponent, false)
+    }
+}
+
+impl<const PRECISION: usize> FromStr for BigFloat<PRECISION>
+{
+    type Err = ParseBigFloatError;
+
+    /// Parses a decimal such as `-12.5e-3`, correctly rounded.
+    ///
+    /// Fails on decimal exponents, net of the digits after the point, beyond
+    /// `±1000000`, which would take too long to scale exactly.
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (negative, unsigned): (bool, &str) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s))
+        };
+        let (significand, exponent): (&str, i64) = match unsigned.find(['e', 'E']) {
+            Some(idx) => (&unsigned[..idx], unsigned[idx + 1..].parse().map_err(|_| ParseBigFloatError)?),
+            None => (unsigned, 0)
+        };
+        let (integral, fractional): (&str, &str) = match significand.find('.') {
+            Some(idx) => (&significand[..idx], &significand[idx + 1..]),
+            None => (significand, "")
+        };
+        let digits: String = format!("{}{}", integral, fractional);
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseBigFloatError)
+        }
+        let mut numerator: BigInt = digits.parse().map_err(|_| ParseBigFloatError)?;
+        if negative {
+            numerator = -numerator;
+        }
+        let scale: i64 = i64::try_from(fractional.len())
+            .ok()
+            .and_then(|length| exponent.checked_sub(length))
+            .filter(|scale| (-MAX_DECIMAL_EXPONENT..=MAX_DECIMAL_EXPONENT).contains(scale))
+            .ok_or(ParseBigFloatError)?;
+        Ok(if scale >= 0 {
+            BigFloat::from(numerator * power(10, scale as usize))
+        } else {
+            BigFloat::from_ratio(numerator, power(10, (-scale) as usize))
+        })
+    }
+}
+
+impl<const PRECISION: usize> Default for BigFloat<PRECISION>
+{
+    fn default() -> Self
+    {
+        BigFloat::zero()
+    }
+}
+
+impl<const PRECISION: usize> Ord for BigFloat<PRECISION>
+{
+    /// Mantissas have the same length, so magnitudes compare by exponent first.
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        let sign = |x: &Self| -> i8 {
+            if x.is_zero() { 0 } else if x.is_negative() { -1 } else { 1 }
+        };
+        match sign(self).cmp(&sign(other)) {
+            Ordering::Equal if sign(self) == 0 => Ordering::Equal,
+            Ordering::Equal => {
+                let magnitude: Ordering = self.exponent
+                    .cmp(&other.exponent)
+                    .then_with(|| self.mantissa.clone().abs().cmp(&other.mantissa.clone().abs()));
+                if self.is_negative() { magnitude.reverse() } else { magnitude }
+            },
+            unequal => unequal
+        }
+    }
+}
+
+impl<const PRECISION: usize> PartialOrd for BigFloat<PRECISION>
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const PRECISION: usize> Add for BigFloat<PRECISION>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        if self.is_zero() {
+            return rhs
+        }
+        if rhs.is_zero() {
+            return self
+        }
+        let (hi, lo): (Self, Self) = if self.exponent >= rhs.exponent { (self, rhs) } else { (rhs, self) };
+        let gap: i64 = hi.exponent - lo.exponent;
+        if gap > PRECISION as i64 + 2 {
+            // The smaller operand is below a quarter of an ulp of the larger,
+            // so only its sign matters to the rounding.
+            let (negative, same_sign): (bool, bool) = (hi.is_negative(), hi.is_negative() == lo.is_negative());
+            let magnitude: BigInt = hi.mantissa.abs() << 2;
+            let magnitude: BigInt = if same_sign { magnitude } else { magnitude - 1u32 };
+            let mantissa: BigInt = if negative { -magnitude } else { magnitude };
+            return BigFloat::round(mantissa, hi.exponent - 2, true)
+        }
+        BigFloat::round((hi.mantissa << gap as usize) + lo.mantissa, lo.exponent, false)
+    }
+}
+
+impl<const PRECISION: usize> Sub for BigFloat<PRECISION>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        self + (-rhs)
+    }
+}
+
+impl<const PRECISION: usize> Neg for BigFloat<PRECISION>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        BigFloat { mantissa: -self.mantissa, exponent: self.exponent }
+    }
+}
+
+impl<const PRECISION: usize> Mul for BigFloat<PRECISION>
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        BigFloat::round(self.mantissa * rhs.mantissa, self.exponent + rhs.exponent, false)
+    }
+}
+
+impl<const PRECISION: usize> Div for BigFloat<PRECISION>
+{
+    type Output = Self;
+
+    /// Panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self
+    {
+        BigFloat::quotient(self.mantissa, rhs.mantissa, self.exponent - rhs.exponent)
+    }
+}
+
+impl<const PRECISION: usize> fmt::Display for BigFloat<PRECISION>
+{
+    /// Scientific notation with enough significant digits to read back the
+    /// same value, or `{:.n}` for `n` digits after the point.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        if self.is_zero() {
+            return write!(f, "0")
+        }
+        let digits: usize = match f.precision() {
+            Some(precision) => precision + 1,
+            None => (PRECISION as f64 * std::f64::consts::LOG10_2).ceil() as usize + 1
+        };
+        let (numerator, denominator): (BigInt, BigInt) = self.to_ratio();
+        let numerator: BigInt = numerator.abs();
+        let (lowest, highest): (BigInt, BigInt) = (power(10, digits - 1), power(10, digits));
+
+        // Scale by 10^(digits - 1 - k) for the decimal exponent k, starting
+        // from an estimate that may be off by one.
+        let bits: i64 = self.mantissa.bits() as i64 + self.exponent - 1;
+        let mut k: i64 = (bits as f64 * std::f64::consts::LOG10_2).floor() as i64;
+        let scaled: BigInt = loop {
+            let scale: i64 = digits as i64 - 1 - k;
+            let (n, d): (BigInt, BigInt) = if scale >= 0 {
+                (&numerator * power(10, scale as usize), denominator.clone())
+            } else {
+                (numerator.clone(), &denominator * power(10, (-scale) as usize))
+            };
+            let rounded: BigInt = (n * 2u32 + &d).div_rem_floor(&(d * 2u32)).0;
+            if rounded >= highest {
+                k += 1;
+            } else if rounded < lowest {
+                k -= 1;
+            } else {
+                break rounded
+            }
+        };
+
+        let mut significand: String = scaled.to_string();
+        if f.precision().is_none() {
+            significand = significand.trim_end_matches('0').to_string();
+        }
+        let sign: &str = if self.is_negative() { "-" } else { "" };
+        let (first, rest): (&str, &str) = significand.split_at(1);
+        if rest.is_empty() {
+            write!(f, "{}{}e{}", sign, first, k)
+        } else {
+            write!(f, "{}{}.{}e{}", sign, first, rest, k)
+        }
+    }
+}
+
+impl<const PRECISION: usize> fmt::Debug for BigFloat<PRECISION>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for ParseBigFloatError
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "invalid decimal number")
+    }
+}
+
+impl Error for ParseBigFloatError {}
+
+impl<const PRECISION: usize> AdditiveIdentity for BigFloat<PRECISION>
+{
+    type Output = Self;
+
+    fn additive_identity() -> Self::Output
+    {
+        BigFloat::zero()
+    }
+}
+
+impl<const PRECISION: usize> MultiplicativeIdentity for BigFloat<PRECISION>
+{
+    type Output = Self;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        BigFloat::from(1)
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<const PRECISION: usize> Shrink for BigFloat<PRECISION>
+{
+    /// Shrinks the nearest `f64`, towards fewer digits.
+    fn shrink(&self) -> Vec<Self>
+    {
+        self.to_f64()
+            .shrink()
+            .into_iter()
+            .map(BigFloat::from)
+            .filter(|candidate| candidate != self)
+            .collect()
+    }
+}
+
+impl_assign_ops!{
+    take [const PRECISION: usize] BigFloat<PRECISION>;
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div
+}
+
+impl_structures!{
+    [const PRECISION: usize] BigFloat<PRECISION>;
+    Magma, Semigroup, Monoid, Group, AbelianGroup, Semiring, Ring, Field
+}
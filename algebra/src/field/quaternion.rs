@@ -0,0 +1,417 @@
+use std::iter::FromIterator;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::{Dimension, VectorSpace};
+#[cfg(feature = "laws")]
+use crate::laws::Shrink;
+
+use super::{AbelianGroup, AbsDiffEq, AdditiveIdentity, Group, Magma, Monoid, MultiplicativeIdentity, RealField, RelativeEq, Ring, Semigroup, Semiring, UlpsEq, impl_assign_ops, impl_structures, two};
+
+/// A quaternion `w + xi + yj + zk` under the Hamilton product.
+///
+/// Quaternions form a ring whose multiplication does not commute. Unit
+/// quaternions represent rotations of three-dimensional space, with `q` and
+/// `-q` the same rotation; `rotate` applies one to a 3-vector, such as one of
+/// a `vspace!` of dimension three. Four-vectors convert in the order
+/// `(w, x, y, z)`.
+///
+/// Conversions take the space of the vectors, whose `Dimension` bound rejects
+/// a vector of the wrong dimension at compile time:
+///
+/// ```compile_fail
+/// use algebra::{Dimension, Quaternion, VectorSpace};
+///
+/// struct Plane;
+///
+/// impl VectorSpace for Plane
+/// {
+///     type Scalar = f64;
+///     type Vector = Vec<f64>;
+/// }
+///
+/// impl Dimension<2> for Plane {}
+///
+/// let q: Quaternion<f64> = Quaternion::pure(&Plane, &vec![1.0, 2.0]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Quaternion<F>
+{
+    pub w: F,
+    pub x: F,
+    pub y: F,
+    pub z: F
+}
+
+/// The entries of a vector of `space`, which has dimension `N`.
+fn entries<S, V, F, const N: usize>(_space: &S, vector: &V) -> [F; N]
+where
+    S: VectorSpace<Scalar=F, Vector=V> + Dimension<N>,
+    F: RealField,
+    for<'a> &'a V: IntoIterator<Item=&'a F>
+{
+    let entries: Vec<F> = vector.into_iter().copied().collect();
+    debug_assert!(entries.len() == N);
+    let mut buf: [F; N] = [F::additive_identity(); N];
+    buf.copy_from_slice(&entries);
+    buf
+}
+
+impl<F> Quaternion<F>
+where
+    F: RealField
+{
+    pub fn new(w: F, x: F, y: F, z: F) -> Self
+    {
+        Quaternion { w, x, y, z }
+    }
+
+    /// The quaternion `w + 0i + 0j + 0k`.
+    pub fn real(w: F) -> Self
+    {
+        let zero: F = F::additive_identity();
+        Quaternion::new(w, zero, zero, zero)
+    }
+
+    /// The pure quaternion `xi + yj + zk` of a 3-vector.
+    pub fn pure<S, V>(space: &S, vector: &V) -> Self
+    where
+        S: VectorSpace<Scalar=F, Vector=V> + Dimension<3>,
+        for<'a> &'a V: IntoIterator<Item=&'a F>
+    {
+        let [x, y, z]: [F; 3] = entries(space, vector);
+        Quaternion::new(F::additive_identity(), x, y, z)
+    }
+
+    /// The quaternion of a 4-vector `(w, x, y, z)`.
+    pub fn from_vector<S, V>(space: &S, vector: &V) -> Self
+    where
+        S: VectorSpace<Scalar=F, Vector=V> + Dimension<4>,
+        for<'a> &'a V: IntoIterator<Item=&'a F>
+    {
+        let [w, x, y, z]: [F; 4] = entries(space, vector);
+        Quaternion::new(w, x, y, z)
+    }
+
+    /// The 4-vector `(w, x, y, z)` of `space`.
+    pub fn to_vector<S, V>(&self, _space: &S) -> V
+    where
+        S: VectorSpace<Scalar=F, Vector=V> + Dimension<4>,
+        V: FromIterator<F>
+    {
+        vec![self.w, self.x, self.y, self.z].into_iter().collect()
+    }
+
+    /// The 3-vector `(x, y, z)` of the imaginary part, in `space`.
+    pub fn vector_part<S, V>(&self, _space: &S) -> V
+    where
+        S: VectorSpace<Scalar=F, Vector=V> + Dimension<3>,
+        V: FromIterator<F>
+    {
+        vec![self.x, self.y, self.z].into_iter().collect()
+    }
+
+    pub fn conjugate(self) -> Self
+    {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn scale(self, factor: F) -> Self
+    {
+        Quaternion::new(self.w * factor, self.x * factor, self.y * factor, self.z * factor)
+    }
+
+    /// The dot product of the quaternions as 4-vectors.
+    pub fn dot(&self, other: &Self) -> F
+    {
+        self.w * other.w + self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    pub fn norm_squared(&self) -> F
+    {
+        self.dot(self)
+    }
+
+    pub fn norm(&self) -> F
+    {
+        self.norm_squared().sqrt()
+    }
+
+    /// The unit quaternion in the same direction, or `None` for zero.
+    pub fn normalize(self) -> Option<Self>
+    {
+        let norm: F = self.norm();
+        if norm == F::additive_identity() {
+            return None
+        }
+        Some(self.scale(F::multiplicative_identity() / norm))
+    }
+
+    /// The multiplicative inverse `q* / |q|²`, or `None` for zero.
+    pub fn inverse(self) -> Option<Self>
+    {
+        let norm_squared: F = self.norm_squared();
+        if norm_squared == F::additive_identity() {
+            return None
+        }
+        Some(self.conjugate().scale(F::multiplicative_identity() / norm_squared))
+    }
+
+    /// The rotation by `angle` radians about `axis`, counterclockwise looking
+    /// down the axis, or `None` if the axis is zero.
+    pub fn from_axis_angle<S, V>(space: &S, axis: &V, angle: F) -> Option<Self>
+    where
+        S: VectorSpace<Scalar=F, Vector=V> + Dimension<3>,
+        for<'a> &'a V: IntoIterator<Item=&'a F>
+    {
+        let axis: Self = Quaternion::pure(space, axis).normalize()?;
+        let half: F = angle / two();
+        Some(Quaternion::real(half.cos()) + axis.scale(half.sin()))
+    }
+
+    /// The unit axis and the angle in `[0, 2π]` of the rotation of a unit
+    /// quaternion; the axis is `(1, 0, 0)` for the identity.
+    pub fn to_axis_angle<S, V>(&self, space: &S) -> (V, F)
+    where
+        S: VectorSpace<Scalar=F, Vector=V> + Dimension<3>,
+        V: FromIterator<F>
+    {
+        let sin: F = (self.x * self.x + self.y * self.y + self.z * self.z).sqrt();
+        let angle: F = two::<F>() * sin.atan2(self.w);
+        if sin == F::additive_identity() {
+            let (zero, one): (F, F) = (F::additive_identity(), F::multiplicative_identity());
+            return (vec![one, zero, zero].into_iter().collect(), angle)
+        }
+        (self.scale(F::multiplicative_identity() / sin).vector_part(space), angle)
+    }
+
+    /// The rotation by `roll` about x, then `pitch` about y, then `yaw` about z,
+    /// all about the fixed axes.
+    pub fn from_euler(roll: F, pitch: F, yaw: F) -> Self
+    {
+        let (cr, sr): (F, F) = ((roll / two()).cos(), (roll / two()).sin());
+        let (cp, sp): (F, F) = ((pitch / two()).cos(), (pitch / two()).sin());
+        let (cy, sy): (F, F) = ((yaw / two()).cos(), (yaw / two()).sin());
+        Quaternion::new(
+            cr * cp * cy + sr * sp * sy,
+            sr * cp * cy - cr * sp * sy,
+            cr * sp * cy + sr * cp * sy,
+            cr * cp * sy - sr * sp * cy
+        )
+    }
+
+    /// The `(roll, pitch, yaw)` of a unit quaternion, with the pitch in
+    /// `[-π/2, π/2]`; at a pitch of `±π/2` the roll and yaw are not unique.
+    pub fn to_euler(&self) -> (F, F, F)
+    {
+        let (zero, one): (F, F) = (F::additive_identity(), F::multiplicative_identity());
+        let roll: F = (two::<F>() * (self.w * self.x + self.y * self.z))
+            .atan2(one - two::<F>() * (self.x * self.x + self.y * self.y));
+        let sin_pitch: F = (two::<F>() * (self.w * self.y - self.z * self.x)).max(-one).min(one);
+        let pitch: F = sin_pitch.atan2((one - sin_pitch * sin_pitch).max(zero).sqrt());
+        let yaw: F = (two::<F>() * (self.w * self.z + self.x * self.y))
+            .atan2(one - two::<F>() * (self.y * self.y + self.z * self.z));
+        (roll, pitch, yaw)
+    }
+
+    /// Rotates a 3-vector by a unit quaternion, as `q v q*`.
+    pub fn rotate<S, V>(&self, space: &S, vector: &V) -> V
+    where
+        S: VectorSpace<Scalar=F, Vector=V> + Dimension<3>,
+        V: FromIterator<F>,
+        for<'a> &'a V: IntoIterator<Item=&'a F>
+    {
+        (*self * Quaternion::pure(space, vector) * self.conjugate()).vector_part(space)
+    }
+
+    /// The rotation matrix of a unit quaternion, row by row.
+    pub fn to_rotation_matrix(&self) -> [[F; 3]; 3]
+    {
+        let one: F = F::multiplicative_identity();
+        let t: F = two();
+        let Quaternion { w, x, y, z } = *self;
+        [
+            [one - t * (y * y + z * z), t * (x * y - w * z), t * (x * z + w * y)],
+            [t * (x * y + w * z), one - t * (x * x + z * z), t * (y * z - w * x)],
+            [t * (x * z - w * y), t * (y * z + w * x), one - t * (x * x + y * y)]
+        ]
+    }
+
+    /// Spherical linear interpolation between unit quaternions along the
+    /// shorter arc, at constant angular velocity for `t` from `0` to `1`.
+    pub fn slerp(&self, other: &Self, t: F) -> Self
+    {
+        let one: F = F::multiplicative_identity();
+        let (mut other, mut cos): (Self, F) = (*other, self.dot(other));
+        if cos < F::additive_identity() {
+            other = -other;
+            cos = -cos;
+        }
+        // Nearly parallel quaternions fall back to normalized linear
+        // interpolation, as the sine below vanishes.
+        if cos > one - F::from_f64(1e-6) {
+            let lerp: Self = *self + (other - *self).scale(t);
+            return lerp.normalize().unwrap_or(*self)
+        }
+        let sin: F = (one - cos * cos).sqrt();
+        let angle: F = sin.atan2(cos);
+        let a: F = ((one - t) * angle).sin() / sin;
+        let b: F = (t * angle).sin() / sin;
+        self.scale(a) + other.scale(b)
+    }
+}
+
+impl<F: RealField> Add for Quaternion<F>
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        Quaternion::new(self.w + rhs.w, self.x + rhs.x, self.y + rhs.y, self.z + rhs.z)
+    }
+}
+
+impl<F: RealField> Sub for Quaternion<F>
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        Quaternion::new(self.w - rhs.w, self.x - rhs.x, self.y - rhs.y, self.z - rhs.z)
+    }
+}
+
+impl<F: RealField> Neg for Quaternion<F>
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        Quaternion::new(-self.w, -self.x, -self.y, -self.z)
+    }
+}
+
+impl<F: RealField> Mul for Quaternion<F>
+{
+    type Output = Self;
+
+    /// The Hamilton product, with `i² = j² = k² = ijk = -1`.
+    fn mul(self, rhs: Self) -> Self
+    {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w
+        )
+    }
+}
+
+impl<F: RealField> Div for Quaternion<F>
+{
+    type Output = Self;
+
+    /// The right quotient `self * rhs⁻¹`; panics if `rhs` is zero.
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self
+    {
+        self * rhs.inverse().expect("division by the zero quaternion")
+    }
+}
+
+impl<F: RealField> AdditiveIdentity for Quaternion<F>
+{
+    type Output = Self;
+
+    fn additive_identity() -> Self::Output
+    {
+        Quaternion::real(F::additive_identity())
+    }
+}
+
+impl<F: RealField> MultiplicativeIdentity for Quaternion<F>
+{
+    type Output = Self;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Quaternion::real(F::multiplicative_identity())
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<F> Shrink for Quaternion<F>
+where
+    F: Shrink + Copy
+{
+    /// Shrinks one component at a time.
+    fn shrink(&self) -> Vec<Self>
+    {
+        let mut shrunk: Vec<Self> = Vec::new();
+        shrunk.extend(self.w.shrink().into_iter().map(|w| Quaternion { w, ..*self }));
+        shrunk.extend(self.x.shrink().into_iter().map(|x| Quaternion { x, ..*self }));
+        shrunk.extend(self.y.shrink().into_iter().map(|y| Quaternion { y, ..*self }));
+        shrunk.extend(self.z.shrink().into_iter().map(|z| Quaternion { z, ..*self }));
+        shrunk
+    }
+}
+
+/// Compares every component against the same tolerance.
+impl<F: AbsDiffEq> AbsDiffEq for Quaternion<F>
+{
+    type Epsilon = F::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon
+    {
+        F::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool
+    {
+        self.w.abs_diff_eq(&other.w, epsilon)
+            && self.x.abs_diff_eq(&other.x, epsilon)
+            && self.y.abs_diff_eq(&other.y, epsilon)
+            && self.z.abs_diff_eq(&other.z, epsilon)
+    }
+}
+
+impl<F: RelativeEq> RelativeEq for Quaternion<F>
+{
+    fn default_max_relative() -> Self::Epsilon
+    {
+        F::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, max_relative: Self::Epsilon) -> bool
+    {
+        self.w.relative_eq(&other.w, epsilon, max_relative)
+            && self.x.relative_eq(&other.x, epsilon, max_relative)
+            && self.y.relative_eq(&other.y, epsilon, max_relative)
+            && self.z.relative_eq(&other.z, epsilon, max_relative)
+    }
+}
+
+impl<F: UlpsEq> UlpsEq for Quaternion<F>
+{
+    fn default_max_ulps() -> u32
+    {
+        F::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool
+    {
+        self.w.ulps_eq(&other.w, epsilon, max_ulps)
+            && self.x.ulps_eq(&other.x, epsilon, max_ulps)
+            && self.y.ulps_eq(&other.y, epsilon, max_ulps)
+            && self.z.ulps_eq(&other.z, epsilon, max_ulps)
+    }
+}
+
+impl_assign_ops!{
+    copy [F: RealField] Quaternion<F>;
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div
+}
+
+impl_structures!{[F: RealField] Quaternion<F>; Magma, Semigroup, Monoid, Group, AbelianGroup, Semiring, Ring}
@@ -0,0 +1,529 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_bigint::BigInt;
+
+#[cfg(feature = "laws")]
+use crate::laws::Shrink;
+
+use super::{AbelianGroup, AdditiveIdentity, Field, Group, Magma, Monoid, MultiplicativeIdentity, Ring, Semigroup, Semiring, impl_assign_ops, impl_structures};
+
+/// An integer type that rationals can be built over.
+///
+/// The arithmetic is checked, returning `None` where a fixed-width primitive
+/// would overflow; `BigInt` never does.
+pub trait Integer:
+    Clone + Ord + Hash + fmt::Display
+    + AdditiveIdentity<Output=Self> + MultiplicativeIdentity<Output=Self>
+{
+    fn checked_add(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>;
+
+    /// The quotient rounded towards zero, or `None` for a zero divisor.
+    fn checked_div(&self, rhs: &Self) -> Option<Self>;
+
+    fn checked_neg(&self) -> Option<Self>;
+
+    /// The floored quotient and the remainder in `0..divisor`, for a positive divisor.
+    fn div_rem_floor(&self, divisor: &Self) -> (Self, Self);
+
+    fn is_zero(&self) -> bool
+    {
+        *self == Self::additive_identity()
+    }
+
+    fn is_negative(&self) -> bool
+    {
+        *self < Self::additive_identity()
+    }
+
+    /// A greatest common divisor by Euclid's algorithm, up to sign.
+    fn gcd(&self, other: &Self) -> Self
+    {
+        let (mut a, mut b): (Self, Self) = (self.clone(), other.clone());
+        while !b.is_zero() {
+            // Only `MIN / -1` overflows, and it divides exactly.
+            let remainder: Self = a
+                .checked_div(&b)
+                .and_then(|quotient| quotient.checked_mul(&b))
+                .and_then(|product| a.checked_sub(&product))
+                .unwrap_or_else(Self::additive_identity);
+            a = b;
+            b = remainder;
+        }
+        a
+    }
+}
+
+macro_rules! integers {
+    ($($primitive:ty),*) => {
+        $(
+            impl Integer for $primitive
+            {
+                fn checked_add(&self, rhs: &Self) -> Option<Self>
+                {
+                    <$primitive>::checked_add(*self, *rhs)
+                }
+
+                fn checked_sub(&self, rhs: &Self) -> Option<Self>
+                {
+                    <$primitive>::checked_sub(*self, *rhs)
+                }
+
+                fn checked_mul(&self, rhs: &Self) -> Option<Self>
+                {
+                    <$primitive>::checked_mul(*self, *rhs)
+                }
+
+                fn checked_div(&self, rhs: &Self) -> Option<Self>
+                {
+                    <$primitive>::checked_div(*self, *rhs)
+                }
+
+                fn checked_neg(&self) -> Option<Self>
+                {
+                    <$primitive>::checked_neg(*self)
+                }
+
+                fn div_rem_floor(&self, divisor: &Self) -> (Self, Self)
+                {
+                    (self.div_euclid(*divisor), self.rem_euclid(*divisor))
+                }
+            }
+        )*
+    };
+}
+
+integers!{
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+}
+
+/// An integer type with additive inverses, over which rationals form a field.
+///
+/// Rationals over unsigned integers have no negation and stop at `Semiring`.
+pub trait SignedInteger: Integer {}
+
+impl SignedInteger for i8 {}
+impl SignedInteger for i16 {}
+impl SignedInteger for i32 {}
+impl SignedInteger for i64 {}
+impl SignedInteger for i128 {}
+impl SignedInteger for isize {}
+impl SignedInteger for BigInt {}
+
+impl AdditiveIdentity for BigInt
+{
+    type Output = BigInt;
+
+    fn additive_identity() -> Self::Output
+    {
+        BigInt::from(0)
+    }
+}
+
+impl MultiplicativeIdentity for BigInt
+{
+    type Output = BigInt;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        BigInt::from(1)
+    }
+}
+
+impl Integer for BigInt
+{
+    fn checked_add(&self, rhs: &Self) -> Option<Self>
+    {
+        Some(self + rhs)
+    }
+
+    fn checked_sub(&self, rhs: &Self) -> Option<Self>
+    {
+        Some(self - rhs)
+    }
+
+    fn checked_mul(&self, rhs: &Self) -> Option<Self>
+    {
+        Some(self * rhs)
+    }
+
+    fn checked_div(&self, rhs: &Self) -> Option<Self>
+    {
+        if rhs.is_zero() { None } else { Some(self / rhs) }
+    }
+
+    fn checked_neg(&self) -> Option<Self>
+    {
+        Some(-self)
+    }
+
+    fn div_rem_floor(&self, divisor: &Self) -> (Self, Self)
+    {
+        let (quotient, remainder): (BigInt, BigInt) = (self / divisor, self % divisor);
+        if remainder.is_negative() {
+            (quotient - 1, remainder + divisor)
+        } else {
+            (quotient, remainder)
+        }
+    }
+}
+
+#[cfg(feature = "laws")]
+impl Shrink for BigInt
+{
+    /// Towards zero: zero itself, half the value and one step closer.
+    fn shrink(&self) -> Vec<Self>
+    {
+        if self.is_zero() {
+            return Vec::new()
+        }
+        let mut candidates: Vec<Self> = vec![BigInt::from(0)];
+        let half: BigInt = self / 2;
+        if !half.is_zero() {
+            candidates.push(half.clone());
+        }
+        let step: BigInt = if self.is_negative() { self + 1 } else { self - 1 };
+        if !step.is_zero() && step != half {
+            candidates.push(step);
+        }
+        candidates
+    }
+}
+
+/// An exact fraction of two integers, kept in lowest terms with a positive
+/// denominator.
+///
+/// Arithmetic over primitive integers panics when an intermediate result
+/// overflows, rather than wrapping; the `checked_*` methods return `None`
+/// instead. Negating a non-zero rational over an unsigned integer overflows,
+/// so those rationals are only a `Semiring`; see `SignedInteger`.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Rational<I>
+{
+    numerator: I,
+    denominator: I
+}
+
+/// A rational of arbitrary precision, which never overflows.
+pub type BigRational = Rational<BigInt>;
+
+impl<I> Rational<I>
+where
+    I: Integer
+{
+    /// The fraction `numerator / denominator` in lowest terms.
+    ///
+    /// Panics if the denominator is zero or normalising it overflows.
+    pub fn new(numerator: I, denominator: I) -> Self
+    {
+        assert!(!denominator.is_zero(), "the denominator of a rational must not be zero");
+        Self::checked_new(numerator, denominator).expect("overflow normalising a rational")
+    }
+
+    /// The fraction `numerator / denominator` in lowest terms, or `None` if the
+    /// denominator is zero or normalising it overflows.
+    pub fn checked_new(numerator: I, denominator: I) -> Option<Self>
+    {
+        if denominator.is_zero() {
+            return None
+        }
+        let divisor: I = numerator.gcd(&denominator);
+        let (mut numerator, mut denominator): (I, I) = (numerator.checked_div(&divisor)?, denominator.checked_div(&divisor)?);
+        if denominator.is_negative() {
+            numerator = numerator.checked_neg()?;
+            denominator = denominator.checked_neg()?;
+        }
+        Some(Rational { numerator, denominator })
+    }
+
+    pub fn integer(value: I) -> Self
+    {
+        Rational { numerator: value, denominator: I::multiplicative_identity() }
+    }
+
+    pub fn numerator(&self) -> &I
+    {
+        &self.numerator
+    }
+
+    /// The denominator, which is always positive.
+    pub fn denominator(&self) -> &I
+    {
+        &self.denominator
+    }
+
+    pub fn is_integer(&self) -> bool
+    {
+        self.denominator == I::multiplicative_identity()
+    }
+
+    /// The integer part, rounded towards zero.
+    pub fn trunc(&self) -> Self
+    {
+        Rational::integer(self.numerator.checked_div(&self.denominator).expect("the denominator is positive"))
+    }
+
+    pub fn checked_add(&self, rhs: &Self) -> Option<Self>
+    {
+        self.combine(rhs, I::checked_add)
+    }
+
+    pub fn checked_sub(&self, rhs: &Self) -> Option<Self>
+    {
+        self.combine(rhs, I::checked_sub)
+    }
+
+    /// Cross-cancels before multiplying, so the intermediate products are no
+    /// larger than the result.
+    pub fn checked_mul(&self, rhs: &Self) -> Option<Self>
+    {
+        let (left, right): (I, I) = (self.numerator.gcd(&rhs.denominator), rhs.numerator.gcd(&self.denominator));
+        let numerator: I = self.numerator.checked_div(&left)?.checked_mul(&rhs.numerator.checked_div(&right)?)?;
+        let denominator: I = self.denominator.checked_div(&right)?.checked_mul(&rhs.denominator.checked_div(&left)?)?;
+        Self::checked_new(numerator, denominator)
+    }
+
+    /// `None` for a zero divisor as well as on overflow.
+    pub fn checked_div(&self, rhs: &Self) -> Option<Self>
+    {
+        self.checked_mul(&rhs.recip()?)
+    }
+
+    pub fn checked_neg(&self) -> Option<Self>
+    {
+        Some(Rational { numerator: self.numerator.checked_neg()?, denominator: self.denominator.clone() })
+    }
+
+    /// The reciprocal, or `None` for zero.
+    pub fn recip(&self) -> Option<Self>
+    {
+        Self::checked_new(self.denominator.clone(), self.numerator.clone())
+    }
+
+    /// `a/b ∘ c/d = (a·(l/b) ∘ c·(l/d)) / l` over the least common multiple `l`.
+    fn combine<F>(&self, rhs: &Self, op: F) -> Option<Self>
+    where
+        F: Fn(&I, &I) -> Option<I>
+    {
+        if self.denominator == rhs.denominator {
+            return Self::checked_new(op(&self.numerator, &rhs.numerator)?, self.denominator.clone())
+        }
+        let divisor: I = self.denominator.gcd(&rhs.denominator);
+        let (left, right): (I, I) = (rhs.denominator.checked_div(&divisor)?, self.denominator.checked_div(&divisor)?);
+        let numerator: I = op(&self.numerator.checked_mul(&left)?, &rhs.numerator.checked_mul(&right)?)?;
+        Self::checked_new(numerator, self.denominator.checked_mul(&left)?)
+    }
+}
+
+impl<I> From<I> for Rational<I>
+where
+    I: Integer
+{
+    fn from(value: I) -> Self
+    {
+        Rational::integer(value)
+    }
+}
+
+impl<I> Ord for Rational<I>
+where
+    I: Integer
+{
+    /// Compares the continued fraction expansions, so no product can overflow.
+    fn cmp(&self, other: &Self) -> Ordering
+    {
+        let (mut a, mut b): (I, I) = (self.numerator.clone(), self.denominator.clone());
+        let (mut c, mut d): (I, I) = (other.numerator.clone(), other.denominator.clone());
+        let mut reversed: bool = false;
+        loop {
+            let (p, r) = a.div_rem_floor(&b);
+            let (q, s) = c.div_rem_floor(&d);
+            let ordering: Ordering = match p.cmp(&q) {
+                Ordering::Equal => match (r.is_zero(), s.is_zero()) {
+                    (true, true) => Ordering::Equal,
+                    (true, false) => Ordering::Less,
+                    (false, true) => Ordering::Greater,
+                    (false, false) => {
+                        // a/b = p + r/b and c/d = p + s/d, so compare d/s with b/r.
+                        a = b;
+                        b = r;
+                        c = d;
+                        d = s;
+                        reversed = !reversed;
+                        continue
+                    }
+                },
+                ordering => ordering
+            };
+            return if reversed { ordering.reverse() } else { ordering }
+        }
+    }
+}
+
+impl<I> PartialOrd for Rational<I>
+where
+    I: Integer
+{
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+    {
+        Some(self.cmp(other))
+    }
+}
+
+impl<I> Add for Rational<I>
+where
+    I: Integer
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        self.checked_add(&rhs).expect("attempt to add rationals with overflow")
+    }
+}
+
+impl<I> Sub for Rational<I>
+where
+    I: Integer
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        self.checked_sub(&rhs).expect("attempt to subtract rationals with overflow")
+    }
+}
+
+impl<I> Mul for Rational<I>
+where
+    I: Integer
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        self.checked_mul(&rhs).expect("attempt to multiply rationals with overflow")
+    }
+}
+
+impl<I> Div for Rational<I>
+where
+    I: Integer
+{
+    type Output = Self;
+
+    /// Panics if `rhs` is zero or the quotient overflows.
+    fn div(self, rhs: Self) -> Self
+    {
+        assert!(!rhs.numerator.is_zero(), "attempt to divide a rational by zero");
+        self.checked_div(&rhs).expect("attempt to divide rationals with overflow")
+    }
+}
+
+impl<I> Neg for Rational<I>
+where
+    I: Integer
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        self.checked_neg().expect("attempt to negate a rational with overflow")
+    }
+}
+
+impl_assign_ops!{
+    clone [I: Integer] Rational<I>;
+    AddAssign, add_assign, add;
+    SubAssign, sub_assign, sub;
+    MulAssign, mul_assign, mul;
+    DivAssign, div_assign, div
+}
+
+impl<I> AdditiveIdentity for Rational<I>
+where
+    I: Integer
+{
+    type Output = Self;
+
+    fn additive_identity() -> Self::Output
+    {
+        Rational::integer(I::additive_identity())
+    }
+}
+
+impl<I> MultiplicativeIdentity for Rational<I>
+where
+    I: Integer
+{
+    type Output = Self;
+
+    fn multiplicative_identity() -> Self::Output
+    {
+        Rational::integer(I::multiplicative_identity())
+    }
+}
+
+impl_structures!{[I: Integer] Rational<I>; Magma, Semigroup, Monoid, Semiring}
+impl_structures!{[I: SignedInteger] Rational<I>; Group, AbelianGroup, Ring, Field}
+
+impl<I> fmt::Display for Rational<I>
+where
+    I: Integer
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl<I> fmt::Debug for Rational<I>
+where
+    I: Integer
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "{}", self)
+    }
+}
+
+#[cfg(feature = "laws")]
+impl<I> Shrink for Rational<I>
+where
+    I: Integer + Shrink
+{
+    /// Zero, the integer part, then smaller numerators and denominators.
+    fn shrink(&self) -> Vec<Self>
+    {
+        if self.numerator.is_zero() {
+            return Vec::new()
+        }
+        let mut candidates: Vec<Self> = vec![Self::additive_identity()];
+        let mut push = |candidate: Self| {
+            if candidate != *self && !candidates.contains(&candidate) {
+                candidates.push(candidate);
+            }
+        };
+        push(self.trunc());
+        for numerator in self.numerator.shrink() {
+            push(Rational::new(numerator, self.denominator.clone()));
+        }
+        for denominator in self.denominator.shrink() {
+            if !denominator.is_zero() && !denominator.is_negative() {
+                push(Rational::new(self.numerator.clone(), denominator));
+            }
+        }
+        candidates
+    }
+}
@@ -0,0 +1,241 @@
+use std::fmt::Debug;
+
+use num_complex::Complex;
+
+use super::Field;
+
+/// A scalar that supports the arithmetic needed by numerical linear algebra.
+///
+/// Real scalars are complex scalars with a vanishing imaginary part, so
+/// algorithms written against `ComplexField` work for both.
+pub trait ComplexField: Field + Copy + PartialEq + Debug
+{
+    type Real: RealField;
+
+    fn from_real(re: Self::Real) -> Self;
+
+    fn real(self) -> Self::Real;
+
+    fn imaginary(self) -> Self::Real;
+
+    fn conjugate(self) -> Self;
+
+    /// The absolute value `|z|`.
+    fn modulus(self) -> Self::Real;
+
+    /// The squared absolute value `|z|^2`, which avoids a square root.
+    fn modulus_squared(self) -> Self::Real;
+
+    /// The principal square root.
+    fn sqrt(self) -> Self;
+
+    fn exp(self) -> Self;
+
+    /// Multiplies by a real factor.
+    fn scale(self, factor: Self::Real) -> Self
+    {
+        self * Self::from_real(factor)
+    }
+}
+
+/// A totally ordered real scalar.
+pub trait RealField: ComplexField<Real=Self> + PartialOrd
+{
+    /// The difference between `1` and the next representable value.
+    fn epsilon() -> Self;
+
+    fn from_f64(value: f64) -> Self;
+
+    fn to_f64(self) -> f64;
+
+    fn sin(self) -> Self;
+
+    fn cos(self) -> Self;
+
+    /// The natural logarithm.
+    fn ln(self) -> Self;
+
+    /// The angle of the point `(other, self)` from the positive x-axis, in
+    /// `[-π, π]`.
+    fn atan2(self, other: Self) -> Self;
+
+    /// The least representable value greater than `self`.
+    fn next_up(self) -> Self;
+
+    /// The greatest representable value less than `self`.
+    fn next_down(self) -> Self;
+
+    /// Whether `self` is neither infinite nor NaN.
+    fn is_finite(self) -> bool;
+
+    fn abs(self) -> Self
+    {
+        if self < Self::additive_identity() { -self } else { self }
+    }
+
+    fn max(self, other: Self) -> Self
+    {
+        if self < other { other } else { self }
+    }
+
+    fn min(self, other: Self) -> Self
+    {
+        if other < self { other } else { self }
+    }
+}
+
+macro_rules! fields {
+    ($($primitive:ident),*) => {
+        $(
+            impl ComplexField for $primitive
+            {
+                type Real = $primitive;
+
+                fn from_real(re: Self::Real) -> Self
+                {
+                    re
+                }
+
+                fn real(self) -> Self::Real
+                {
+                    self
+                }
+
+                fn imaginary(self) -> Self::Real
+                {
+                    0.0
+                }
+
+                fn conjugate(self) -> Self
+                {
+                    self
+                }
+
+                fn modulus(self) -> Self::Real
+                {
+                    $primitive::abs(self)
+                }
+
+                fn modulus_squared(self) -> Self::Real
+                {
+                    self * self
+                }
+
+                fn sqrt(self) -> Self
+                {
+                    $primitive::sqrt(self)
+                }
+
+                fn exp(self) -> Self
+                {
+                    $primitive::exp(self)
+                }
+            }
+
+            impl RealField for $primitive
+            {
+                fn epsilon() -> Self
+                {
+                    $primitive::EPSILON
+                }
+
+                fn from_f64(value: f64) -> Self
+                {
+                    value as $primitive
+                }
+
+                fn to_f64(self) -> f64
+                {
+                    self as f64
+                }
+
+                fn sin(self) -> Self
+                {
+                    $primitive::sin(self)
+                }
+
+                fn cos(self) -> Self
+                {
+                    $primitive::cos(self)
+                }
+
+                fn ln(self) -> Self
+                {
+                    $primitive::ln(self)
+                }
+
+                fn atan2(self, other: Self) -> Self
+                {
+                    $primitive::atan2(self, other)
+                }
+
+                fn next_up(self) -> Self
+                {
+                    $primitive::next_up(self)
+                }
+
+                fn next_down(self) -> Self
+                {
+                    $primitive::next_down(self)
+                }
+
+                fn is_finite(self) -> bool
+                {
+                    $primitive::is_finite(self)
+                }
+
+                fn abs(self) -> Self
+                {
+                    $primitive::abs(self)
+                }
+            }
+
+            impl ComplexField for Complex<$primitive>
+            {
+                type Real = $primitive;
+
+                fn from_real(re: Self::Real) -> Self
+                {
+                    Complex::new(re, 0.0)
+                }
+
+                fn real(self) -> Self::Real
+                {
+                    self.re
+                }
+
+                fn imaginary(self) -> Self::Real
+                {
+                    self.im
+                }
+
+                fn conjugate(self) -> Self
+                {
+                    self.conj()
+                }
+
+                fn modulus(self) -> Self::Real
+                {
+                    self.norm()
+                }
+
+                fn modulus_squared(self) -> Self::Real
+                {
+                    self.norm_sqr()
+                }
+
+                fn sqrt(self) -> Self
+                {
+                    Complex::sqrt(&self)
+                }
+
+                fn exp(self) -> Self
+                {
+                    Complex::exp(&self)
+                }
+            }
+        )*
+    };
+}
+
+fields!{f32, f64}
@@ -0,0 +1,346 @@
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use num_complex::Complex;
+
+use super::{AdditiveIdentity, MultiplicativeIdentity};
+
+/// A set closed under addition.
+///
+/// The hierarchy is written additively up to `AbelianGroup`; `Semiring` and
+/// `Ring` then add the multiplicative structure. The operators are
+/// supertraits, so a bound on one of these traits is all the generic code
+/// needs.
+///
+/// The traits only name those bounds: they are markers without methods, so a
+/// scalar type implements its binary operators and identities, and then
+/// `structure!` derives the compound assignments and opts it in to each
+/// level. The laws themselves are promises the compiler cannot check; `laws`
+/// tests them.
+pub trait Magma:
+    Sized + Clone
+    + Add<Output=Self> + AddAssign
+    + for<'a> AddAssign<&'a Self>
+{
+    // Supertrait.
+}
+
+/// A magma whose addition is associative.
+pub trait Semigroup: Magma
+{
+    // Supertrait.
+}
+
+/// A semigroup with an additive identity.
+pub trait Monoid: Semigroup + AdditiveIdentity<Output=Self>
+{
+    // Supertrait.
+}
+
+/// A monoid in which every element has an additive inverse.
+pub trait Group:
+    Monoid
+    + Neg<Output=Self> + Sub<Output=Self> + SubAssign
+    + for<'a> SubAssign<&'a Self>
+{
+    // Supertrait.
+}
+
+/// A group whose addition is commutative.
+pub trait AbelianGroup: Group
+{
+    // Supertrait.
+}
+
+/// A monoid with an associative multiplication that distributes over
+/// addition and has an identity, but possibly no additive inverses, like the
+/// unsigned integers.
+pub trait Semiring:
+    Monoid
+    + Mul<Output=Self> + MulAssign
+    + for<'a> MulAssign<&'a Self>
+    + MultiplicativeIdentity<Output=Self>
+{
+    // Supertrait.
+}
+
+/// A semiring whose addition forms an abelian group.
+pub trait Ring: AbelianGroup + Semiring
+{
+    // Supertrait.
+}
+
+/// A commutative ring in which every non-zero element has a multiplicative
+/// inverse.
+pub trait Field:
+    Ring
+    + Div<Output=Self> + DivAssign
+    + for<'a> DivAssign<&'a Self>
+{
+    // Supertrait.
+}
+
+/// `1 + 1`, for the factors of two in formulas over a generic scalar.
+pub(crate) fn two<F: Semiring>() -> F
+{
+    F::multiplicative_identity() + F::multiplicative_identity()
+}
+
+/// Opts a scalar type in to a level of the hierarchy and every level below
+/// it, deriving the compound assignment operators that level needs from the
+/// binary ones.
+///
+/// The type brings its binary operators and identities: `Add` and
+/// `AdditiveIdentity` for `Monoid`, then `Mul` and `MultiplicativeIdentity`
+/// for `Semiring`, `Neg` and `Sub` for `AbelianGroup` and `Ring`, and `Div` for
+/// `Field`. The first token says how the left operand is moved out of
+/// `&mut self`: `copy` dereferences it and `clone` clones it. The brackets
+/// hold the generics of the impls.
+///
+/// ```
+/// use std::ops::{Add, Mul};
+///
+/// use algebra::{AdditiveIdentity, MultiplicativeIdentity, Semiring};
+///
+/// /// The tropical semiring, with `min` as addition and `+` as multiplication.
+/// #[derive(Clone, Copy, Debug, PartialEq)]
+/// struct Tropical(f64);
+///
+/// impl Add for Tropical
+/// {
+///     type Output = Self;
+///
+///     fn add(self, rhs: Self) -> Self
+///     {
+///         Tropical(self.0.min(rhs.0))
+///     }
+/// }
+///
+/// impl Mul for Tropical
+/// {
+///     type Output = Self;
+///
+///     fn mul(self, rhs: Self) -> Self
+///     {
+///         Tropical(self.0 + rhs.0)
+///     }
+/// }
+///
+/// impl AdditiveIdentity for Tropical
+/// {
+///     type Output = Self;
+///
+///     fn additive_identity() -> Self
+///     {
+///         Tropical(f64::INFINITY)
+///     }
+/// }
+///
+/// impl MultiplicativeIdentity for Tropical
+/// {
+///     type Output = Self;
+///
+///     fn multiplicative_identity() -> Self
+///     {
+///         Tropical(0.0)
+///     }
+/// }
+///
+/// algebra::structure!{copy [] Tropical: Semiring}
+///
+/// fn cheapest<T: Semiring>(paths: &[(T, T)]) -> T
+/// {
+///     paths.iter().fold(T::additive_identity(), |mut acc, (l, r)| { acc += l.clone() * r.clone(); acc })
+/// }
+///
+/// assert_eq!(cheapest(&[(Tropical(1.0), Tropical(2.0)), (Tropical(0.5), Tropical(1.0))]), Tropical(1.5));
+/// ```
+#[macro_export]
+macro_rules! structure {
+    (
+        $mode:ident $generics:tt $type:ty: Monoid
+    ) => {
+        $crate::structure!(@assign $mode $generics $type; AddAssign, add_assign, +);
+        $crate::structure!(@mark $generics $type; Magma, Semigroup, Monoid);
+    };
+
+    (
+        $mode:ident $generics:tt $type:ty: AbelianGroup
+    ) => {
+        $crate::structure!($mode $generics $type: Monoid);
+        $crate::structure!(@assign $mode $generics $type; SubAssign, sub_assign, -);
+        $crate::structure!(@mark $generics $type; Group, AbelianGroup);
+    };
+
+    (
+        $mode:ident $generics:tt $type:ty: Semiring
+    ) => {
+        $crate::structure!($mode $generics $type: Monoid);
+        $crate::structure!(@assign $mode $generics $type; MulAssign, mul_assign, *);
+        $crate::structure!(@mark $generics $type; Semiring);
+    };
+
+    (
+        $mode:ident $generics:tt $type:ty: Ring
+    ) => {
+        $crate::structure!($mode $generics $type: Semiring);
+        $crate::structure!(@assign $mode $generics $type; SubAssign, sub_assign, -);
+        $crate::structure!(@mark $generics $type; Group, AbelianGroup, Ring);
+    };
+
+    (
+        $mode:ident $generics:tt $type:ty: Field
+    ) => {
+        $crate::structure!($mode $generics $type: Ring);
+        $crate::structure!(@assign $mode $generics $type; DivAssign, div_assign, /);
+        $crate::structure!(@mark $generics $type; Field);
+    };
+
+    (
+        @assign $mode:ident [$($generics:tt)*] $type:ty; $trait:ident, $method:ident, $op:tt
+    ) => {
+        impl<$($generics)*> ::std::ops::$trait for $type
+        {
+            fn $method(&mut self, rhs: Self)
+            {
+                *self = $crate::structure!(@lhs $mode self) $op rhs;
+            }
+        }
+
+        impl<'a, $($generics)*> ::std::ops::$trait<&'a $type> for $type
+        {
+            fn $method(&mut self, rhs: &'a Self)
+            {
+                *self = $crate::structure!(@lhs $mode self) $op $crate::structure!(@lhs $mode rhs);
+            }
+        }
+    };
+
+    (
+        @mark $generics:tt $type:ty; $($trait:ident),*
+    ) => {
+        $(
+            $crate::structure!(@impl $generics $type; $trait);
+        )*
+    };
+
+    (
+        @impl [$($generics:tt)*] $type:ty; $trait:ident
+    ) => {
+        impl<$($generics)*> $crate::$trait for $type {}
+    };
+
+    (@lhs copy $value:ident) => { *$value };
+    (@lhs clone $value:ident) => { $value.clone() };
+}
+
+/// Implements compound assignment operators in terms of the binary ones, by
+/// value and by reference, for a scalar type with the given generics.
+///
+/// The first token says how the left operand is moved out of `&mut self`:
+/// `copy` dereferences it, `clone` clones it and `take` leaves the `Default`
+/// behind.
+macro_rules! impl_assign_ops {
+    (
+        $mode:ident $generics:tt $type:ty; $($trait:ident, $method:ident, $op:ident);*
+    ) => {
+        $(
+            impl_assign_ops!(@impl $mode $generics $type; $trait, $method, $op);
+        )*
+    };
+
+    (
+        @impl $mode:ident [$($generics:tt)*] $type:ty; $trait:ident, $method:ident, $op:ident
+    ) => {
+        impl<$($generics)*> $trait for $type
+        {
+            fn $method(&mut self, rhs: Self)
+            {
+                *self = impl_assign_ops!(@lhs $mode self).$op(rhs);
+            }
+        }
+
+        impl<'a, $($generics)*> $trait<&'a $type> for $type
+        {
+            fn $method(&mut self, rhs: &'a Self)
+            {
+                *self = impl_assign_ops!(@lhs $mode self).$op(impl_assign_ops!(@rhs $mode rhs));
+            }
+        }
+    };
+
+    (@lhs copy $value:ident) => { (*$value) };
+    (@lhs clone $value:ident) => { $value.clone() };
+    (@lhs take $value:ident) => { std::mem::take($value) };
+
+    (@rhs copy $value:ident) => { *$value };
+    (@rhs clone $value:ident) => { $value.clone() };
+    (@rhs take $value:ident) => { $value.clone() };
+}
+
+pub(crate) use impl_assign_ops;
+
+/// Opts a scalar type with the given generics in to each listed structure.
+macro_rules! impl_structures {
+    (
+        $generics:tt $type:ty; $($trait:ident),*
+    ) => {
+        $(
+            impl_structures!(@impl $generics $type; $trait);
+        )*
+    };
+
+    (
+        @impl [$($generics:tt)*] $type:ty; $trait:ident
+    ) => {
+        impl<$($generics)*> $trait for $type {}
+    };
+}
+
+pub(crate) use impl_structures;
+
+macro_rules! structures {
+    (
+        @monoid $($primitive:ty),*
+    ) => {
+        $(
+            impl Magma for $primitive {}
+            impl Semigroup for $primitive {}
+            impl Monoid for $primitive {}
+        )*
+    };
+
+    (
+        @semiring $($primitive:ty),*
+    ) => {
+        $(
+            structures!(@monoid $primitive);
+            impl Semiring for $primitive {}
+        )*
+    };
+
+    (
+        @ring $($primitive:ty),*
+    ) => {
+        $(
+            structures!(@semiring $primitive);
+            impl Group for $primitive {}
+            impl AbelianGroup for $primitive {}
+            impl Ring for $primitive {}
+        )*
+    };
+
+    (
+        @field $($primitive:ty),*
+    ) => {
+        $(
+            structures!(@ring $primitive);
+            impl Field for $primitive {}
+        )*
+    };
+}
+
+structures!{@semiring u8, u16, u32, u64, u128, usize}
+
+structures!{@ring i8, i16, i32, i64, i128, isize}
+
+structures!{@field f32, f64, Complex<f32>, Complex<f64>}
@@ -0,0 +1,433 @@
+//! Property tests for the algebraic axioms.
+//!
+//! A `Laws` run draws random scalars and vectors from user-supplied
+//! generators, checks every axiom of a ring, a field, a vector space or a
+//! module on them, and shrinks the first failing case to a minimal
+//! `Counterexample`.
+//!
+//! The module is behind the `laws` feature, which pulls in `rand`; the
+//! `Shrink` impls of the scalar types come with it.
+//!
+//! ```
+//! use algebra::{FiniteDimensional, VAdd, VAdditiveInverse, VScale, VectorSpace};
+//! use algebra::laws::{Exact, Laws, Rng, StdRng};
+//!
+//! /// Pairs of integers, a module over the integers.
+//! struct Pairs;
+//!
+//! impl VectorSpace for Pairs
+//! {
+//!     type Scalar = i64;
+//!     type Vector = Vec<i64>;
+//! }
+//!
+//! impl FiniteDimensional for Pairs
+//! {
+//!     fn dimension(&self) -> usize
+//!     {
+//!         2
+//!     }
+//! }
+//!
+//! impl VAdd for Pairs
+//! {
+//!     type Vector = Vec<i64>;
+//!
+//!     fn vadd(&self, lhs: &Vec<i64>, rhs: &Vec<i64>) -> Vec<i64>
+//!     {
+//!         lhs.iter().zip(rhs).map(|(l, r)| l + r).collect()
+//!     }
+//! }
+//!
+//! impl VScale for Pairs
+//! {
+//!     type Scalar = i64;
+//!     type Vector = Vec<i64>;
+//!
+//!     fn vscale(&self, vector: &Vec<i64>, scalar: &i64) -> Vec<i64>
+//!     {
+//!         vector.iter().map(|val| val * scalar).collect()
+//!     }
+//! }
+//!
+//! impl VAdditiveInverse for Pairs
+//! {
+//!     type Vector = Vec<i64>;
+//!
+//!     fn additive_inv(&self, vector: &Vec<i64>) -> Vec<i64>
+//!     {
+//!         vector.iter().map(|val| -val).collect()
+//!     }
+//! }
+//!
+//! let vectors = |rng: &mut StdRng| (0..2).map(|_| rng.gen_range(-9i64, 9)).collect();
+//! Laws::default().module(&Pairs, vectors, |rng| rng.gen_range(-9i64, 9), Exact).unwrap();
+//! ```
+
+use std::fmt;
+use std::iter::FromIterator;
+
+pub use rand::{Rng, SeedableRng};
+pub use rand::rngs::StdRng;
+
+use crate::{ApproxEq, Field, FiniteDimensional, Ring, Tolerance, VAdd, VAdditiveInverse, VScale};
+
+mod shrink;
+pub use shrink::*;
+
+/// The number of shrinking steps after which a counterexample is reported as is.
+const MAX_SHRINKS: usize = 1024;
+
+/// How the two sides of an axiom are compared.
+///
+/// Any `Fn(&T, &T) -> bool` is an equivalence, so custom ones are closures.
+pub trait Equivalence<T>
+{
+    fn equivalent(&self, lhs: &T, rhs: &T) -> bool;
+}
+
+impl<T, F> Equivalence<T> for F
+where
+    F: Fn(&T, &T) -> bool
+{
+    fn equivalent(&self, lhs: &T, rhs: &T) -> bool
+    {
+        self(lhs, rhs)
+    }
+}
+
+/// Exact equality, for integer and other exact scalars.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Exact;
+
+impl<T> Equivalence<T> for Exact
+where
+    T: PartialEq
+{
+    fn equivalent(&self, lhs: &T, rhs: &T) -> bool
+    {
+        lhs == rhs
+    }
+}
+
+/// Approximate equality, for floating-point scalars whose axioms only hold up
+/// to rounding.
+#[derive(Clone, Copy, Debug)]
+pub struct Approximate<E>(pub Tolerance<E>);
+
+impl<T, E> Equivalence<T> for Approximate<E>
+where
+    T: ApproxEq<Epsilon=E>,
+    E: Copy
+{
+    fn equivalent(&self, lhs: &T, rhs: &T) -> bool
+    {
+        lhs.approx_eq(rhs, self.0)
+    }
+}
+
+/// A minimal case on which an axiom fails.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Counterexample<T, V>
+{
+    pub law: &'static str,
+    pub scalars: Vec<T>,
+    pub vectors: Vec<V>
+}
+
+impl<T, V> fmt::Display for Counterexample<T, V>
+where
+    T: fmt::Debug,
+    V: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(f, "`{}` fails for scalars {:?}", self.law, self.scalars)?;
+        if !self.vectors.is_empty() {
+            write!(f, " and vectors {:?}", self.vectors)?;
+        }
+        Ok(())
+    }
+}
+
+/// Whether an axiom holds for the given scalars and vectors.
+type Holds<'l, T, V> = Box<dyn Fn(&[T], &[V]) -> bool + 'l>;
+
+/// An axiom over a number of scalars and vectors.
+struct Law<'l, T, V>
+{
+    name: &'static str,
+    scalars: usize,
+    vectors: usize,
+    holds: Holds<'l, T, V>
+}
+
+impl<'l, T, V> Law<'l, T, V>
+{
+    fn new<F>(name: &'static str, scalars: usize, vectors: usize, holds: F) -> Self
+    where
+        F: Fn(&[T], &[V]) -> bool + 'l
+    {
+        Law { name, scalars, vectors, holds: Box::new(holds) }
+    }
+}
+
+/// How many random cases to check and the seed they are drawn from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Laws
+{
+    pub cases: usize,
+    pub seed: u64
+}
+
+impl Laws
+{
+    pub fn new(cases: usize, seed: u64) -> Self
+    {
+        Laws { cases, seed }
+    }
+
+    /// Checks the axioms of a ring with identity: `+` is an abelian group,
+    /// `·` is associative with identity `1`, and `·` distributes over `+`.
+    pub fn ring<T, G, E>(&self, scalars: G, equivalence: E) -> Result<(), Counterexample<T, ()>>
+    where
+        T: Ring + Shrink,
+        G: FnMut(&mut StdRng) -> T,
+        E: Equivalence<T>
+    {
+        let laws: Vec<Law<T, ()>> = ring_laws(&equivalence);
+        self.run(&laws, scalars, |_| (), |_| Vec::new())
+    }
+
+    /// Checks the axioms of a field: those of a commutative ring, plus an
+    /// inverse `a⁻¹` with `a · a⁻¹ = 1` for every `a ≠ 0`.
+    pub fn field<T, G, E>(&self, scalars: G, equivalence: E) -> Result<(), Counterexample<T, ()>>
+    where
+        T: Field + Shrink,
+        G: FnMut(&mut StdRng) -> T,
+        E: Equivalence<T>
+    {
+        let laws: Vec<Law<T, ()>> = field_laws(&equivalence);
+        self.run(&laws, scalars, |_| (), |_| Vec::new())
+    }
+
+    /// Checks the axioms of a vector space over the scalars of `space`, and
+    /// the field axioms on the scalars from the same generator.
+    ///
+    /// Vectors are compared entrywise under `equivalence`, and vectors of
+    /// different lengths are never equal.
+    pub fn vector_space<S, T, V, GV, GS, E>(&self, space: &S, vectors: GV, scalars: GS, equivalence: E) -> Result<(), Counterexample<T, V>>
+    where
+        S: FiniteDimensional<Scalar=T, Vector=V> + VAdd<Vector=V> + VScale<Scalar=T, Vector=V> + VAdditiveInverse<Vector=V>,
+        T: Field + Shrink,
+        V: Clone + FromIterator<T>,
+        for<'a> &'a V: IntoIterator<Item=&'a T>,
+        GV: FnMut(&mut StdRng) -> V,
+        GS: FnMut(&mut StdRng) -> T,
+        E: Equivalence<T>
+    {
+        let e: &E = &equivalence;
+        let same = entrywise(e);
+        let zero: V = (0..space.dimension()).map(|_| T::additive_identity()).collect();
+
+        let mut laws: Vec<Law<T, V>> = field_laws(e);
+        laws.extend(vector_laws(space, &same, &zero));
+        self.run(&laws, scalars, vectors, shrink_entries)
+    }
+
+    /// Checks the axioms of a module over the scalars of `space`, for scalars
+    /// that only form a ring, and the ring axioms on the scalars from the same
+    /// generator.
+    ///
+    /// The module axioms are those of `vector_space`.
+    pub fn module<S, T, V, GV, GS, E>(&self, space: &S, vectors: GV, scalars: GS, equivalence: E) -> Result<(), Counterexample<T, V>>
+    where
+        S: FiniteDimensional<Scalar=T, Vector=V> + VAdd<Vector=V> + VScale<Scalar=T, Vector=V> + VAdditiveInverse<Vector=V>,
+        T: Ring + Shrink,
+        V: Clone + FromIterator<T>,
+        for<'a> &'a V: IntoIterator<Item=&'a T>,
+        GV: FnMut(&mut StdRng) -> V,
+        GS: FnMut(&mut StdRng) -> T,
+        E: Equivalence<T>
+    {
+        let e: &E = &equivalence;
+        let same = entrywise(e);
+        let zero: V = (0..space.dimension()).map(|_| T::additive_identity()).collect();
+
+        let mut laws: Vec<Law<T, V>> = ring_laws(e);
+        laws.extend(vector_laws(space, &same, &zero));
+        self.run(&laws, scalars, vectors, shrink_entries)
+    }
+
+    fn run<T, V, GS, GV, SV>(&self, laws: &[Law<T, V>], mut scalars: GS, mut vectors: GV, shrink_vector: SV) -> Result<(), Counterexample<T, V>>
+    where
+        T: Clone + Shrink,
+        V: Clone,
+        GS: FnMut(&mut StdRng) -> T,
+        GV: FnMut(&mut StdRng) -> V,
+        SV: Fn(&V) -> Vec<V>
+    {
+        let mut rng: StdRng = StdRng::seed_from_u64(self.seed);
+        for _ in 0..self.cases {
+            for law in laws {
+                let s: Vec<T> = (0..law.scalars).map(|_| scalars(&mut rng)).collect();
+                let v: Vec<V> = (0..law.vectors).map(|_| vectors(&mut rng)).collect();
+                if !(law.holds)(&s, &v) {
+                    return Err(minimise(law, s, v, &shrink_vector))
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for Laws
+{
+    fn default() -> Self
+    {
+        Laws::new(256, 0x5eed)
+    }
+}
+
+fn ring_laws<'l, T, V, E>(e: &'l E) -> Vec<Law<'l, T, V>>
+where
+    T: Ring,
+    E: Equivalence<T>
+{
+    vec![
+        Law::new("addition is associative", 3, 0, move |s: &[T], _: &[V]| e.equivalent(&(s[0].clone() + s[1].clone() + s[2].clone()), &(s[0].clone() + (s[1].clone() + s[2].clone())))),
+        Law::new("addition is commutative", 2, 0, move |s: &[T], _: &[V]| e.equivalent(&(s[0].clone() + s[1].clone()), &(s[1].clone() + s[0].clone()))),
+        Law::new("zero is an additive identity", 1, 0, move |s: &[T], _: &[V]| e.equivalent(&(s[0].clone() + T::additive_identity()), &s[0])),
+        Law::new("scalars have an additive inverse", 1, 0, move |s: &[T], _: &[V]| e.equivalent(&(s[0].clone() + -s[0].clone()), &T::additive_identity())),
+        Law::new("multiplication is associative", 3, 0, move |s: &[T], _: &[V]| e.equivalent(&(s[0].clone() * s[1].clone() * s[2].clone()), &(s[0].clone() * (s[1].clone() * s[2].clone())))),
+        Law::new("one is a multiplicative identity", 1, 0, move |s: &[T], _: &[V]| {
+            let one: T = T::multiplicative_identity();
+            e.equivalent(&(s[0].clone() * one.clone()), &s[0]) && e.equivalent(&(one * s[0].clone()), &s[0])
+        }),
+        Law::new("multiplication distributes over addition", 3, 0, move |s: &[T], _: &[V]| {
+            let left: bool = e.equivalent(&(s[0].clone() * (s[1].clone() + s[2].clone())), &(s[0].clone() * s[1].clone() + s[0].clone() * s[2].clone()));
+            let right: bool = e.equivalent(&((s[1].clone() + s[2].clone()) * s[0].clone()), &(s[1].clone() * s[0].clone() + s[2].clone() * s[0].clone()));
+            left && right
+        })
+    ]
+}
+
+fn field_laws<'l, T, V, E>(e: &'l E) -> Vec<Law<'l, T, V>>
+where
+    T: Field,
+    E: Equivalence<T>
+{
+    let mut laws: Vec<Law<T, V>> = ring_laws(e);
+    laws.push(Law::new("multiplication is commutative", 2, 0, move |s: &[T], _: &[V]| {
+        e.equivalent(&(s[0].clone() * s[1].clone()), &(s[1].clone() * s[0].clone()))
+    }));
+    laws.push(Law::new("non-zero scalars have a multiplicative inverse", 1, 0, move |s: &[T], _: &[V]| {
+        let (zero, one): (T, T) = (T::additive_identity(), T::multiplicative_identity());
+        e.equivalent(&s[0], &zero) || e.equivalent(&(s[0].clone() * (one.clone() / s[0].clone())), &one)
+    }));
+    laws
+}
+
+/// Entrywise equality under `e`, where vectors of different lengths differ.
+fn entrywise<'l, T, V, E>(e: &'l E) -> impl Fn(&V, &V) -> bool + 'l
+where
+    for<'a> &'a V: IntoIterator<Item=&'a T>,
+    E: Equivalence<T>
+{
+    move |lhs: &V, rhs: &V| {
+        lhs.into_iter().count() == rhs.into_iter().count()
+            && lhs.into_iter().zip(rhs).all(|(l, r)| e.equivalent(l, r))
+    }
+}
+
+fn vector_laws<'l, S, T, V>(space: &'l S, same: &'l dyn Fn(&V, &V) -> bool, zero: &'l V) -> Vec<Law<'l, T, V>>
+where
+    S: VAdd<Vector=V> + VScale<Scalar=T, Vector=V> + VAdditiveInverse<Vector=V>,
+    T: Ring
+{
+    vec![
+        Law::new("vector addition is associative", 0, 3, move |_: &[T], v: &[V]| {
+            same(&space.vadd(&space.vadd(&v[0], &v[1]), &v[2]), &space.vadd(&v[0], &space.vadd(&v[1], &v[2])))
+        }),
+        Law::new("vector addition is commutative", 0, 2, move |_: &[T], v: &[V]| {
+            same(&space.vadd(&v[0], &v[1]), &space.vadd(&v[1], &v[0]))
+        }),
+        Law::new("the zero vector is an additive identity", 0, 1, move |_: &[T], v: &[V]| {
+            same(&space.vadd(&v[0], zero), &v[0])
+        }),
+        Law::new("vectors have an additive inverse", 0, 1, move |_: &[T], v: &[V]| {
+            same(&space.vadd(&v[0], &space.additive_inv(&v[0])), zero)
+        }),
+        Law::new("scaling is compatible with scalar multiplication", 2, 1, move |s: &[T], v: &[V]| {
+            same(&space.vscale(&space.vscale(&v[0], &s[1]), &s[0]), &space.vscale(&v[0], &(s[0].clone() * s[1].clone())))
+        }),
+        Law::new("scaling by one is the identity", 0, 1, move |_: &[T], v: &[V]| {
+            same(&space.vscale(&v[0], &T::multiplicative_identity()), &v[0])
+        }),
+        Law::new("scaling distributes over vector addition", 1, 2, move |s: &[T], v: &[V]| {
+            same(&space.vscale(&space.vadd(&v[0], &v[1]), &s[0]), &space.vadd(&space.vscale(&v[0], &s[0]), &space.vscale(&v[1], &s[0])))
+        }),
+        Law::new("scaling distributes over scalar addition", 2, 1, move |s: &[T], v: &[V]| {
+            same(&space.vscale(&v[0], &(s[0].clone() + s[1].clone())), &space.vadd(&space.vscale(&v[0], &s[0]), &space.vscale(&v[0], &s[1])))
+        })
+    ]
+}
+
+/// Shrinks a vector one entry at a time.
+fn shrink_entries<T, V>(vector: &V) -> Vec<V>
+where
+    T: Clone + Shrink,
+    V: FromIterator<T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    let entries: Vec<T> = vector.into_iter().cloned().collect();
+    let mut candidates: Vec<V> = Vec::new();
+    for (idx, entry) in entries.iter().enumerate() {
+        for smaller in entry.shrink() {
+            let mut shrunk: Vec<T> = entries.clone();
+            shrunk[idx] = smaller;
+            candidates.push(shrunk.into_iter().collect());
+        }
+    }
+    candidates
+}
+
+/// Greedily replaces one scalar or vector of a failing case by a smaller one
+/// for as long as the law keeps failing.
+fn minimise<T, V, SV>(law: &Law<T, V>, mut scalars: Vec<T>, mut vectors: Vec<V>, shrink_vector: &SV) -> Counterexample<T, V>
+where
+    T: Clone + Shrink,
+    V: Clone,
+    SV: Fn(&V) -> Vec<V>
+{
+    for _ in 0..MAX_SHRINKS {
+        let mut smaller: Option<(Vec<T>, Vec<V>)> = None;
+        'search: for idx in 0..scalars.len() {
+            for candidate in scalars[idx].shrink() {
+                let mut s: Vec<T> = scalars.clone();
+                s[idx] = candidate;
+                if !(law.holds)(&s, &vectors) {
+                    smaller = Some((s, vectors.clone()));
+                    break 'search
+                }
+            }
+        }
+        if smaller.is_none() {
+            'vectors: for idx in 0..vectors.len() {
+                for candidate in shrink_vector(&vectors[idx]) {
+                    let mut v: Vec<V> = vectors.clone();
+                    v[idx] = candidate;
+                    if !(law.holds)(&scalars, &v) {
+                        smaller = Some((scalars.clone(), v));
+                        break 'vectors
+                    }
+                }
+            }
+        }
+        match smaller {
+            Some((s, v)) => { scalars = s; vectors = v; },
+            None => break
+        }
+    }
+    Counterexample { law: law.name, scalars, vectors }
+}
@@ -0,0 +1,92 @@
+use num_complex::Complex;
+
+/// Smaller values to try when minimising a counterexample.
+///
+/// Every candidate should be strictly simpler than `self`, so that shrinking
+/// ends; the default of no candidates reports counterexamples as generated.
+pub trait Shrink: Sized
+{
+    fn shrink(&self) -> Vec<Self>
+    {
+        Vec::new()
+    }
+}
+
+macro_rules! shrink {
+    (
+        $($primitive:ty),*
+    ) => {
+        $(
+            impl Shrink for $primitive
+            {
+                /// Towards zero: zero itself, half the value and one step closer.
+                fn shrink(&self) -> Vec<Self>
+                {
+                    if *self == 0 {
+                        return Vec::new()
+                    }
+                    let mut candidates: Vec<Self> = vec![0];
+                    let half: Self = *self / 2;
+                    if half != 0 {
+                        candidates.push(half);
+                    }
+                    let step: Self = if *self > 0 { *self - 1 } else { *self + 1 };
+                    if step != 0 && step != half {
+                        candidates.push(step);
+                    }
+                    candidates
+                }
+            }
+        )*
+    };
+
+    (
+        @float $($primitive:ty),*
+    ) => {
+        $(
+            impl Shrink for $primitive
+            {
+                /// Towards fewer digits: zero itself, then the value truncated
+                /// to at most six decimal places where that stays finite.
+                fn shrink(&self) -> Vec<Self>
+                {
+                    if *self == 0.0 || !self.is_finite() {
+                        return Vec::new()
+                    }
+                    let mut candidates: Vec<Self> = vec![0.0];
+                    let mut scale: Self = 1.0;
+                    for _ in 0..=6 {
+                        // Scaling a value near the maximum overflows.
+                        let truncated: Self = (*self * scale).trunc() / scale;
+                        if truncated.is_finite() && truncated != *self && !candidates.contains(&truncated) {
+                            candidates.push(truncated);
+                        }
+                        scale *= 10.0;
+                    }
+                    candidates
+                }
+            }
+
+            impl Shrink for Complex<$primitive>
+            {
+                /// Shrinks the real and imaginary parts one at a time.
+                fn shrink(&self) -> Vec<Self>
+                {
+                    self.re
+                        .shrink()
+                        .into_iter()
+                        .map(|re| Complex::new(re, self.im))
+                        .chain(self.im.shrink().into_iter().map(|im| Complex::new(self.re, im)))
+                        .collect()
+                }
+            }
+        )*
+    };
+}
+
+shrink!{
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize
+}
+
+shrink!{@float f32, f64}
@@ -3,3 +3,12 @@ pub use vector_space::*;
 
 mod field;
 pub use field::*;
+
+mod operator;
+pub use operator::*;
+
+pub use num_complex::Complex;
+pub use num_bigint::BigInt;
+
+#[cfg(feature = "laws")]
+pub mod laws;
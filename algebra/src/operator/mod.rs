@@ -0,0 +1,258 @@
+use std::marker::PhantomData;
+
+use crate::{ComplexField, VAddMut, VScaleMut, VectorSpace};
+
+/// The vectors an operator `L` acts on.
+pub type DomainVector<L> = <<L as LinearOperator>::Domain as VectorSpace>::Vector;
+
+/// The vectors an operator `L` produces.
+pub type CodomainVector<L> = <<L as LinearOperator>::Codomain as VectorSpace>::Vector;
+
+/// A linear map `A: Domain → Codomain` between two vector spaces over the same scalars.
+///
+/// Only the action of `A` and of its adjoint `Aᴴ` on vectors is required, so
+/// dense matrices, sparse matrices and matrix-free products all fit.
+pub trait LinearOperator
+{
+    type Domain: VectorSpace;
+
+    type Codomain: VectorSpace<Scalar=<Self::Domain as VectorSpace>::Scalar>;
+
+    /// Computes `Ax`.
+    fn apply(&self, x: &DomainVector<Self>) -> CodomainVector<Self>;
+
+    /// Computes `y ← Ax`, reusing the storage of `y` where the operator can.
+    fn apply_mut(&self, x: &DomainVector<Self>, y: &mut CodomainVector<Self>)
+    {
+        *y = self.apply(x);
+    }
+
+    /// Computes `Aᴴy`.
+    fn adjoint_apply(&self, y: &CodomainVector<Self>) -> DomainVector<Self>;
+
+    /// The composition `A ∘ B`, which applies `inner` first.
+    fn compose<B>(self, inner: B) -> Composition<Self, B>
+    where
+        Self: Sized,
+        B: LinearOperator<Codomain=Self::Domain>
+    {
+        Composition { outer: self, inner }
+    }
+
+    /// The sum `A + B`.
+    fn sum<B>(self, other: B) -> Sum<Self, B>
+    where
+        Self: Sized,
+        B: LinearOperator<Domain=Self::Domain, Codomain=Self::Codomain>
+    {
+        Sum { lhs: self, rhs: other }
+    }
+
+    /// The scaled operator `αA`.
+    fn scale(self, factor: <Self::Domain as VectorSpace>::Scalar) -> Scaled<Self, <Self::Domain as VectorSpace>::Scalar>
+    where
+        Self: Sized
+    {
+        Scaled { operator: self, factor }
+    }
+}
+
+impl<L> LinearOperator for &L
+where
+    L: LinearOperator
+{
+    type Domain = L::Domain;
+
+    type Codomain = L::Codomain;
+
+    fn apply(&self, x: &DomainVector<L>) -> CodomainVector<L>
+    {
+        (**self).apply(x)
+    }
+
+    fn apply_mut(&self, x: &DomainVector<L>, y: &mut CodomainVector<L>)
+    {
+        (**self).apply_mut(x, y)
+    }
+
+    fn adjoint_apply(&self, y: &CodomainVector<L>) -> DomainVector<L>
+    {
+        (**self).adjoint_apply(y)
+    }
+}
+
+/// The composition `A ∘ B`, see `LinearOperator::compose`.
+#[derive(Clone, Copy, Debug)]
+pub struct Composition<A, B>
+{
+    outer: A,
+    inner: B
+}
+
+impl<A, B> LinearOperator for Composition<A, B>
+where
+    A: LinearOperator,
+    B: LinearOperator<Codomain=A::Domain>,
+    A::Codomain: VectorSpace<Scalar=<B::Domain as VectorSpace>::Scalar>
+{
+    type Domain = B::Domain;
+
+    type Codomain = A::Codomain;
+
+    fn apply(&self, x: &DomainVector<B>) -> CodomainVector<A>
+    {
+        self.outer.apply(&self.inner.apply(x))
+    }
+
+    fn apply_mut(&self, x: &DomainVector<B>, y: &mut CodomainVector<A>)
+    {
+        self.outer.apply_mut(&self.inner.apply(x), y)
+    }
+
+    /// `(AB)ᴴ = BᴴAᴴ`.
+    fn adjoint_apply(&self, y: &CodomainVector<A>) -> DomainVector<B>
+    {
+        self.inner.adjoint_apply(&self.outer.adjoint_apply(y))
+    }
+}
+
+/// The sum `A + B`, see `LinearOperator::sum`.
+#[derive(Clone, Copy, Debug)]
+pub struct Sum<A, B>
+{
+    lhs: A,
+    rhs: B
+}
+
+impl<A, B> LinearOperator for Sum<A, B>
+where
+    A: LinearOperator,
+    B: LinearOperator<Domain=A::Domain, Codomain=A::Codomain>,
+    A::Domain: VAddMut<Vector=DomainVector<A>> + Default,
+    A::Codomain: VAddMut<Vector=CodomainVector<A>> + Default
+{
+    type Domain = A::Domain;
+
+    type Codomain = A::Codomain;
+
+    fn apply(&self, x: &DomainVector<A>) -> CodomainVector<A>
+    {
+        let mut y = self.lhs.apply(x);
+        A::Codomain::default().vadd_mut(&mut y, &self.rhs.apply(x));
+        y
+    }
+
+    fn adjoint_apply(&self, y: &CodomainVector<A>) -> DomainVector<A>
+    {
+        let mut x = self.lhs.adjoint_apply(y);
+        A::Domain::default().vadd_mut(&mut x, &self.rhs.adjoint_apply(y));
+        x
+    }
+}
+
+/// The scaled operator `αA`, see `LinearOperator::scale`.
+#[derive(Clone, Copy, Debug)]
+pub struct Scaled<A, T>
+{
+    operator: A,
+    factor: T
+}
+
+impl<A, T> LinearOperator for Scaled<A, T>
+where
+    A: LinearOperator,
+    A::Domain: VectorSpace<Scalar=T> + VScaleMut<Vector=DomainVector<A>, Scalar=T> + Default,
+    A::Codomain: VScaleMut<Vector=CodomainVector<A>, Scalar=T> + Default,
+    T: ComplexField
+{
+    type Domain = A::Domain;
+
+    type Codomain = A::Codomain;
+
+    fn apply(&self, x: &DomainVector<A>) -> CodomainVector<A>
+    {
+        let mut y = self.operator.apply(x);
+        A::Codomain::default().vscale_mut(&mut y, &self.factor);
+        y
+    }
+
+    fn apply_mut(&self, x: &DomainVector<A>, y: &mut CodomainVector<A>)
+    {
+        self.operator.apply_mut(x, y);
+        A::Codomain::default().vscale_mut(y, &self.factor);
+    }
+
+    /// `(αA)ᴴ = ᾱAᴴ`.
+    fn adjoint_apply(&self, y: &CodomainVector<A>) -> DomainVector<A>
+    {
+        let mut x = self.operator.adjoint_apply(y);
+        A::Domain::default().vscale_mut(&mut x, &self.factor.conjugate());
+        x
+    }
+}
+
+/// A matrix-free operator given by closures for `Ax` and `Aᴴy`.
+pub struct FnOperator<F, G, D, C>
+{
+    forward: F,
+    adjoint: G,
+    _spaces: PhantomData<(D, C)>
+}
+
+impl<F, G, D, C> FnOperator<F, G, D, C>
+where
+    D: VectorSpace,
+    C: VectorSpace<Scalar=D::Scalar>,
+    F: Fn(&D::Vector) -> C::Vector,
+    G: Fn(&C::Vector) -> D::Vector
+{
+    pub fn new(forward: F, adjoint: G) -> Self
+    {
+        FnOperator { forward, adjoint, _spaces: PhantomData }
+    }
+}
+
+impl<F, D> FnOperator<F, F, D, D>
+where
+    D: VectorSpace,
+    F: Fn(&D::Vector) -> D::Vector + Clone
+{
+    /// A self-adjoint operator, `A = Aᴴ`.
+    pub fn hermitian(forward: F) -> Self
+    {
+        FnOperator { adjoint: forward.clone(), forward, _spaces: PhantomData }
+    }
+}
+
+impl<F, G, D, C> Clone for FnOperator<F, G, D, C>
+where
+    F: Clone,
+    G: Clone
+{
+    fn clone(&self) -> Self
+    {
+        FnOperator { forward: self.forward.clone(), adjoint: self.adjoint.clone(), _spaces: PhantomData }
+    }
+}
+
+impl<F, G, D, C> LinearOperator for FnOperator<F, G, D, C>
+where
+    D: VectorSpace,
+    C: VectorSpace<Scalar=D::Scalar>,
+    F: Fn(&D::Vector) -> C::Vector,
+    G: Fn(&C::Vector) -> D::Vector
+{
+    type Domain = D;
+
+    type Codomain = C;
+
+    fn apply(&self, x: &D::Vector) -> C::Vector
+    {
+        (self.forward)(x)
+    }
+
+    fn adjoint_apply(&self, y: &C::Vector) -> D::Vector
+    {
+        (self.adjoint)(y)
+    }
+}
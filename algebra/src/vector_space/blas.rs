@@ -1,39 +1,69 @@
-/// The `axpyv` BLAS routine.
+use super::{Entrywise, EntrywiseMut};
+
+/// The `axpyv` BLAS routine, `αx + y`, for owned vectors or views.
 pub trait VAXPY
 {
-    type Vector;
+    type Vector: Entrywise<Scalar=Self::Scalar>;
 
     type Scalar;
 
-    fn vaxpy(&self, alpha: &Self::Scalar, x: &Self::Vector, y: &Self::Vector) -> Self::Vector;
+    fn vaxpy<X, Y>(&self, alpha: &Self::Scalar, x: &X, y: &Y) -> Self::Vector
+    where
+        X: Entrywise<Scalar=Self::Scalar> + ?Sized,
+        Y: Entrywise<Scalar=Self::Scalar> + ?Sized;
 }
 
-/// The `axpyv` BLAS routine.
+/// The `axpyv` BLAS routine, `y ← αx + y`, for owned vectors or views.
 pub trait VAXPYMut
 {
-    type Vector;
+    type Vector: EntrywiseMut<Scalar=Self::Scalar>;
 
     type Scalar;
 
-    fn vaxpy_mut(&self, alpha: &Self::Scalar, x: &Self::Vector, y: &mut Self::Vector);
+    fn vaxpy_mut<X, Y>(&self, alpha: &Self::Scalar, x: &X, y: &mut Y)
+    where
+        X: Entrywise<Scalar=Self::Scalar> + ?Sized,
+        Y: EntrywiseMut<Scalar=Self::Scalar> + ?Sized;
 }
 
-/// The `dotv` BLAS routine.
+/// The `dotv` BLAS routine, `xᵀy`, for owned vectors or views.
 pub trait DotV
 {
-    type Vector;
+    type Vector: Entrywise<Scalar=Self::Scalar>;
 
     type Scalar;
 
-    fn dotv(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar;
+    fn dotv<X, Y>(&self, x: &X, y: &Y) -> Self::Scalar
+    where
+        X: Entrywise<Scalar=Self::Scalar> + ?Sized,
+        Y: Entrywise<Scalar=Self::Scalar> + ?Sized;
 }
 
-/// The `dotv` BLAS routine.
+/// The `dotv` BLAS routine, `ρ ← ρ + xᵀy`, for owned vectors or views.
 pub trait DotVMut
 {
-    type Vector;
+    type Vector: Entrywise<Scalar=Self::Scalar>;
+
+    type Scalar;
 
+    fn dotv_mut<X, Y>(&self, x: &X, y: &Y, output: &mut Self::Scalar)
+    where
+        X: Entrywise<Scalar=Self::Scalar> + ?Sized,
+        Y: Entrywise<Scalar=Self::Scalar> + ?Sized;
+}
+
+/// The `gemv` BLAS routine, `αAx + βy`, for a matrix mapping vectors of type `X` to type `Y`.
+pub trait GEMV<X, Y=X>
+{
+    type Scalar;
+
+    fn gemv(&self, alpha: &Self::Scalar, x: &X, beta: &Self::Scalar, y: &Y) -> Y;
+}
+
+/// The `gemv` BLAS routine, `y ← αAx + βy`, for a matrix mapping vectors of type `X` to type `Y`.
+pub trait GEMVMut<X, Y=X>
+{
     type Scalar;
 
-    fn dotv_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar);
+    fn gemv_mut(&self, alpha: &Self::Scalar, x: &X, beta: &Self::Scalar, y: &mut Y);
 }
@@ -1,6 +1,11 @@
 mod blas;
 pub use blas::*;
 
+mod strided;
+pub use strided::*;
+
+use crate::Tolerance;
+
 pub trait VectorSpace
 {
     type Scalar;
@@ -8,6 +13,13 @@ pub trait VectorSpace
     type Vector;
 }
 
+/// A vector space whose vectors all have the same number of scalars.
+pub trait FiniteDimensional: VectorSpace
+{
+    /// The number of scalars in a vector of the space.
+    fn dimension(&self) -> usize;
+}
+
 pub trait VAdd
 {
     type Vector;
@@ -77,6 +89,23 @@ pub trait VAdditiveInverseMut
 }
 
 
+/// Marks a space of dimension `N`, so that operations which only exist in one
+/// dimension can be implemented for exactly those spaces.
+pub trait Dimension<const N: usize>
+{
+    // Marker.
+}
+
+/// The cross product `lhs × rhs`, which `vspace!` implements for
+/// three-dimensional spaces only.
+pub trait VCross
+{
+    type Vector;
+
+    fn cross(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector;
+}
+
+
 pub trait VPartialEq
 {
     type Vector;
@@ -84,6 +113,22 @@ pub trait VPartialEq
     fn eq(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> bool;
 }
 
+/// Entrywise approximate equality, see `ApproxEq`.
+pub trait VApproxEq
+{
+    type Vector;
+
+    type Epsilon;
+
+    fn approx_eq(&self, lhs: &Self::Vector, rhs: &Self::Vector, tolerance: Tolerance<Self::Epsilon>) -> bool
+    {
+        self.mismatch(lhs, rhs, tolerance).is_none()
+    }
+
+    /// The index of the first pair of entries that are not approximately equal.
+    fn mismatch(&self, lhs: &Self::Vector, rhs: &Self::Vector, tolerance: Tolerance<Self::Epsilon>) -> Option<usize>;
+}
+
 
 #[macro_export]
 macro_rules! vadd 
@@ -106,3 +151,42 @@ macro_rules! vscale
         }
     };
 }
+
+/// Asserts that two vectors of a space are approximately equal, by default
+/// relative to the magnitude of each entry, printing the first differing entry
+/// on failure.
+#[macro_export]
+macro_rules! assert_vapprox_eq
+{
+    ($vector_space:expr, $lhs:expr, $rhs:expr) => {
+        match (&$vector_space, &$lhs, &$rhs) {
+            (vector_space, lhs, rhs) => {
+                fn default_tolerance<S, V, T>(_: &S) -> $crate::Tolerance<T::Epsilon>
+                where
+                    S: $crate::VectorSpace<Vector=V, Scalar=T>,
+                    T: $crate::ApproxEq
+                {
+                    T::default_tolerance()
+                }
+                $crate::assert_vapprox_eq!(*vector_space, *lhs, *rhs, default_tolerance(vector_space))
+            }
+        }
+    };
+
+    ($vector_space:expr, $lhs:expr, $rhs:expr, $tolerance:expr) => {
+        match (&$vector_space, &$lhs, &$rhs) {
+            (vector_space, lhs, rhs) => {
+                let tolerance = $tolerance;
+                if let Some(idx) = $crate::VApproxEq::mismatch(vector_space, lhs, rhs, tolerance) {
+                    panic!(
+                        "assertion failed: `(left ≈ right)` at index {}\n  left: `{:?}`,\n right: `{:?}`,\n tolerance: `{:?}`",
+                        idx,
+                        lhs.into_iter().nth(idx).unwrap(),
+                        rhs.into_iter().nth(idx).unwrap(),
+                        tolerance
+                    )
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,185 @@
+use std::iter::{StepBy, Take};
+use std::slice;
+
+/// The entries of a `Strided` vector in order.
+pub type Entries<'a, T> = Take<StepBy<slice::Iter<'a, T>>>;
+
+/// The entries of a `StridedMut` vector in order.
+pub type EntriesMut<'a, T> = Take<StepBy<slice::IterMut<'a, T>>>;
+
+/// Read access to a vector whose entries are evenly spaced in a slice.
+///
+/// Owned vectors have a stride of one; borrowed views may skip entries. The
+/// BLAS traits accept anything implementing this, through `Entrywise`, so they
+/// work on blocks of a larger vector without copying.
+pub trait Strided
+{
+    type Scalar;
+
+    /// The number of entries.
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool
+    {
+        self.len() == 0
+    }
+
+    /// The distance between consecutive entries in `as_strided_slice()`.
+    fn stride(&self) -> usize;
+
+    /// A slice whose element `i · stride()` is entry `i`, for `i < len()`.
+    fn as_strided_slice(&self) -> &[Self::Scalar];
+
+    fn entries(&self) -> Entries<'_, Self::Scalar>
+    {
+        self.as_strided_slice().iter().step_by(self.stride()).take(self.len())
+    }
+}
+
+/// Write access to a vector whose entries are evenly spaced in a slice.
+pub trait StridedMut: Strided
+{
+    /// A slice whose element `i · stride()` is entry `i`, for `i < len()`.
+    fn as_strided_slice_mut(&mut self) -> &mut [Self::Scalar];
+
+    fn entries_mut(&mut self) -> EntriesMut<'_, Self::Scalar>
+    {
+        let (stride, len) = (self.stride(), self.len());
+        self.as_strided_slice_mut().iter_mut().step_by(stride).take(len)
+    }
+}
+
+impl<T> Strided for [T]
+{
+    type Scalar = T;
+
+    fn len(&self) -> usize
+    {
+        <[T]>::len(self)
+    }
+
+    fn stride(&self) -> usize
+    {
+        1
+    }
+
+    fn as_strided_slice(&self) -> &[T]
+    {
+        self
+    }
+}
+
+impl<T> StridedMut for [T]
+{
+    fn as_strided_slice_mut(&mut self) -> &mut [T]
+    {
+        self
+    }
+}
+
+impl<T> Strided for Vec<T>
+{
+    type Scalar = T;
+
+    fn len(&self) -> usize
+    {
+        <[T]>::len(self)
+    }
+
+    fn stride(&self) -> usize
+    {
+        1
+    }
+
+    fn as_strided_slice(&self) -> &[T]
+    {
+        self
+    }
+}
+
+impl<T> StridedMut for Vec<T>
+{
+    fn as_strided_slice_mut(&mut self) -> &mut [T]
+    {
+        self
+    }
+}
+
+/// Read access to the entries of a vector one at a time, which is all the
+/// BLAS traits need of their arguments.
+///
+/// Every `Strided` vector has it. Vectors that are not stored as a slice of
+/// their scalars, like bit-packed vectors over `GF(2)`, implement it directly.
+/// The methods are named apart from `Strided` so that both can be in scope.
+pub trait Entrywise
+{
+    type Scalar;
+
+    type Iter<'a>: Iterator<Item=&'a Self::Scalar>
+    where
+        Self: 'a;
+
+    /// The number of entries.
+    fn length(&self) -> usize;
+
+    fn iter_entries(&self) -> Self::Iter<'_>;
+
+    /// The entries packed a bit to a word, entry `k` being bit `k % 64` of
+    /// word `k / 64`, for vectors over `GF(2)` that are stored that way.
+    ///
+    /// The BLAS traits of packed spaces work a word at a time when their
+    /// arguments have this, and entry by entry otherwise.
+    fn packed_words(&self) -> Option<&[u64]>
+    {
+        None
+    }
+}
+
+/// Write access to the entries of a vector one at a time.
+pub trait EntrywiseMut: Entrywise
+{
+    /// Calls `f` on each entry in order, storing what it leaves behind.
+    fn update_entries<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Self::Scalar);
+
+    /// The packed words of `packed_words`, for writing; the bits past the
+    /// length must stay clear.
+    fn packed_words_mut(&mut self) -> Option<&mut [u64]>
+    {
+        None
+    }
+}
+
+impl<V> Entrywise for V
+where
+    V: Strided + ?Sized
+{
+    type Scalar = V::Scalar;
+
+    type Iter<'a> = Entries<'a, V::Scalar>
+    where
+        Self: 'a;
+
+    fn length(&self) -> usize
+    {
+        Strided::len(self)
+    }
+
+    fn iter_entries(&self) -> Self::Iter<'_>
+    {
+        self.entries()
+    }
+}
+
+impl<V> EntrywiseMut for V
+where
+    V: StridedMut + ?Sized
+{
+    fn update_entries<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut Self::Scalar)
+    {
+        self.entries_mut().for_each(f)
+    }
+}
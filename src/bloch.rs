@@ -0,0 +1,210 @@
+//! The Bloch sphere picture of a single qubit.
+//!
+//! Every single-qubit density matrix is `ρ = (I + xX + yY + zZ) / 2` for a
+//! Bloch vector `(x, y, z)` in the unit ball: pure states lie on the sphere,
+//! mixed states inside it and the maximally mixed state at the centre. States
+//! are indexed like the kernels in `dense`, and density matrices and gates
+//! like the matrices of `mspace!`, by `(row, column)`.
+
+use std::f64::consts::PI;
+use std::iter::FromIterator;
+use std::ops::Index;
+
+use algebra::{Dimension, Quaternion, VectorSpace};
+use num_complex::Complex;
+
+const TOLERANCE: f64 = 1e-9;
+
+/// A point `(x, y, z)` of the Bloch ball.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Bloch
+{
+    pub x: f64,
+    pub y: f64,
+    pub z: f64
+}
+
+impl Bloch
+{
+    /// Panics if the point lies outside the unit ball.
+    pub fn new(x: f64, y: f64, z: f64) -> Self
+    {
+        let bloch: Bloch = Bloch { x, y, z };
+        assert!(bloch.radius() <= 1.0 + TOLERANCE, "{:?} lies outside the Bloch ball", bloch);
+        bloch
+    }
+
+    /// The pure state `cos(θ/2)|0⟩ + e^{iφ} sin(θ/2)|1⟩`.
+    pub fn from_angles(theta: f64, phi: f64) -> Self
+    {
+        Bloch::new(theta.sin() * phi.cos(), theta.sin() * phi.sin(), theta.cos())
+    }
+
+    /// The point of a pure state `α|0⟩ + β|1⟩` of a two-dimensional `space`,
+    /// which need not be normalised.
+    ///
+    /// Returns `None` if the state is zero.
+    pub fn from_state<S, V>(_space: &S, state: &V) -> Option<Self>
+    where
+        S: VectorSpace<Scalar=Complex<f64>, Vector=V> + Dimension<2>,
+        V: Index<usize, Output=Complex<f64>>
+    {
+        let (alpha, beta): (Complex<f64>, Complex<f64>) = (state[0], state[1]);
+        let norm: f64 = alpha.norm_sqr() + beta.norm_sqr();
+        if norm <= TOLERANCE {
+            return None
+        }
+        let coherence: Complex<f64> = alpha.conj() * beta * (2.0 / norm);
+        Some(Bloch::new(coherence.re, coherence.im, (alpha.norm_sqr() - beta.norm_sqr()) / norm))
+    }
+
+    /// The point of a 2×2 density matrix, normalised by its trace.
+    ///
+    /// Panics if the trace vanishes.
+    pub fn from_density<M>(density: &M) -> Self
+    where
+        M: Index<(usize, usize), Output=Complex<f64>>
+    {
+        let trace: f64 = (density[(0, 0)] + density[(1, 1)]).re;
+        assert!(trace > TOLERANCE, "a density matrix has unit trace");
+        let coherence: Complex<f64> = density[(1, 0)] * (2.0 / trace);
+        Bloch::new(coherence.re, coherence.im, (density[(0, 0)] - density[(1, 1)]).re / trace)
+    }
+
+    /// The pure state `cos(θ/2)|0⟩ + e^{iφ} sin(θ/2)|1⟩` in the direction of
+    /// the point, with a real amplitude on |0⟩; the centre maps to |0⟩.
+    pub fn to_state<V>(&self) -> V
+    where
+        V: FromIterator<Complex<f64>>
+    {
+        let (theta, phi): (f64, f64) = (self.theta(), self.phi());
+        vec![Complex::new((theta / 2.0).cos(), 0.0), Complex::from_polar(&(theta / 2.0).sin(), &phi)]
+            .into_iter()
+            .collect()
+    }
+
+    /// The density matrix `(I + xX + yY + zZ) / 2`, in row-major order.
+    pub fn to_density<M>(&self) -> M
+    where
+        M: FromIterator<Complex<f64>>
+    {
+        vec![
+            Complex::new((1.0 + self.z) / 2.0, 0.0),
+            Complex::new(self.x / 2.0, -self.y / 2.0),
+            Complex::new(self.x / 2.0, self.y / 2.0),
+            Complex::new((1.0 - self.z) / 2.0, 0.0)
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    /// The coordinates `(x, y, z)` as a 3-vector.
+    pub fn to_vector<V>(&self) -> V
+    where
+        V: FromIterator<f64>
+    {
+        vec![self.x, self.y, self.z].into_iter().collect()
+    }
+
+    /// The polar angle from |0⟩, in `[0, π]`.
+    pub fn theta(&self) -> f64
+    {
+        self.x.hypot(self.y).atan2(self.z)
+    }
+
+    /// The azimuthal angle from the x-axis, in `[0, 2π)`.
+    pub fn phi(&self) -> f64
+    {
+        let phi: f64 = self.y.atan2(self.x);
+        if phi < 0.0 { phi + 2.0 * PI } else { phi }
+    }
+
+    /// The distance from the centre: one for pure states, zero for the
+    /// maximally mixed state.
+    pub fn radius(&self) -> f64
+    {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// The purity `tr(ρ²) = (1 + r²) / 2`.
+    pub fn purity(&self) -> f64
+    {
+        (1.0 + self.x * self.x + self.y * self.y + self.z * self.z) / 2.0
+    }
+
+    pub fn is_pure(&self) -> bool
+    {
+        (self.radius() - 1.0).abs() <= TOLERANCE
+    }
+
+    /// The point after applying a single-qubit gate, which rotates the ball.
+    pub fn apply<M>(&self, gate: &M) -> Self
+    where
+        M: Index<(usize, usize), Output=Complex<f64>>
+    {
+        let [x, y, z]: [f64; 3] = rotation(gate).to_rotation_matrix()
+            .map(|row| row[0] * self.x + row[1] * self.y + row[2] * self.z);
+        Bloch { x, y, z }
+    }
+
+    /// Projects the sphere onto the y-z plane as ASCII art, `radius` rows above
+    /// and below the equator.
+    ///
+    /// The outline is drawn with `.`, the z-axis with `|` between the poles `0`
+    /// and `1`, and the equator with `-`. The point is `*` when on the near
+    /// side (`x >= 0`) and `o` when behind the sphere.
+    pub fn render(&self, radius: usize) -> String
+    {
+        assert!(radius > 0, "the sphere must be at least one row tall");
+        // Terminal cells are about twice as tall as wide.
+        let (rows, columns): (usize, usize) = (2 * radius + 1, 4 * radius + 1);
+        let column = |y: f64| -> usize { ((1.0 + y) * (2 * radius) as f64).round() as usize };
+        let row = |z: f64| -> usize { ((1.0 - z) * radius as f64).round() as usize };
+
+        let mut grid: Vec<Vec<char>> = vec![vec![' '; columns]; rows];
+        for (r, line) in grid.iter_mut().enumerate() {
+            let z: f64 = 1.0 - r as f64 / radius as f64;
+            let extent: f64 = (1.0 - z * z).max(0.0).sqrt();
+            line[column(-extent)] = '.';
+            line[column(extent)] = '.';
+            line[2 * radius] = '|';
+        }
+        for cell in &mut grid[radius][1..columns - 1] {
+            *cell = '-';
+        }
+        grid[radius][2 * radius] = '+';
+        grid[0][2 * radius] = '0';
+        grid[rows - 1][2 * radius] = '1';
+        grid[row(self.z)][column(self.y)] = if self.x >= 0.0 { '*' } else { 'o' };
+
+        grid.iter()
+            .map(|line| line.iter().collect::<String>().trim_end().to_string())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+/// The rotation of the Bloch ball effected by a single-qubit gate, as a unit
+/// quaternion; its `to_rotation_matrix` is the image of the gate in `SO(3)`.
+///
+/// The global phase is discarded, so `U` and `e^{iα}U` give the same rotation,
+/// and `exp(-iθ n·σ / 2)` rotates by `θ` about the axis `n`. Panics if the gate
+/// is not unitary.
+pub fn rotation<M>(gate: &M) -> Quaternion<f64>
+where
+    M: Index<(usize, usize), Output=Complex<f64>>
+{
+    let (a, b, c, d): (Complex<f64>, Complex<f64>, Complex<f64>, Complex<f64>) = (gate[(0, 0)], gate[(0, 1)], gate[(1, 0)], gate[(1, 1)]);
+    let orthogonal: Complex<f64> = a.conj() * b + c.conj() * d;
+    assert!(
+        (a.norm_sqr() + c.norm_sqr() - 1.0).abs() < TOLERANCE
+            && (b.norm_sqr() + d.norm_sqr() - 1.0).abs() < TOLERANCE
+            && orthogonal.norm() < TOLERANCE,
+        "the gate is not unitary"
+    );
+    // Dividing by a square root of the determinant leaves a matrix of SU(2),
+    // `w I - i(xX + yY + zZ)`.
+    let phase: Complex<f64> = (a * d - b * c).sqrt();
+    let (a, c): (Complex<f64>, Complex<f64>) = (a / phase, c / phase);
+    Quaternion::new(a.re, -c.im, c.re, -a.im)
+}
@@ -0,0 +1,160 @@
+//! Dense state-vector kernels.
+//!
+//! The kernels work on any amplitude buffer that can be indexed by basis state,
+//! so the vectors generated by `vspace!` can be used directly as quantum states.
+//! A register of `qubits` qubits holds `2^qubits` amplitudes and qubit `q` is
+//! bit `q` of the basis index.
+
+use std::ops::{Index, IndexMut};
+
+use num_complex::Complex;
+use rand::Rng;
+
+use crate::{Gate, Measurement};
+
+const TOLERANCE: f64 = 1e-12;
+
+/// Applies `gate` to a register of `qubits` qubits.
+pub fn apply<V>(state: &mut V, qubits: usize, gate: &Gate)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    assert!(gate.support() < qubits, "gate {:?} is outside a register of {} qubits", gate, qubits);
+    match *gate {
+        Gate::H(q) => hadamard(state, qubits, q),
+        Gate::S(q) => phase(state, qubits, q),
+        Gate::X(q) => pauli_x(state, qubits, q),
+        Gate::Y(q) => pauli_y(state, qubits, q),
+        Gate::Z(q) => pauli_z(state, qubits, q),
+        Gate::CNOT(control, target) => cnot(state, qubits, control, target)
+    }
+}
+
+/// Prepares the basis state |0…0⟩.
+pub fn zero<V>(state: &mut V, qubits: usize)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    for idx in 0..(1 << qubits) {
+        state[idx] = Complex::new(0.0, 0.0);
+    }
+    state[0] = Complex::new(1.0, 0.0);
+}
+
+pub fn hadamard<V>(state: &mut V, qubits: usize, target: usize)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    let norm: f64 = std::f64::consts::FRAC_1_SQRT_2;
+    for (lo, hi) in pairs(qubits, target) {
+        let (a, b) = (state[lo], state[hi]);
+        state[lo] = (a + b) * norm;
+        state[hi] = (a - b) * norm;
+    }
+}
+
+pub fn phase<V>(state: &mut V, qubits: usize, target: usize)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    for (_, hi) in pairs(qubits, target) {
+        state[hi] *= Complex::<f64>::i();
+    }
+}
+
+pub fn pauli_x<V>(state: &mut V, qubits: usize, target: usize)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    for (lo, hi) in pairs(qubits, target) {
+        let a: Complex<f64> = state[lo];
+        state[lo] = state[hi];
+        state[hi] = a;
+    }
+}
+
+pub fn pauli_y<V>(state: &mut V, qubits: usize, target: usize)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    for (lo, hi) in pairs(qubits, target) {
+        let (a, b) = (state[lo], state[hi]);
+        state[lo] = Complex::new(b.im, -b.re);
+        state[hi] = Complex::new(-a.im, a.re);
+    }
+}
+
+pub fn pauli_z<V>(state: &mut V, qubits: usize, target: usize)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    for (_, hi) in pairs(qubits, target) {
+        state[hi] = -state[hi];
+    }
+}
+
+pub fn cnot<V>(state: &mut V, qubits: usize, control: usize, target: usize)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    assert!(control != target, "CNOT control and target must differ");
+    for (lo, hi) in pairs(qubits, target) {
+        if lo & (1 << control) != 0 {
+            let a: Complex<f64> = state[lo];
+            state[lo] = state[hi];
+            state[hi] = a;
+        }
+    }
+}
+
+/// The probability of observing |1⟩ on `target`.
+pub fn probability<V>(state: &V, qubits: usize, target: usize) -> f64
+where
+    V: Index<usize, Output=Complex<f64>>
+{
+    pairs(qubits, target)
+        .map(|(_, hi)| state[hi].norm_sqr())
+        .sum()
+}
+
+/// Projects `target` onto `outcome` and renormalises the state.
+///
+/// Panics if the outcome has zero probability.
+pub fn collapse<V>(state: &mut V, qubits: usize, target: usize, outcome: bool)
+where
+    V: IndexMut<usize, Output=Complex<f64>>
+{
+    let p_one: f64 = probability(state, qubits, target);
+    let p: f64 = if outcome { p_one } else { 1.0 - p_one };
+    assert!(p > TOLERANCE, "cannot collapse qubit {} onto an outcome with zero probability", target);
+
+    let norm: f64 = p.sqrt().recip();
+    for (lo, hi) in pairs(qubits, target) {
+        let (keep, drop) = if outcome { (hi, lo) } else { (lo, hi) };
+        state[keep] *= norm;
+        state[drop] = Complex::new(0.0, 0.0);
+    }
+}
+
+/// Measures `target` in the Z basis, sampling the outcome from `rng`.
+pub fn measure<V, R>(state: &mut V, qubits: usize, target: usize, rng: &mut R) -> Measurement
+where
+    V: IndexMut<usize, Output=Complex<f64>>,
+    R: Rng + ?Sized
+{
+    let p_one: f64 = probability(state, qubits, target);
+    let deterministic: bool = !(TOLERANCE..=1.0 - TOLERANCE).contains(&p_one);
+    let outcome: bool = if deterministic { p_one > 0.5 } else { rng.gen_bool(p_one) };
+    collapse(state, qubits, target, outcome);
+    Measurement { outcome, deterministic }
+}
+
+/// Iterates the basis index pairs that differ only in bit `target`.
+fn pairs(qubits: usize, target: usize) -> impl Iterator<Item=(usize, usize)>
+{
+    assert!(target < qubits, "qubit {} is outside a register of {} qubits", target, qubits);
+    let mask: usize = 1 << target;
+    (0..(1usize << qubits))
+        .filter(move |idx| idx & mask == 0)
+        .map(move |idx| (idx, idx | mask))
+}
@@ -0,0 +1,37 @@
+/// The Clifford gates understood by both the dense and the stabilizer simulators.
+///
+/// Qubits are addressed by index, where qubit `q` is bit `q` of a computational
+/// basis state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Gate
+{
+    H(usize),
+    S(usize),
+    X(usize),
+    Y(usize),
+    Z(usize),
+    CNOT(usize, usize)
+}
+
+impl Gate
+{
+    /// The largest qubit index touched by the gate.
+    pub fn support(&self) -> usize
+    {
+        match *self {
+            Gate::H(q) | Gate::S(q) | Gate::X(q) | Gate::Y(q) | Gate::Z(q) => q,
+            Gate::CNOT(control, target) => control.max(target)
+        }
+    }
+}
+
+/// The outcome of a Z-basis measurement.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Measurement
+{
+    /// `true` when the qubit collapsed to |1⟩.
+    pub outcome: bool,
+
+    /// `true` when the outcome was fixed by the state rather than sampled.
+    pub deterministic: bool
+}
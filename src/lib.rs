@@ -0,0 +1,11 @@
+mod gate;
+pub use gate::*;
+
+mod pauli;
+pub use pauli::*;
+
+pub mod dense;
+
+pub mod bloch;
+
+pub mod stabilizer;
@@ -0,0 +1,74 @@
+use std::fmt;
+
+mod string;
+pub use string::*;
+
+mod sum;
+pub use sum::*;
+
+/// A single-qubit Pauli operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Pauli
+{
+    I,
+    X,
+    Y,
+    Z
+}
+
+impl Pauli
+{
+    /// Builds the operator from its symplectic `(x, z)` bits.
+    pub fn from_bits(x: bool, z: bool) -> Self
+    {
+        match (x, z) {
+            (false, false) => Pauli::I,
+            (true, false) => Pauli::X,
+            (true, true) => Pauli::Y,
+            (false, true) => Pauli::Z
+        }
+    }
+
+    /// The symplectic `(x, z)` bits of the operator.
+    pub fn bits(self) -> (bool, bool)
+    {
+        match self {
+            Pauli::I => (false, false),
+            Pauli::X => (true, false),
+            Pauli::Y => (true, true),
+            Pauli::Z => (false, true)
+        }
+    }
+}
+
+impl fmt::Display for Pauli
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let symbol: char = match self {
+            Pauli::I => 'I',
+            Pauli::X => 'X',
+            Pauli::Y => 'Y',
+            Pauli::Z => 'Z'
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// The number of qubits packed into each word of a bit-packed Pauli row.
+pub(crate) const WORD: usize = 64;
+
+/// Accumulates the phase of the word-wise product `(x1, z1)·(x2, z2)` into a
+/// pair of bitwise mod-4 counters.
+///
+/// Each bit position contributes `±1` to the exponent of `i` when the two
+/// single-qubit operators anticommute; `popcount(cnt1) + 2·popcount(cnt2)` is
+/// the total exponent modulo 4.
+#[inline]
+pub(crate) fn accumulate_phase(x1: u64, z1: u64, x2: u64, z2: u64, cnt1: &mut u64, cnt2: &mut u64)
+{
+    let x1z2: u64 = x1 & z2;
+    let anti_commutes: u64 = (x2 & z1) ^ x1z2;
+    *cnt2 ^= (*cnt1 ^ x1 ^ x2 ^ z1 ^ z2 ^ x1z2) & anti_commutes;
+    *cnt1 ^= anti_commutes;
+}
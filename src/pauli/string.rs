@@ -0,0 +1,298 @@
+use std::{fmt, ops::{IndexMut, Mul}, str::FromStr, iter::FromIterator};
+
+use algebra::{DotV, Strided};
+use num_complex::Complex;
+
+use super::{accumulate_phase, Pauli, WORD};
+
+/// A tensor product of single-qubit Pauli operators with a phase of `±1` or `±i`.
+///
+/// Character `k` of the textual form `XIZY` is the operator on qubit `k`. The
+/// operators are stored as bit-packed `(x, z)` rows, so products and
+/// commutation checks cost one pass over `n / 64` words.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PauliString
+{
+    qubits: usize,
+    phase: u8,
+    x: Vec<u64>,
+    z: Vec<u64>
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParsePauliError
+{
+    /// The string names no qubits.
+    Empty,
+
+    /// The character at `index` is not one of `I`, `X`, `Y` or `Z`.
+    InvalidCharacter { index: usize, character: char }
+}
+
+impl fmt::Display for ParsePauliError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            ParsePauliError::Empty => write!(f, "a Pauli string must act on at least one qubit"),
+            ParsePauliError::InvalidCharacter { index, character } => {
+                write!(f, "invalid Pauli operator {:?} at position {}", character, index)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParsePauliError { }
+
+impl PauliString
+{
+    /// The identity on `qubits` qubits.
+    pub fn identity(qubits: usize) -> Self
+    {
+        let words: usize = qubits.div_ceil(WORD);
+        PauliString {
+            qubits,
+            phase: 0,
+            x: vec![0; words],
+            z: vec![0; words]
+        }
+    }
+
+    pub(crate) fn from_words(qubits: usize, phase: u8, x: Vec<u64>, z: Vec<u64>) -> Self
+    {
+        PauliString { qubits, phase: phase % 4, x, z }
+    }
+
+    pub fn qubits(&self) -> usize
+    {
+        self.qubits
+    }
+
+    /// The phase as a power of `i`, in `0..4`.
+    pub fn phase_exponent(&self) -> u8
+    {
+        self.phase
+    }
+
+    /// The phase as one of `1`, `i`, `-1` or `-i`.
+    pub fn phase(&self) -> Complex<f64>
+    {
+        power_of_i(self.phase as u32)
+    }
+
+    /// Multiplies the phase by `i^exponent`.
+    pub fn with_phase(mut self, exponent: u8) -> Self
+    {
+        self.phase = (self.phase + exponent % 4) % 4;
+        self
+    }
+
+    pub fn get(&self, qubit: usize) -> Pauli
+    {
+        assert!(qubit < self.qubits, "qubit {} is outside a string of {} qubits", qubit, self.qubits);
+        let (word, bit) = (qubit / WORD, qubit % WORD);
+        Pauli::from_bits(self.x[word] >> bit & 1 == 1, self.z[word] >> bit & 1 == 1)
+    }
+
+    pub fn set(&mut self, qubit: usize, pauli: Pauli)
+    {
+        assert!(qubit < self.qubits, "qubit {} is outside a string of {} qubits", qubit, self.qubits);
+        let (word, bit) = (qubit / WORD, qubit % WORD);
+        let (x, z) = pauli.bits();
+        self.x[word] = (self.x[word] & !(1 << bit)) | ((x as u64) << bit);
+        self.z[word] = (self.z[word] & !(1 << bit)) | ((z as u64) << bit);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item=Pauli> + '_
+    {
+        (0..self.qubits).map(move |q| self.get(q))
+    }
+
+    /// The number of qubits on which the string acts non-trivially.
+    pub fn weight(&self) -> usize
+    {
+        self.x
+            .iter()
+            .zip(self.z.iter())
+            .map(|(x, z)| (x | z).count_ones() as usize)
+            .sum()
+    }
+
+    pub fn commutes_with(&self, other: &Self) -> bool
+    {
+        assert_eq!(self.qubits, other.qubits, "Pauli strings act on different numbers of qubits");
+        let parity: u32 = self.x
+            .iter()
+            .zip(self.z.iter())
+            .zip(other.x.iter().zip(other.z.iter()))
+            .map(|((x1, z1), (x2, z2))| ((x1 & z2) ^ (z1 & x2)).count_ones())
+            .sum();
+        parity.is_multiple_of(2)
+    }
+
+    pub fn anticommutes_with(&self, other: &Self) -> bool
+    {
+        !self.commutes_with(other)
+    }
+
+    /// Applies the string to a dense state of `self.qubits()` qubits in place.
+    ///
+    /// The operator is never materialised: each basis state `|b⟩` is mapped to
+    /// `i^k (-1)^{|b ∧ z|} |b ⊕ x⟩` in a single pass over the amplitudes.
+    pub fn apply_mut<V>(&self, state: &mut V)
+    where
+        V: IndexMut<usize, Output=Complex<f64>>
+    {
+        assert!(self.qubits < WORD, "a dense state of {} qubits cannot be addressed", self.qubits);
+        let x_mask: usize = self.x.first().copied().unwrap_or(0) as usize;
+        let z_mask: usize = self.z.first().copied().unwrap_or(0) as usize;
+
+        let y_count: u32 = (x_mask & z_mask).count_ones();
+        let factor: Complex<f64> = power_of_i(self.phase as u32 + y_count);
+        let sign = |b: usize| if (b & z_mask).count_ones().is_multiple_of(2) { factor } else { -factor };
+
+        for b in 0..(1usize << self.qubits) {
+            let c: usize = b ^ x_mask;
+            if c == b {
+                state[b] *= sign(b);
+            } else if b < c {
+                let (amp_b, amp_c) = (state[b], state[c]);
+                state[c] = amp_b * sign(b);
+                state[b] = amp_c * sign(c);
+            }
+        }
+    }
+
+    /// Applies the string to a copy of a dense state.
+    pub fn apply<V>(&self, state: &V) -> V
+    where
+        V: Clone + IndexMut<usize, Output=Complex<f64>>
+    {
+        let mut image: V = state.clone();
+        self.apply_mut(&mut image);
+        image
+    }
+
+    /// The expectation value `⟨ψ|P|ψ⟩`, evaluated with the space's `dotv`.
+    pub fn expectation<S, V>(&self, space: &S, state: &V) -> Complex<f64>
+    where
+        S: DotV<Vector=V, Scalar=Complex<f64>>,
+        V: Clone + IndexMut<usize, Output=Complex<f64>> + Strided<Scalar=Complex<f64>>
+    {
+        let image: V = self.apply(state);
+        let bra: V = conjugate(state, self.qubits);
+        space.dotv(&bra, &image)
+    }
+}
+
+impl<'a> Mul<&'a PauliString> for &'a PauliString
+{
+    type Output = PauliString;
+
+    fn mul(self, rhs: &'a PauliString) -> Self::Output
+    {
+        assert_eq!(self.qubits, rhs.qubits, "Pauli strings act on different numbers of qubits");
+        let (mut cnt1, mut cnt2) = (0u64, 0u64);
+        let mut x: Vec<u64> = Vec::with_capacity(self.x.len());
+        let mut z: Vec<u64> = Vec::with_capacity(self.z.len());
+        for w in 0..self.x.len() {
+            let (x1, z1, x2, z2) = (self.x[w], self.z[w], rhs.x[w], rhs.z[w]);
+            accumulate_phase(x1, z1, x2, z2, &mut cnt1, &mut cnt2);
+            x.push(x1 ^ x2);
+            z.push(z1 ^ z2);
+        }
+        let exponent: u32 = cnt1.count_ones() + 2 * cnt2.count_ones() + self.phase as u32 + rhs.phase as u32;
+        PauliString::from_words(self.qubits, (exponent % 4) as u8, x, z)
+    }
+}
+
+impl Mul<PauliString> for PauliString
+{
+    type Output = PauliString;
+
+    fn mul(self, rhs: PauliString) -> Self::Output
+    {
+        &self * &rhs
+    }
+}
+
+impl FromIterator<Pauli> for PauliString
+{
+    fn from_iter<I>(iterator: I) -> Self
+    where
+        I: IntoIterator<Item=Pauli>
+    {
+        let paulis: Vec<Pauli> = iterator.into_iter().collect();
+        let mut string = PauliString::identity(paulis.len());
+        for (q, pauli) in paulis.into_iter().enumerate() {
+            string.set(q, pauli);
+        }
+        string
+    }
+}
+
+impl FromStr for PauliString
+{
+    type Err = ParsePauliError;
+
+    /// Parses strings such as `XIZY`, `-ZZ` or `+iXY`.
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let (phase, offset): (u8, usize) = [("+i", 1), ("-i", 3), ("i", 1), ("+", 0), ("-", 2)]
+            .iter()
+            .find(|(prefix, _)| s.starts_with(prefix))
+            .map(|(prefix, phase)| (*phase, prefix.len()))
+            .unwrap_or((0, 0));
+
+        let paulis: Vec<Pauli> = s[offset..]
+            .chars()
+            .enumerate()
+            .map(|(idx, character)| match character {
+                'I' => Ok(Pauli::I),
+                'X' => Ok(Pauli::X),
+                'Y' => Ok(Pauli::Y),
+                'Z' => Ok(Pauli::Z),
+                _ => Err(ParsePauliError::InvalidCharacter { index: idx + offset, character })
+            })
+            .collect::<Result<_, _>>()?;
+
+        if paulis.is_empty() {
+            return Err(ParsePauliError::Empty)
+        }
+        Ok(paulis.into_iter().collect::<PauliString>().with_phase(phase))
+    }
+}
+
+impl fmt::Display for PauliString
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let prefix: &str = ["+", "+i", "-", "-i"][self.phase as usize];
+        write!(f, "{}", prefix)?;
+        for pauli in self.iter() {
+            write!(f, "{}", pauli)?;
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn power_of_i(exponent: u32) -> Complex<f64>
+{
+    match exponent % 4 {
+        0 => Complex::new(1.0, 0.0),
+        1 => Complex::new(0.0, 1.0),
+        2 => Complex::new(-1.0, 0.0),
+        _ => Complex::new(0.0, -1.0)
+    }
+}
+
+pub(crate) fn conjugate<V>(state: &V, qubits: usize) -> V
+where
+    V: Clone + IndexMut<usize, Output=Complex<f64>>
+{
+    let mut bra: V = state.clone();
+    for b in 0..(1usize << qubits) {
+        bra[b] = bra[b].conj();
+    }
+    bra
+}
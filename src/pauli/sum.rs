@@ -0,0 +1,102 @@
+use std::{fmt, ops::IndexMut};
+
+use algebra::{DotV, Strided};
+use num_complex::Complex;
+
+use super::{string::conjugate, PauliString};
+
+/// A weighted sum of Pauli strings, such as a qubit Hamiltonian.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PauliSum
+{
+    qubits: usize,
+    terms: Vec<(f64, PauliString)>
+}
+
+impl PauliSum
+{
+    /// The empty sum on `qubits` qubits, which is the zero operator.
+    pub fn new(qubits: usize) -> Self
+    {
+        PauliSum {
+            qubits,
+            terms: Vec::new()
+        }
+    }
+
+    pub fn qubits(&self) -> usize
+    {
+        self.qubits
+    }
+
+    pub fn terms(&self) -> &[(f64, PauliString)]
+    {
+        &self.terms
+    }
+
+    pub fn push(&mut self, weight: f64, string: PauliString)
+    {
+        assert_eq!(self.qubits, string.qubits(), "Pauli strings act on different numbers of qubits");
+        self.terms.push((weight, string));
+    }
+
+    /// Applies the sum to a copy of a dense state without forming its matrix.
+    pub fn apply<V>(&self, state: &V) -> V
+    where
+        V: Clone + IndexMut<usize, Output=Complex<f64>>
+    {
+        let mut output: V = state.clone();
+        for b in 0..(1usize << self.qubits) {
+            output[b] = Complex::new(0.0, 0.0);
+        }
+        for (weight, string) in self.terms.iter() {
+            let image: V = string.apply(state);
+            for b in 0..(1usize << self.qubits) {
+                output[b] += image[b] * *weight;
+            }
+        }
+        output
+    }
+
+    /// The expectation value `Σ w ⟨ψ|P|ψ⟩`, evaluated with the space's `dotv`.
+    ///
+    /// The result is real whenever every term is Hermitian.
+    pub fn expectation<S, V>(&self, space: &S, state: &V) -> Complex<f64>
+    where
+        S: DotV<Vector=V, Scalar=Complex<f64>>,
+        V: Clone + IndexMut<usize, Output=Complex<f64>> + Strided<Scalar=Complex<f64>>
+    {
+        let bra: V = conjugate(state, self.qubits);
+        self.terms
+            .iter()
+            .map(|(weight, string)| space.dotv(&bra, &string.apply(state)) * *weight)
+            .sum()
+    }
+}
+
+impl Extend<(f64, PauliString)> for PauliSum
+{
+    /// Panics if a string acts on a different number of qubits.
+    fn extend<I>(&mut self, iterator: I)
+    where
+        I: IntoIterator<Item=(f64, PauliString)>
+    {
+        for (weight, string) in iterator {
+            self.push(weight, string);
+        }
+    }
+}
+
+impl fmt::Display for PauliSum
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        for (idx, (weight, string)) in self.terms.iter().enumerate() {
+            if idx > 0 {
+                write!(f, " + ")?;
+            }
+            write!(f, "{} {}", weight, string)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+//! An Aaronson–Gottesman stabilizer tableau.
+//!
+//! The tableau stores `n` destabilizer rows, `n` stabilizer rows and a scratch
+//! row, each as a pair of bit-packed GF(2) vectors `(x, z)` plus a sign bit.
+//! Clifford gates cost `O(n)` and measurements `O(n^2 / 64)`, so registers of
+//! thousands of qubits are practical where a dense state vector is not.
+
+use rand::Rng;
+
+use crate::{Gate, Measurement, PauliString};
+use crate::pauli::{accumulate_phase, WORD};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Tableau
+{
+    qubits: usize,
+    words: usize,
+    x: Vec<u64>,
+    z: Vec<u64>,
+    r: Vec<bool>
+}
+
+impl Tableau
+{
+    /// The tableau of the basis state |0…0⟩ on `qubits` qubits.
+    pub fn new(qubits: usize) -> Self
+    {
+        let words: usize = qubits.div_ceil(WORD);
+        let rows: usize = 2 * qubits + 1;
+        let mut tableau = Tableau {
+            qubits,
+            words,
+            x: vec![0; rows * words],
+            z: vec![0; rows * words],
+            r: vec![false; rows]
+        };
+        for q in 0..qubits {
+            tableau.set_x(q, q, true);
+            tableau.set_z(qubits + q, q, true);
+        }
+        tableau
+    }
+
+    pub fn qubits(&self) -> usize
+    {
+        self.qubits
+    }
+
+    pub fn apply(&mut self, gate: &Gate)
+    {
+        assert!(gate.support() < self.qubits, "gate {:?} is outside a register of {} qubits", gate, self.qubits);
+        match *gate {
+            Gate::H(q) => self.hadamard(q),
+            Gate::S(q) => self.phase(q),
+            Gate::X(q) => self.pauli_x(q),
+            Gate::Y(q) => self.pauli_y(q),
+            Gate::Z(q) => self.pauli_z(q),
+            Gate::CNOT(control, target) => self.cnot(control, target)
+        }
+    }
+
+    pub fn hadamard(&mut self, q: usize)
+    {
+        for row in 0..2 * self.qubits {
+            let (x, z) = (self.get_x(row, q), self.get_z(row, q));
+            self.r[row] ^= x & z;
+            self.set_x(row, q, z);
+            self.set_z(row, q, x);
+        }
+    }
+
+    pub fn phase(&mut self, q: usize)
+    {
+        for row in 0..2 * self.qubits {
+            let (x, z) = (self.get_x(row, q), self.get_z(row, q));
+            self.r[row] ^= x & z;
+            self.set_z(row, q, z ^ x);
+        }
+    }
+
+    pub fn pauli_x(&mut self, q: usize)
+    {
+        for row in 0..2 * self.qubits {
+            self.r[row] ^= self.get_z(row, q);
+        }
+    }
+
+    pub fn pauli_y(&mut self, q: usize)
+    {
+        for row in 0..2 * self.qubits {
+            self.r[row] ^= self.get_x(row, q) ^ self.get_z(row, q);
+        }
+    }
+
+    pub fn pauli_z(&mut self, q: usize)
+    {
+        for row in 0..2 * self.qubits {
+            self.r[row] ^= self.get_x(row, q);
+        }
+    }
+
+    pub fn cnot(&mut self, control: usize, target: usize)
+    {
+        assert!(control != target, "CNOT control and target must differ");
+        for row in 0..2 * self.qubits {
+            let (xc, zc) = (self.get_x(row, control), self.get_z(row, control));
+            let (xt, zt) = (self.get_x(row, target), self.get_z(row, target));
+            self.r[row] ^= xc & zt & !(xt ^ zc);
+            self.set_x(row, target, xt ^ xc);
+            self.set_z(row, control, zc ^ zt);
+        }
+    }
+
+    /// Measures qubit `q` in the Z basis, sampling random outcomes from `rng`.
+    pub fn measure<R>(&mut self, q: usize, rng: &mut R) -> Measurement
+    where
+        R: Rng + ?Sized
+    {
+        assert!(q < self.qubits, "qubit {} is outside a register of {} qubits", q, self.qubits);
+        let n: usize = self.qubits;
+
+        match (n..2 * n).find(|&row| self.get_x(row, q)) {
+            Some(p) => {
+                // Row `p - n` is overwritten below, so it is skipped here.
+                for row in 0..2 * n {
+                    if row != p && row != p - n && self.get_x(row, q) {
+                        self.rowsum(row, p);
+                    }
+                }
+                self.copy_row(p - n, p);
+                self.clear_row(p);
+                self.set_z(p, q, true);
+
+                let outcome: bool = rng.gen();
+                self.r[p] = outcome;
+                Measurement { outcome, deterministic: false }
+            },
+            None => {
+                let scratch: usize = 2 * n;
+                self.clear_row(scratch);
+                for row in 0..n {
+                    if self.get_x(row, q) {
+                        self.rowsum(scratch, row + n);
+                    }
+                }
+                Measurement { outcome: self.r[scratch], deterministic: true }
+            }
+        }
+    }
+
+    /// The `index`-th stabilizer generator.
+    pub fn stabilizer(&self, index: usize) -> PauliString
+    {
+        assert!(index < self.qubits, "stabilizer {} is outside a tableau of {} generators", index, self.qubits);
+        self.row(self.qubits + index)
+    }
+
+    /// The `index`-th destabilizer.
+    pub fn destabilizer(&self, index: usize) -> PauliString
+    {
+        assert!(index < self.qubits, "destabilizer {} is outside a tableau of {} generators", index, self.qubits);
+        self.row(index)
+    }
+
+    fn row(&self, row: usize) -> PauliString
+    {
+        let words: usize = self.words;
+        PauliString::from_words(
+            self.qubits,
+            if self.r[row] { 2 } else { 0 },
+            self.x[row * words..(row + 1) * words].to_vec(),
+            self.z[row * words..(row + 1) * words].to_vec()
+        )
+    }
+
+    /// Replaces row `h` with the product of rows `i` and `h`, tracking the sign.
+    fn rowsum(&mut self, h: usize, i: usize)
+    {
+        let (mut cnt1, mut cnt2) = (0u64, 0u64);
+        for w in 0..self.words {
+            let (x1, z1) = (self.x[i * self.words + w], self.z[i * self.words + w]);
+            let (x2, z2) = (self.x[h * self.words + w], self.z[h * self.words + w]);
+            accumulate_phase(x1, z1, x2, z2, &mut cnt1, &mut cnt2);
+
+            self.x[h * self.words + w] = x1 ^ x2;
+            self.z[h * self.words + w] = z1 ^ z2;
+        }
+        let exponent: u32 = cnt1.count_ones() + 2 * cnt2.count_ones()
+            + 2 * (self.r[h] as u32) + 2 * (self.r[i] as u32);
+        debug_assert!(exponent.is_multiple_of(2), "stabilizer rows must commute");
+        self.r[h] = exponent % 4 == 2;
+    }
+
+    fn copy_row(&mut self, dst: usize, src: usize)
+    {
+        let words: usize = self.words;
+        self.x.copy_within(src * words..(src + 1) * words, dst * words);
+        self.z.copy_within(src * words..(src + 1) * words, dst * words);
+        self.r[dst] = self.r[src];
+    }
+
+    fn clear_row(&mut self, row: usize)
+    {
+        let words: usize = self.words;
+        self.x[row * words..(row + 1) * words].iter_mut().for_each(|w| *w = 0);
+        self.z[row * words..(row + 1) * words].iter_mut().for_each(|w| *w = 0);
+        self.r[row] = false;
+    }
+
+    #[inline]
+    fn get_x(&self, row: usize, q: usize) -> bool
+    {
+        self.x[row * self.words + q / WORD] >> (q % WORD) & 1 == 1
+    }
+
+    #[inline]
+    fn get_z(&self, row: usize, q: usize) -> bool
+    {
+        self.z[row * self.words + q / WORD] >> (q % WORD) & 1 == 1
+    }
+
+    #[inline]
+    fn set_x(&mut self, row: usize, q: usize, bit: bool)
+    {
+        let word: &mut u64 = &mut self.x[row * self.words + q / WORD];
+        *word = (*word & !(1 << (q % WORD))) | ((bit as u64) << (q % WORD));
+    }
+
+    #[inline]
+    fn set_z(&mut self, row: usize, q: usize, bit: bool)
+    {
+        let word: &mut u64 = &mut self.z[row * self.words + q / WORD];
+        *word = (*word & !(1 << (q % WORD))) | ((bit as u64) << (q % WORD));
+    }
+}
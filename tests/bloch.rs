@@ -0,0 +1,203 @@
+use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_2, PI};
+
+use algebra::VPartialEq;
+use num_complex::Complex;
+use rand::{rngs::StdRng, SeedableRng};
+use vector::{mspace, vspace};
+
+use qlogic_rs::bloch::{self, Bloch};
+use qlogic_rs::{dense, Gate};
+
+mod common;
+use common::random_amplitudes;
+
+vspace! {
+    QubitSpace {
+        vector: Qubit,
+        dimension: 2,
+        using: [T; 2]
+    }
+}
+
+vspace! {
+    EuclideanSpace {
+        vector: Euclidean,
+        dimension: 3
+    }
+}
+
+mspace! {
+    OperatorSpace {
+        matrix: Operator,
+        dimension: 2,
+        using: [T; 4]
+    }
+}
+
+fn c(re: f64, im: f64) -> Complex<f64>
+{
+    Complex::new(re, im)
+}
+
+fn operator(entries: [Complex<f64>; 4]) -> Operator<Complex<f64>>
+{
+    Operator::new(entries)
+}
+
+fn close(lhs: &Bloch, rhs: &Bloch) -> bool
+{
+    (lhs.x - rhs.x).abs() < 1e-12 && (lhs.y - rhs.y).abs() < 1e-12 && (lhs.z - rhs.z).abs() < 1e-12
+}
+
+/// `exp(-iθ n·σ / 2)`, the rotation by `θ` about the unit axis `n`.
+fn rotation_gate(n: [f64; 3], theta: f64) -> Operator<Complex<f64>>
+{
+    let (cos, sin): (f64, f64) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+    operator([
+        c(cos, -sin * n[2]),
+        c(-sin * n[1], -sin * n[0]),
+        c(sin * n[1], -sin * n[0]),
+        c(cos, sin * n[2])
+    ])
+}
+
+#[test]
+fn test_basis_states()
+{
+    let space: QubitSpace<Complex<f64>> = QubitSpace::new();
+    let h: f64 = FRAC_1_SQRT_2;
+    let cases: [([Complex<f64>; 2], Bloch); 6] = [
+        ([c(1.0, 0.0), c(0.0, 0.0)], Bloch::new(0.0, 0.0, 1.0)),
+        ([c(0.0, 0.0), c(1.0, 0.0)], Bloch::new(0.0, 0.0, -1.0)),
+        ([c(h, 0.0), c(h, 0.0)], Bloch::new(1.0, 0.0, 0.0)),
+        ([c(h, 0.0), c(-h, 0.0)], Bloch::new(-1.0, 0.0, 0.0)),
+        ([c(h, 0.0), c(0.0, h)], Bloch::new(0.0, 1.0, 0.0)),
+        ([c(h, 0.0), c(0.0, -h)], Bloch::new(0.0, -1.0, 0.0))
+    ];
+    for (amplitudes, expected) in cases.iter() {
+        let point: Bloch = Bloch::from_state(&space, &Qubit::new(*amplitudes)).unwrap();
+        assert!(close(&point, expected), "{:?} != {:?}", point, expected);
+        assert!(point.is_pure());
+    }
+
+    // Normalisation and global phase do not matter.
+    let scaled: Qubit<Complex<f64>> = Qubit::new([c(0.0, 3.0), c(0.0, 3.0)]);
+    assert!(close(&Bloch::from_state(&space, &scaled).unwrap(), &Bloch::new(1.0, 0.0, 0.0)));
+
+    // The zero vector is not a state.
+    assert_eq!(Bloch::from_state(&space, &Qubit::new([c(0.0, 0.0); 2])), None);
+
+    let euclidean: EuclideanSpace<f64> = EuclideanSpace::new();
+    let vector: Euclidean<f64> = Bloch::new(0.0, 1.0, 0.0).to_vector();
+    assert!(euclidean.eq(&vector, &vec![0.0, 1.0, 0.0].into_iter().collect()));
+}
+
+#[test]
+fn test_angles_and_round_trips()
+{
+    let point: Bloch = Bloch::from_angles(2.0, 4.0);
+    assert!((point.theta() - 2.0).abs() < 1e-12);
+    assert!((point.phi() - 4.0).abs() < 1e-12);
+    assert_eq!(Bloch::new(0.0, 0.0, -1.0).theta(), PI);
+    assert_eq!(Bloch::new(0.0, -1.0, 0.0).phi(), 3.0 * FRAC_PI_2);
+
+    let space: QubitSpace<Complex<f64>> = QubitSpace::new();
+    let mut rng: StdRng = SeedableRng::seed_from_u64(11);
+    for _ in 0..64 {
+        let state: Qubit<Complex<f64>> = random_amplitudes(&mut rng, 2);
+        let point: Bloch = Bloch::from_state(&space, &state).unwrap();
+        assert!(close(&Bloch::from_state(&space, &point.to_state::<Qubit<Complex<f64>>>()).unwrap(), &point));
+        assert!(close(&Bloch::from_density(&point.to_density::<Operator<Complex<f64>>>()), &point));
+
+        // |ψ⟩⟨ψ| has the same point as |ψ⟩.
+        let norm: f64 = state[0].norm_sqr() + state[1].norm_sqr();
+        let density: Operator<Complex<f64>> = (0..4).map(|k| state[k / 2] * state[k % 2].conj() / norm).collect();
+        assert!(close(&Bloch::from_density(&density), &point));
+    }
+}
+
+#[test]
+fn test_mixed_states()
+{
+    let maximally_mixed: Bloch = Bloch::from_density(&operator([c(0.5, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(0.5, 0.0)]));
+    assert_eq!(maximally_mixed.radius(), 0.0);
+    assert_eq!(maximally_mixed.purity(), 0.5);
+    assert!(!maximally_mixed.is_pure());
+
+    // An equal mixture of |0⟩ and |+⟩.
+    let density: Operator<Complex<f64>> = operator([c(0.75, 0.0), c(0.25, 0.0), c(0.25, 0.0), c(0.25, 0.0)]);
+    let point: Bloch = Bloch::from_density(&density);
+    assert!(close(&point, &Bloch::new(0.5, 0.0, 0.5)));
+    assert!((point.radius() - FRAC_1_SQRT_2).abs() < 1e-12);
+    assert!((point.purity() - 0.75).abs() < 1e-12);
+
+    // The purity agrees with tr(ρ²).
+    let trace: f64 = (0..2).flat_map(|i| (0..2).map(move |j| (i, j))).map(|(i, j)| (density[(i, j)] * density[(j, i)]).re).sum();
+    assert!((trace - point.purity()).abs() < 1e-12);
+}
+
+#[test]
+fn test_gates_rotate_the_sphere()
+{
+    let h: f64 = FRAC_1_SQRT_2;
+    let gates: [(Gate, Operator<Complex<f64>>); 5] = [
+        (Gate::H(0), operator([c(h, 0.0), c(h, 0.0), c(h, 0.0), c(-h, 0.0)])),
+        (Gate::S(0), operator([c(1.0, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(0.0, 1.0)])),
+        (Gate::X(0), operator([c(0.0, 0.0), c(1.0, 0.0), c(1.0, 0.0), c(0.0, 0.0)])),
+        (Gate::Y(0), operator([c(0.0, 0.0), c(0.0, -1.0), c(0.0, 1.0), c(0.0, 0.0)])),
+        (Gate::Z(0), operator([c(1.0, 0.0), c(0.0, 0.0), c(0.0, 0.0), c(-1.0, 0.0)]))
+    ];
+    let space: QubitSpace<Complex<f64>> = QubitSpace::new();
+    let mut rng: StdRng = SeedableRng::seed_from_u64(5);
+    for (gate, matrix) in gates.iter() {
+        let mut state: Qubit<Complex<f64>> = random_amplitudes(&mut rng, 2);
+        let before: Bloch = Bloch::from_state(&space, &state).unwrap();
+        dense::apply(&mut state, 1, gate);
+        assert!(close(&before.apply(matrix), &Bloch::from_state(&space, &state).unwrap()), "{:?}", gate);
+    }
+
+    // The Hadamard swaps the x and z axes.
+    let rotation: [[f64; 3]; 3] = bloch::rotation(&gates[0].1).to_rotation_matrix();
+    let expected: [[f64; 3]; 3] = [[0.0, 0.0, 1.0], [0.0, -1.0, 0.0], [1.0, 0.0, 0.0]];
+    for (row, expected) in rotation.iter().zip(expected.iter()) {
+        assert!(row.iter().zip(expected.iter()).all(|(r, e)| (r - e).abs() < 1e-12));
+    }
+
+    // exp(-iθ n·σ / 2) turns the sphere by θ about n, whatever the global phase.
+    let gate: Operator<Complex<f64>> = rotation_gate([0.0, 0.0, 1.0], FRAC_PI_2);
+    assert!(close(&Bloch::new(1.0, 0.0, 0.0).apply(&gate), &Bloch::new(0.0, 1.0, 0.0)));
+    let phased: Operator<Complex<f64>> = (&gate).into_iter().map(|&z| z * Complex::from_polar(&1.0, &0.7)).collect();
+    assert!(close(&Bloch::new(1.0, 0.0, 0.0).apply(&phased), &Bloch::new(0.0, 1.0, 0.0)));
+
+    let n: [f64; 3] = [1.0 / 3.0, -2.0 / 3.0, 2.0 / 3.0];
+    let (axis, angle): (Euclidean<f64>, f64) = bloch::rotation(&rotation_gate(n, 1.2)).to_axis_angle(&EuclideanSpace::new());
+    assert!((angle - 1.2).abs() < 1e-12);
+    assert!((&axis).into_iter().zip(n.iter()).all(|(a, b)| (a - b).abs() < 1e-12));
+
+    // Rotations preserve the radius of mixed states.
+    assert!((Bloch::new(0.3, -0.2, 0.1).apply(&gate).radius() - Bloch::new(0.3, -0.2, 0.1).radius()).abs() < 1e-12);
+}
+
+#[test]
+#[should_panic(expected = "not unitary")]
+fn test_rotation_rejects_non_unitary_gates()
+{
+    bloch::rotation(&operator([c(1.0, 0.0), c(1.0, 0.0), c(0.0, 0.0), c(1.0, 0.0)]));
+}
+
+#[test]
+fn test_render()
+{
+    let zero: String = Bloch::new(0.0, 0.0, 1.0).render(2);
+    assert_eq!(zero, ["    *", " .  |  .", ".---+---.", " .  |  .", "    1"].join("\n"));
+
+    let plus_i: String = Bloch::new(0.0, 1.0, 0.0).render(3);
+    assert_eq!(
+        plus_i,
+        ["      0", "  .   |   .", ".     |     .", ".-----+-----*", ".     |     .", "  .   |   .", "      1"].join("\n")
+    );
+
+    // Points on the far side of the sphere are drawn hollow.
+    let behind: String = Bloch::from_angles(2.0, PI).render(3);
+    assert_eq!(behind.lines().nth(4), Some(".     o     ."));
+}
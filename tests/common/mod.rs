@@ -0,0 +1,32 @@
+//! Random states and approximate assertions shared by the integration tests.
+//!
+//! Each test crate compiles its own copy and uses only some of it.
+#![allow(dead_code)]
+
+use std::fmt::Debug;
+use std::iter::FromIterator;
+
+use num_complex::Complex;
+use rand::{rngs::StdRng, Rng};
+
+/// `len` amplitudes whose parts are uniform in `[-1, 1)`; the state is not
+/// normalised.
+pub fn random_amplitudes<V>(rng: &mut StdRng, len: usize) -> V
+where
+    V: FromIterator<Complex<f64>>
+{
+    (0..len)
+        .map(|_| Complex::new(rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0)))
+        .collect()
+}
+
+/// Asserts that two states agree amplitude by amplitude.
+pub fn assert_close<V>(lhs: &V, rhs: &V)
+where
+    V: Debug,
+    for<'a> &'a V: IntoIterator<Item=&'a Complex<f64>>
+{
+    for (l, r) in lhs.into_iter().zip(rhs) {
+        assert!((l - r).norm() < 1e-9, "Expected: {:?}, Got: {:?}", rhs, lhs);
+    }
+}
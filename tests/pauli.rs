@@ -0,0 +1,159 @@
+use num_complex::Complex;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use vector::vspace;
+
+use qlogic_rs::{dense, Gate, ParsePauliError, Pauli, PauliString, PauliSum};
+
+mod common;
+use common::{assert_close, random_amplitudes};
+
+const QUBITS: usize = 3;
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 8,
+        using: [T; 8]
+    }
+}
+
+fn pauli(s: &str) -> PauliString
+{
+    s.parse().unwrap()
+}
+
+fn random_string(rng: &mut StdRng, qubits: usize) -> PauliString
+{
+    let paulis = [Pauli::I, Pauli::X, Pauli::Y, Pauli::Z];
+    (0..qubits)
+        .map(|_| paulis[rng.gen_range(0, 4)])
+        .collect::<PauliString>()
+        .with_phase(rng.gen_range(0, 4))
+}
+
+fn bell_state() -> State<Complex<f64>>
+{
+    let mut state = State::new([Complex::new(0.0, 0.0); 8]);
+    dense::zero(&mut state, QUBITS);
+    dense::apply(&mut state, QUBITS, &Gate::H(0));
+    dense::apply(&mut state, QUBITS, &Gate::CNOT(0, 1));
+    state
+}
+
+#[test]
+fn test_parse_and_format()
+{
+    for text in ["+XIZY", "-ZZ", "+iXY", "-iI"].iter() {
+        assert_eq!(pauli(text).to_string(), *text);
+    }
+    assert_eq!(pauli("XIZY").to_string(), "+XIZY");
+    assert_eq!(pauli("iZ").phase_exponent(), 1);
+    assert_eq!(pauli("XIZY").get(3), Pauli::Y);
+
+    assert_eq!("".parse::<PauliString>(), Err(ParsePauliError::Empty));
+    assert_eq!(
+        "-XQ".parse::<PauliString>(),
+        Err(ParsePauliError::InvalidCharacter { index: 2, character: 'Q' })
+    );
+}
+
+#[test]
+fn test_multiplication_tracks_phase()
+{
+    assert_eq!(pauli("X") * pauli("Y"), pauli("iZ"));
+    assert_eq!(pauli("Y") * pauli("X"), pauli("-iZ"));
+    assert_eq!(pauli("Z") * pauli("X"), pauli("iY"));
+    assert_eq!(pauli("XX") * pauli("ZZ"), pauli("-YY"));
+    assert_eq!(pauli("-iXZ") * pauli("iXZ"), pauli("II"));
+}
+
+#[test]
+fn test_wide_multiplication_matches_single_qubit_products()
+{
+    let mut rng = StdRng::seed_from_u64(11);
+    let (lhs, rhs) = (random_string(&mut rng, 150), random_string(&mut rng, 150));
+
+    let mut expected: PauliString = PauliString::identity(150)
+        .with_phase(lhs.phase_exponent() + rhs.phase_exponent());
+    for q in 0..150 {
+        let product: PauliString = pauli(&lhs.get(q).to_string()) * pauli(&rhs.get(q).to_string());
+        expected.set(q, product.get(0));
+        expected = expected.with_phase(product.phase_exponent());
+    }
+    assert_eq!(&lhs * &rhs, expected);
+}
+
+#[test]
+fn test_commutation_and_weight()
+{
+    assert!(pauli("XX").commutes_with(&pauli("ZZ")));
+    assert!(pauli("XI").anticommutes_with(&pauli("ZI")));
+    assert!(pauli("XYZ").commutes_with(&pauli("-iXYZ")));
+    assert_eq!(pauli("XIZY").weight(), 3);
+    assert_eq!(PauliString::identity(100).weight(), 0);
+}
+
+#[test]
+fn test_apply_matches_dense_gates()
+{
+    let mut rng = StdRng::seed_from_u64(5);
+    let state: State<Complex<f64>> = random_amplitudes(&mut rng, 8);
+
+    let mut expected = state.clone();
+    dense::pauli_x(&mut expected, QUBITS, 0);
+    dense::pauli_y(&mut expected, QUBITS, 1);
+    dense::pauli_z(&mut expected, QUBITS, 2);
+    assert_close(&pauli("XYZ").apply(&state), &expected);
+}
+
+#[test]
+fn test_apply_respects_products()
+{
+    let mut rng = StdRng::seed_from_u64(9);
+    for _ in 0..20 {
+        let state: State<Complex<f64>> = random_amplitudes(&mut rng, 8);
+        let (p, q) = (random_string(&mut rng, QUBITS), random_string(&mut rng, QUBITS));
+        assert_close(&(&p * &q).apply(&state), &p.apply(&q.apply(&state)));
+    }
+}
+
+#[test]
+fn test_expectation_values()
+{
+    let space = StateSpace::new();
+    let bell = bell_state();
+
+    assert!((pauli("XXI").expectation(&space, &bell) - 1.0).norm() < 1e-9);
+    assert!((pauli("ZZI").expectation(&space, &bell) - 1.0).norm() < 1e-9);
+    assert!((pauli("YYI").expectation(&space, &bell) + 1.0).norm() < 1e-9);
+    assert!(pauli("ZII").expectation(&space, &bell).norm() < 1e-9);
+}
+
+#[test]
+fn test_hamiltonian_expectation()
+{
+    let space = StateSpace::new();
+    let bell = bell_state();
+
+    let mut hamiltonian: PauliSum = PauliSum::new(3);
+    assert_eq!(hamiltonian.expectation(&space, &bell), Complex::new(0.0, 0.0));
+    assert!(hamiltonian.apply(&bell).into_iter().all(|amplitude| amplitude.norm() == 0.0));
+
+    hamiltonian.extend(vec![
+        (0.5, pauli("ZZI")),
+        (0.25, pauli("XXI")),
+        (-1.0, pauli("ZII")),
+        (2.0, pauli("IIZ"))
+    ]);
+
+    let energy: Complex<f64> = hamiltonian.expectation(&space, &bell);
+    assert!((energy - 2.75).norm() < 1e-9, "Expected: 2.75, Got: {}", energy);
+
+    let image = hamiltonian.apply(&bell);
+    let direct: Complex<f64> = bell
+        .into_iter()
+        .zip(&image)
+        .map(|(l, r)| l.conj() * r)
+        .sum();
+    assert!((direct - energy).norm() < 1e-9);
+}
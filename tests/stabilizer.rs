@@ -0,0 +1,142 @@
+use num_complex::Complex;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use vector::vspace;
+
+use qlogic_rs::{dense, stabilizer::Tableau, Gate, Pauli, PauliString};
+
+mod common;
+use common::assert_close;
+
+const QUBITS: usize = 4;
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 16,
+        using: [T; 16]
+    }
+}
+
+fn zero_state() -> State<Complex<f64>>
+{
+    let mut state = State::new([Complex::new(0.0, 0.0); 16]);
+    dense::zero(&mut state, QUBITS);
+    state
+}
+
+fn random_circuit(rng: &mut StdRng, length: usize) -> Vec<Gate>
+{
+    (0..length)
+        .map(|_| {
+            let q: usize = rng.gen_range(0, QUBITS);
+            match rng.gen_range(0, 6) {
+                0 => Gate::H(q),
+                1 => Gate::S(q),
+                2 => Gate::X(q),
+                3 => Gate::Y(q),
+                4 => Gate::Z(q),
+                _ => Gate::CNOT(q, (q + rng.gen_range(1, QUBITS)) % QUBITS)
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_initial_stabilizers()
+{
+    let tableau = Tableau::new(3);
+    for q in 0..3 {
+        let stabilizer: PauliString = tableau.stabilizer(q);
+        assert_eq!(stabilizer.phase_exponent(), 0);
+        for (idx, pauli) in stabilizer.iter().enumerate() {
+            assert_eq!(pauli, if idx == q { Pauli::Z } else { Pauli::I });
+        }
+    }
+}
+
+#[test]
+fn test_bell_state_measurements_agree()
+{
+    let mut rng = StdRng::seed_from_u64(7);
+    for _ in 0..20 {
+        let mut tableau = Tableau::new(2);
+        tableau.apply(&Gate::H(0));
+        tableau.apply(&Gate::CNOT(0, 1));
+
+        let first = tableau.measure(0, &mut rng);
+        let second = tableau.measure(1, &mut rng);
+        assert!(!first.deterministic);
+        assert!(second.deterministic);
+        assert_eq!(first.outcome, second.outcome);
+    }
+}
+
+#[test]
+fn test_stabilizers_fix_dense_state()
+{
+    let mut rng = StdRng::seed_from_u64(42);
+    for _ in 0..50 {
+        let circuit: Vec<Gate> = random_circuit(&mut rng, 40);
+
+        let mut tableau = Tableau::new(QUBITS);
+        let mut state = zero_state();
+        for gate in circuit.iter() {
+            tableau.apply(gate);
+            dense::apply(&mut state, QUBITS, gate);
+        }
+
+        for idx in 0..QUBITS {
+            let image = tableau.stabilizer(idx).apply(&state);
+            assert_close(&image, &state);
+        }
+    }
+}
+
+#[test]
+fn test_measurements_cross_validate_against_dense()
+{
+    let mut rng = StdRng::seed_from_u64(1234);
+    for _ in 0..50 {
+        let circuit: Vec<Gate> = random_circuit(&mut rng, 40);
+
+        let mut tableau = Tableau::new(QUBITS);
+        let mut state = zero_state();
+        for gate in circuit.iter() {
+            tableau.apply(gate);
+            dense::apply(&mut state, QUBITS, gate);
+        }
+
+        for q in 0..QUBITS {
+            let p_one: f64 = dense::probability(&state, QUBITS, q);
+            let measurement = tableau.measure(q, &mut rng);
+            if measurement.deterministic {
+                let expected: f64 = if measurement.outcome { 1.0 } else { 0.0 };
+                assert!((p_one - expected).abs() < 1e-9, "Expected: {}, Got: {}", expected, p_one);
+            } else {
+                assert!((p_one - 0.5).abs() < 1e-9, "Expected: 0.5, Got: {}", p_one);
+            }
+            dense::collapse(&mut state, QUBITS, q, measurement.outcome);
+        }
+    }
+}
+
+#[test]
+fn test_large_ghz_state()
+{
+    const N: usize = 1000;
+    let mut rng = StdRng::seed_from_u64(3);
+
+    let mut tableau = Tableau::new(N);
+    tableau.apply(&Gate::H(0));
+    for q in 1..N {
+        tableau.apply(&Gate::CNOT(q - 1, q));
+    }
+
+    let first = tableau.measure(N - 1, &mut rng);
+    assert!(!first.deterministic);
+    for q in (0..N - 1).step_by(97) {
+        let measurement = tableau.measure(q, &mut rng);
+        assert!(measurement.deterministic);
+        assert_eq!(measurement.outcome, first.outcome);
+    }
+}
@@ -1,5 +1,4 @@
 
-#[macro_use]
 macro_rules! benchmark
 {
     (
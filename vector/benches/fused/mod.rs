@@ -0,0 +1,88 @@
+macro_rules! benchmark
+{
+    (
+       $(
+           ($uid:ident, $dimension:expr)
+        ),+
+    ) => {
+        $(
+            pub use $uid::$uid;
+
+            #[allow(unused_imports)]
+            mod $uid
+            {
+                use criterion::{criterion_group, Criterion};
+                use vector::vspace;
+                use vector::expr::{lazy, Expression};
+                use algebra::*;
+
+                const DIMENSION: usize = $dimension;
+
+                vspace! {
+                    Space {
+                        vector: Vector,
+                        dimension: $dimension
+                    }
+                }
+
+                fn random() -> Vector<f64> {
+                    use rand::{thread_rng, Rng};
+
+                    (0..DIMENSION).map(|_| thread_rng().gen()).collect()
+                }
+
+                /// `w = a·x + b·y - z`.
+                fn bench_axpbymz_fused_against_unfused(bench: &mut Criterion)
+                {
+                    let mut group: _ = bench.benchmark_group(
+                        concat!( stringify!($uid), "-axpbymz-reference" )
+                    );
+
+                    let vector_space = Space::new();
+                    let (x, y, z): (Vector<f64>, Vector<f64>, Vector<f64>) = (random(), random(), random());
+                    let (a, b): (f64, f64) = (1.5, -0.25);
+
+                    {
+                        group.bench_function("unfused", |c| {
+                            c.iter(|| {
+                                let mut w: Vector<f64> = vector_space.vscale(&x, &a);
+                                vector_space.vaxpy_mut(&b, &y, &mut w);
+                                vector_space.vaxpy_mut(&-1.0, &z, &mut w);
+                                w
+                            })
+                        });
+                    }
+
+                    {
+                        group.bench_function("vadd", |c| {
+                            c.iter(|| {
+                                let mut w: Vector<f64> = vector_space.vscale(&x, &a);
+                                let scaled: Vector<f64> = vector_space.vscale(&y, &b);
+                                let negated: Vector<f64> = vector_space.additive_inv(&z);
+                                vadd!(vector_space, w, &scaled, &negated)
+                            })
+                        });
+                    }
+
+                    {
+                        let mut w: Vector<f64> = random();
+                        group.bench_function("fused", |c| {
+                            c.iter(|| (a * lazy(&x) + b * lazy(&y) - lazy(&z)).assign(&mut w))
+                        });
+                    }
+                }
+
+                criterion_group!(
+                    $uid,
+                    bench_axpbymz_fused_against_unfused
+                );
+            }
+        )+
+    };
+}
+
+benchmark!{
+    (fused_smallvec, 32),
+    (fused_medvec, 1024),
+    (fused_bigvec, 1000000)
+}
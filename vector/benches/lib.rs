@@ -1,6 +1,10 @@
+#![allow(clippy::let_with_type_underscore)]
+
 use criterion::criterion_main;
 
 mod comparative;
+mod fused;
+mod sparse;
 
 criterion_main!(
     comparative::cmp_nalgebra::nalgebra_smallvec,
@@ -10,4 +14,11 @@ criterion_main!(
     comparative::cmp_ndarray::ndarray_smallvec,
     comparative::cmp_ndarray::ndarray_medvec,
     comparative::cmp_ndarray::ndarray_bigvec,
+
+    fused::fused_smallvec,
+    fused::fused_medvec,
+    fused::fused_bigvec,
+
+    sparse::sparse_smallmat,
+    sparse::sparse_medmat,
 );
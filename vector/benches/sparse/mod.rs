@@ -0,0 +1,101 @@
+macro_rules! benchmark
+{
+    (
+       $(
+           ($uid:ident, $dimension:expr)
+        ),+
+    ) => {
+        $(
+            pub use $uid::$uid;
+
+            #[allow(unused_imports)]
+            mod $uid
+            {
+                use criterion::{criterion_group, Criterion};
+                use vector::{mspace, vspace};
+                use vector::sparse::CooMatrix;
+                use algebra::*;
+
+                const DIMENSION: usize = $dimension;
+
+                mspace! {
+                    MatrixSpace {
+                        matrix: Matrix,
+                        dimension: $dimension
+                    }
+                }
+
+                vspace! {
+                    Space {
+                        vector: Vector,
+                        dimension: $dimension
+                    }
+                }
+
+                /// A banded matrix with five non-zero diagonals, like a nearest-neighbour Hamiltonian.
+                fn banded() -> CooMatrix<f64> {
+                    use rand::{thread_rng, Rng};
+
+                    let mut coo: CooMatrix<f64> = CooMatrix::new(DIMENSION, DIMENSION);
+                    for i in 0..DIMENSION {
+                        for j in i.saturating_sub(2)..(i + 3).min(DIMENSION) {
+                            coo.push(i, j, thread_rng().gen()).unwrap();
+                        }
+                    }
+                    coo
+                }
+
+                fn random() -> Vector<f64> {
+                    use rand::{thread_rng, Rng};
+
+                    (0..DIMENSION).map(|_| thread_rng().gen()).collect()
+                }
+
+                fn bench_gemv_against_dense(bench: &mut Criterion)
+                {
+                    let mut group: _ = bench.benchmark_group(
+                        concat!( stringify!($uid), "-gemv-reference" )
+                    );
+
+                    let coo: CooMatrix<f64> = banded();
+                    let x: Vector<f64> = random();
+                    let y: Vector<f64> = random();
+
+                    {
+                        let csr = coo.to_csr();
+                        let mut output: Vector<f64> = y.clone();
+                        group.bench_function("csr", |c| {
+                            c.iter(|| csr.gemv_mut(&1.0, &x, &0.5, &mut output))
+                        });
+                    }
+
+                    {
+                        let csc = coo.to_csc();
+                        let mut output: Vector<f64> = y.clone();
+                        group.bench_function("csc", |c| {
+                            c.iter(|| csc.gemv_mut(&1.0, &x, &0.5, &mut output))
+                        });
+                    }
+
+                    {
+                        let dense: Matrix<f64> = coo.to_csr().to_dense();
+                        let mut output: Vector<f64> = y.clone();
+                        group.bench_function("dense", |c| {
+                            c.iter(|| dense.gemv_mut(&1.0, &x, &0.5, &mut output))
+                        });
+                    }
+                }
+
+                criterion_group!(
+                    $uid,
+                    bench_gemv_against_dense
+                );
+            }
+        )+
+    };
+}
+
+benchmark!{
+    (sparse_smallmat, 64),
+    (sparse_medmat, 1024)
+}
@@ -4,6 +4,8 @@ macro_rules! vector_base {
         #[derive(Clone)]
         pub struct $name<$T>($inner);  
 
+        serde_base!($length, $name, $inner, $T);
+
         impl<$T> $name<$T>
         {
             pub fn new(inner: $inner) -> Self 
@@ -13,6 +15,85 @@ macro_rules! vector_base {
             }
         }        
 
+        impl<$T> $name<$T>
+        {
+            pub fn view(&self) -> VectorView<'_, $T>
+            {
+                VectorView::new(&self.0[..], 1, $length)
+            }
+
+            pub fn view_mut(&mut self) -> VectorViewMut<'_, $T>
+            {
+                VectorViewMut::new(&mut self.0[..], 1, $length)
+            }
+
+            /// The entries in `range`.
+            pub fn slice<R>(&self, range: R) -> VectorView<'_, $T>
+            where
+                R: ops::RangeBounds<usize>
+            {
+                self.view().slice(range)
+            }
+
+            /// The entries in `range`, mutably.
+            pub fn slice_mut<R>(&mut self, range: R) -> VectorViewMut<'_, $T>
+            where
+                R: ops::RangeBounds<usize>
+            {
+                self.view_mut().slice_mut(range)
+            }
+
+            /// Disjoint mutable views of the first `mid` entries and the rest.
+            pub fn split_at_mut(&mut self, mid: usize) -> (VectorViewMut<'_, $T>, VectorViewMut<'_, $T>)
+            {
+                self.view_mut().split_at_mut(mid)
+            }
+
+            /// Consecutive views of `size` entries; the last may be shorter.
+            pub fn chunks(&self, size: usize) -> impl Iterator<Item=VectorView<'_, $T>>
+            {
+                self.view().chunks(size)
+            }
+
+            /// Consecutive disjoint mutable views of `size` entries; the last may be shorter.
+            pub fn chunks_mut(&mut self, size: usize) -> view::ChunksMut<'_, $T>
+            {
+                self.view_mut().chunks_mut(size)
+            }
+        }
+
+        impl<$T> Strided for $name<$T>
+        {
+            type Scalar = $T;
+
+            #[inline]
+            fn len(&self) -> usize
+            {
+                $length
+            }
+
+            #[inline]
+            fn stride(&self) -> usize
+            {
+                1
+            }
+
+            #[inline]
+            fn as_strided_slice(&self) -> &[$T]
+            {
+                &self.0[..]
+            }
+        }
+
+        impl<$T> StridedMut for $name<$T>
+        {
+            #[inline]
+            fn as_strided_slice_mut(&mut self) -> &mut [$T]
+            {
+                &mut self.0[..]
+            }
+        }
+
         impl<$T> From<$inner> for $name<$T>
         {
             fn from(inner: $inner) -> Self {
@@ -33,16 +114,20 @@ macro_rules! vector_base {
 
         impl<$T> FromIterator<$T> for $name<$T>
         where
-            $inner: FromIterator<$T>
+            $inner: TryFrom<Vec<$T>>
         {
             fn from_iter<I>(iterator: I) -> Self
             where
                 I: IntoIterator<Item=$T>
             {
-                let buf: $inner = iterator
+                let buf: Vec<$T> = iterator
                     .into_iter()
                     .collect();
-                Self::new(buf)
+                assert!(buf.len() == $length);
+                match <$inner>::try_from(buf) {
+                    Ok(inner) => Self::new(inner),
+                    Err(_) => unreachable!()
+                }
             }
         }
 
@@ -61,6 +146,22 @@ macro_rules! vector_base {
             }
         }
 
+        impl<$T> AsRef<[$T]> for $name<$T>
+        {
+            fn as_ref(&self) -> &[$T]
+            {
+                &self.0[..]
+            }
+        }
+
+        impl<$T> AsMut<[$T]> for $name<$T>
+        {
+            fn as_mut(&mut self) -> &mut [$T]
+            {
+                &mut self.0[..]
+            }
+        }
+
         impl<$T> Index<usize> for $name<$T>
         {
             type Output = $T;
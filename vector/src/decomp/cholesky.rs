@@ -0,0 +1,96 @@
+use std::{cmp::Ordering, iter::FromIterator};
+
+use algebra::{AdditiveIdentity, ComplexField, MultiplicativeIdentity};
+
+use crate::Dense;
+use super::{back_substitute, gather, square, DecompError};
+
+/// The factorisation `A = LL*` of a Hermitian positive-definite matrix.
+#[derive(Clone, Debug)]
+pub struct Cholesky<M>
+{
+    l: M
+}
+
+/// Factorises a Hermitian positive-definite matrix, reading only its lower triangle.
+pub fn cholesky<M>(matrix: &M) -> Result<Cholesky<M>, DecompError>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    let n: usize = square(matrix)?;
+    let mut l: M = matrix.clone();
+    let a: &mut [M::Scalar] = l.as_mut_slice();
+    let zero = <M::Scalar as ComplexField>::Real::additive_identity();
+
+    for j in 0..n {
+        let mut diagonal = a[j * n + j].real();
+        for k in 0..j {
+            diagonal -= a[j * n + k].modulus_squared();
+        }
+        // Comparing through `partial_cmp` also rejects a NaN diagonal.
+        if diagonal.partial_cmp(&zero) != Some(Ordering::Greater) {
+            return Err(DecompError::NotPositiveDefinite { pivot: j })
+        }
+        let pivot: M::Scalar = M::Scalar::from_real(diagonal.sqrt());
+        a[j * n + j] = pivot;
+
+        for i in j + 1..n {
+            let mut acc: M::Scalar = a[i * n + j];
+            for k in 0..j {
+                acc -= a[i * n + k] * a[j * n + k].conjugate();
+            }
+            a[i * n + j] = acc / pivot;
+        }
+        for k in j + 1..n {
+            a[j * n + k] = M::Scalar::additive_identity();
+        }
+    }
+    Ok(Cholesky { l })
+}
+
+impl<M> Cholesky<M>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    /// The lower-triangular factor with a positive real diagonal.
+    pub fn l(&self) -> &M
+    {
+        &self.l
+    }
+
+    pub fn det(&self) -> M::Scalar
+    {
+        let n: usize = self.l.rows();
+        let a: &[M::Scalar] = self.l.as_slice();
+        (0..n)
+            .map(|i| a[i * n + i] * a[i * n + i])
+            .fold(M::Scalar::multiplicative_identity(), |acc, val| acc * val)
+    }
+
+    /// Solves `Ax = b` as `Ly = b` followed by `L*x = y`.
+    pub fn solve<V>(&self, b: &V) -> Result<V, DecompError>
+    where
+        V: FromIterator<M::Scalar>,
+        for<'a> &'a V: IntoIterator<Item=&'a M::Scalar>
+    {
+        let n: usize = self.l.rows();
+        let a: &[M::Scalar] = self.l.as_slice();
+        let mut x: Vec<M::Scalar> = gather(b, n)?;
+
+        for i in 0..n {
+            for j in 0..i {
+                let update: M::Scalar = a[i * n + j] * x[j];
+                x[i] -= update;
+            }
+            x[i] /= a[i * n + i];
+        }
+
+        let adjoint: Vec<M::Scalar> = (0..n * n)
+            .map(|idx| a[(idx % n) * n + idx / n].conjugate())
+            .collect();
+        back_substitute(&adjoint, n, &mut x);
+        Ok(x.into_iter().collect())
+    }
+}
@@ -0,0 +1,121 @@
+use std::iter::FromIterator;
+
+use algebra::{AdditiveIdentity, ComplexField, RealField};
+
+use crate::Dense;
+use super::{finite, identity, rotation, square, tolerance, DecompError, MAX_SWEEPS};
+
+/// The eigen-decomposition `A = VΛV*` of a Hermitian matrix.
+///
+/// Eigenvalues are sorted ascending and `vectors()[k]` is the unit
+/// eigenvector of `values()[k]`.
+#[derive(Clone, Debug)]
+pub struct Eigen<V, R>
+{
+    values: Vec<R>,
+    vectors: Vec<V>
+}
+
+impl<V, R> Eigen<V, R>
+{
+    pub fn values(&self) -> &[R]
+    {
+        &self.values
+    }
+
+    pub fn vectors(&self) -> &[V]
+    {
+        &self.vectors
+    }
+
+    pub fn into_parts(self) -> (Vec<R>, Vec<V>)
+    {
+        (self.values, self.vectors)
+    }
+}
+
+/// Diagonalises a real symmetric or complex Hermitian matrix with cyclic Jacobi rotations.
+///
+/// Each rotation `J` zeroes one off-diagonal pair of `J*AJ`; sweeps repeat until
+/// the off-diagonal mass falls below machine precision relative to the matrix.
+pub fn eigh<M, V>(matrix: &M) -> Result<Eigen<V, <M::Scalar as ComplexField>::Real>, DecompError>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField,
+    V: FromIterator<M::Scalar>
+{
+    let n: usize = square(matrix)?;
+    finite(matrix.as_slice(), n)?;
+    let mut a: Vec<M::Scalar> = matrix.as_slice().to_vec();
+    let mut v: Vec<M::Scalar> = a.clone();
+    identity(&mut v, n);
+
+    let tol = tolerance(&a, n);
+    for i in 0..n {
+        for j in 0..=i {
+            if (a[i * n + j] - a[j * n + i].conjugate()).modulus() > tol {
+                return Err(DecompError::NotHermitian { row: i, column: j })
+            }
+        }
+    }
+
+    let zero = <M::Scalar as ComplexField>::Real::additive_identity();
+
+    let norm = a.iter().map(|val| val.modulus_squared()).fold(zero, |acc, val| acc + val);
+    let threshold = norm * <M::Scalar as ComplexField>::Real::epsilon() * <M::Scalar as ComplexField>::Real::epsilon();
+
+    let mut converged: bool = false;
+    for _ in 0..MAX_SWEEPS {
+        let off_diagonal = (0..n * n)
+            .filter(|idx| idx / n != idx % n)
+            .map(|idx| a[idx].modulus_squared())
+            .fold(zero, |acc, val| acc + val);
+        if off_diagonal <= threshold {
+            converged = true;
+            break;
+        }
+
+        for p in 0..n {
+            for q in p + 1..n {
+                if a[p * n + q].modulus() == zero {
+                    continue;
+                }
+                let (c, sp, sq) = rotation(a[p * n + p].real(), a[q * n + q].real(), a[p * n + q]);
+
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = akp * c - akq * sq;
+                    a[k * n + q] = akp * sp + akq * c;
+
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = vkp * c - vkq * sq;
+                    v[k * n + q] = vkp * sp + vkq * c;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - sp * aqk;
+                    a[q * n + k] = sq * apk + c * aqk;
+                }
+                a[p * n + q] = M::Scalar::additive_identity();
+                a[q * n + p] = M::Scalar::additive_identity();
+            }
+        }
+    }
+    if !converged {
+        return Err(DecompError::NoConvergence { iterations: MAX_SWEEPS })
+    }
+
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| {
+        a[i * n + i].real()
+            .partial_cmp(&a[j * n + j].real())
+            .expect("eigenvalues must be comparable")
+    });
+
+    let values = order.iter().map(|&i| a[i * n + i].real()).collect();
+    let vectors: Vec<V> = order
+        .iter()
+        .map(|&j| (0..n).map(|k| v[k * n + j]).collect())
+        .collect();
+    Ok(Eigen { values, vectors })
+}
@@ -0,0 +1,324 @@
+use std::iter::FromIterator;
+
+use algebra::{
+    AdditiveIdentity, ComplexField, DotV, LinearOperator, MultiplicativeIdentity, RealField, StridedMut, VAXPYMut,
+    VScaleMut, VectorSpace
+};
+
+use crate::Dense;
+use super::{eigh, finite, identity, inner, lu, square, Buffer, DecompError};
+
+/// Padé coefficients `b_0, …, b_m` for the degrees of Higham's scaling and squaring method.
+const PADE_3: [f64; 4] = [120.0, 60.0, 12.0, 1.0];
+const PADE_5: [f64; 6] = [30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0];
+const PADE_7: [f64; 8] = [17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0];
+const PADE_9: [f64; 10] = [
+    17643225600.0, 8821612800.0, 2075673600.0, 302702400.0, 30270240.0,
+    2162160.0, 110880.0, 3960.0, 90.0, 1.0
+];
+const PADE_13: [f64; 14] = [
+    64764752532480000.0, 32382376266240000.0, 7771770303897600.0, 1187353796428800.0,
+    129060195264000.0, 10559470521600.0, 670442572800.0, 33522128640.0, 1323241920.0,
+    40840800.0, 960960.0, 16380.0, 182.0, 1.0
+];
+
+/// The largest 1-norm for which each degree is accurate to double precision.
+const THETA: [(usize, f64); 4] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068)
+];
+const THETA_13: f64 = 5.371920351148152;
+
+/// The number of times `expm_multiply` halves a time step before giving up.
+const MAX_HALVINGS: usize = 64;
+
+/// The matrix exponential `e^A` by scaling and squaring with a Padé approximant.
+///
+/// The Padé degree is chosen from the 1-norm of `A`; beyond the largest degree
+/// `A` is scaled by `2^-s` and the result squared `s` times.
+pub fn expm<M>(matrix: &M) -> Result<M, DecompError>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    let n: usize = square(matrix)?;
+    let a: &[M::Scalar] = matrix.as_slice();
+    finite(a, n)?;
+    let mut norm: f64 = 0.0;
+    for column in 0..n {
+        let mut sum: f64 = 0.0;
+        for row in 0..n {
+            sum += a[row * n + column].modulus().to_f64();
+            // The number of squarings would saturate and never finish.
+            if !sum.is_finite() {
+                return Err(DecompError::NotFinite { row, column })
+            }
+        }
+        norm = norm.max(sum);
+    }
+
+    let mut exponential: Vec<M::Scalar> = match THETA.iter().find(|(_, theta)| norm <= *theta) {
+        Some(&(degree, _)) => {
+            let coefficients: &[f64] = match degree {
+                3 => &PADE_3,
+                5 => &PADE_5,
+                7 => &PADE_7,
+                _ => &PADE_9
+            };
+            pade(a, n, coefficients)?
+        },
+        None => {
+            let squarings: i32 = if norm > THETA_13 { (norm / THETA_13).log2().ceil() as i32 } else { 0 };
+            let factor = <M::Scalar as ComplexField>::Real::from_f64(0.5f64.powi(squarings));
+            let scaled: Vec<M::Scalar> = a.iter().map(|val| val.scale(factor)).collect();
+            let mut out: Vec<M::Scalar> = pade_13(&scaled, n)?;
+            for _ in 0..squarings {
+                out = product(&out, &out, n);
+            }
+            out
+        }
+    };
+
+    let mut out: M = matrix.clone();
+    out.as_mut_slice().swap_with_slice(&mut exponential);
+    Ok(out)
+}
+
+/// The action `e^{τH}ψ` of the exponential of a Hermitian operator on a vector.
+///
+/// `H` is never formed: it is only applied through `LinearOperator` and
+/// everything else is done with `VAXPYMut`, `DotV` and `VScaleMut`. Each step
+/// builds a Lanczos basis of at most `krylov` vectors and exponentiates the
+/// projected tridiagonal matrix; steps are halved until the a-posteriori error
+/// estimate is below working precision, and doubled again after each accepted
+/// step. For unitary evolution pass `τ = -it`.
+///
+/// Panics if `krylov` is less than two, as a single basis vector leaves the
+/// error estimate independent of the step.
+pub fn expm_multiply<S, V, T, A>(space: &S, operator: &A, psi: &V, tau: T, krylov: usize) -> Result<V, DecompError>
+where
+    S: VectorSpace<Vector=V> + VAXPYMut<Vector=V, Scalar=T> + DotV<Vector=V, Scalar=T> + VScaleMut<Vector=V, Scalar=T>,
+    T: ComplexField,
+    V: Clone + FromIterator<T> + StridedMut<Scalar=T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>,
+    A: LinearOperator<Domain=S, Codomain=S>
+{
+    assert!(krylov >= 2, "expm_multiply needs a Krylov dimension of at least 2, found {}", krylov);
+    let zero = T::Real::additive_identity();
+    let one = T::Real::multiplicative_identity();
+    let two = one + one;
+    let tol = T::Real::epsilon() * T::Real::from_f64(1024.0);
+
+    let mut state: V = psi.clone();
+    let mut remaining = one;
+    let mut step = one;
+    while remaining > zero {
+        let norm = inner(space, &state, &state).real().sqrt();
+        if norm == zero {
+            break;
+        }
+        let (basis, diagonal, off_diagonal, residual) = lanczos(space, operator, &state, norm, krylov);
+
+        step = step.min(remaining);
+        let mut halvings: usize = 0;
+        let coefficients: Vec<T> = loop {
+            let coefficients = exponentiate(&diagonal, &off_diagonal, tau.scale(step))?;
+            let error = norm * residual * coefficients[coefficients.len() - 1].modulus();
+            if error <= tol * norm {
+                break coefficients;
+            }
+            step /= two;
+            halvings += 1;
+            // A step lost in the rounding of `remaining` would never finish.
+            if halvings == MAX_HALVINGS || remaining - step == remaining {
+                return Err(DecompError::NoConvergence { iterations: halvings })
+            }
+        };
+
+        let mut next: V = basis[0].clone();
+        space.vscale_mut(&mut next, &coefficients[0].scale(norm));
+        for (q, c) in basis.iter().zip(coefficients).skip(1) {
+            space.vaxpy_mut(&c.scale(norm), q, &mut next);
+        }
+        state = next;
+        remaining -= step;
+        step *= two;
+    }
+    Ok(state)
+}
+
+/// The Padé approximant `e^A ≈ (V - U)⁻¹(V + U)`, where `U` collects the odd and
+/// `V` the even powers of `A`.
+fn pade<T>(a: &[T], n: usize, coefficients: &[f64]) -> Result<Vec<T>, DecompError>
+where
+    T: ComplexField
+{
+    // Even powers I, A², A⁴, … up to the degree of the approximant.
+    let mut unit: Vec<T> = vec![T::additive_identity(); n * n];
+    identity(&mut unit, n);
+    let squared: Vec<T> = product(a, a, n);
+    let mut powers: Vec<Vec<T>> = vec![unit];
+    while 2 * powers.len() < coefficients.len() {
+        let next: Vec<T> = product(&powers[powers.len() - 1], &squared, n);
+        powers.push(next);
+    }
+
+    let odd: Vec<(f64, &[T])> = powers.iter().enumerate().map(|(k, p)| (coefficients[2 * k + 1], &p[..])).collect();
+    let even: Vec<(f64, &[T])> = powers.iter().enumerate().map(|(k, p)| (coefficients[2 * k], &p[..])).collect();
+    let u: Vec<T> = product(a, &combine(&odd, n), n);
+    let v: Vec<T> = combine(&even, n);
+    quotient(u, v, n)
+}
+
+/// The degree 13 approximant, evaluated with six matrix products.
+fn pade_13<T>(a: &[T], n: usize) -> Result<Vec<T>, DecompError>
+where
+    T: ComplexField
+{
+    let b = &PADE_13;
+    let mut unit: Vec<T> = vec![T::additive_identity(); n * n];
+    identity(&mut unit, n);
+    let a2: Vec<T> = product(a, a, n);
+    let a4: Vec<T> = product(&a2, &a2, n);
+    let a6: Vec<T> = product(&a4, &a2, n);
+
+    let u_high: Vec<T> = product(&a6, &combine(&[(b[13], &a6), (b[11], &a4), (b[9], &a2)], n), n);
+    let u_low: Vec<T> = combine(&[(b[7], &a6), (b[5], &a4), (b[3], &a2), (b[1], &unit)], n);
+    let u: Vec<T> = product(a, &combine(&[(1.0, &u_high), (1.0, &u_low)], n), n);
+
+    let v_high: Vec<T> = product(&a6, &combine(&[(b[12], &a6), (b[10], &a4), (b[8], &a2)], n), n);
+    let v_low: Vec<T> = combine(&[(b[6], &a6), (b[4], &a4), (b[2], &a2), (b[0], &unit)], n);
+    let v: Vec<T> = combine(&[(1.0, &v_high), (1.0, &v_low)], n);
+    quotient(u, v, n)
+}
+
+/// Solves `(V - U)X = V + U` column by column.
+fn quotient<T>(u: Vec<T>, v: Vec<T>, n: usize) -> Result<Vec<T>, DecompError>
+where
+    T: ComplexField
+{
+    let denominator: Vec<T> = v.iter().zip(&u).map(|(&l, &r)| l - r).collect();
+    let numerator: Vec<T> = v.iter().zip(&u).map(|(&l, &r)| l + r).collect();
+    let factors = lu(&Buffer::new(n, n, denominator))?;
+
+    let mut out: Vec<T> = vec![T::additive_identity(); n * n];
+    for j in 0..n {
+        let column: Vec<T> = (0..n).map(|i| numerator[i * n + j]).collect();
+        let solution: Vec<T> = factors.solve(&column)?;
+        for (i, val) in solution.into_iter().enumerate() {
+            out[i * n + j] = val;
+        }
+    }
+    Ok(out)
+}
+
+/// The product of two square row-major buffers.
+fn product<T>(lhs: &[T], rhs: &[T], n: usize) -> Vec<T>
+where
+    T: ComplexField
+{
+    let mut out: Vec<T> = vec![T::additive_identity(); n * n];
+    for i in 0..n {
+        for k in 0..n {
+            let factor: T = lhs[i * n + k];
+            for j in 0..n {
+                out[i * n + j] += factor * rhs[k * n + j];
+            }
+        }
+    }
+    out
+}
+
+/// The linear combination `Σ c_k X_k` of square row-major buffers.
+fn combine<T>(terms: &[(f64, &[T])], n: usize) -> Vec<T>
+where
+    T: ComplexField
+{
+    let mut out: Vec<T> = vec![T::additive_identity(); n * n];
+    for (coefficient, matrix) in terms {
+        let coefficient = T::Real::from_f64(*coefficient);
+        for (acc, val) in out.iter_mut().zip(matrix.iter()) {
+            *acc += val.scale(coefficient);
+        }
+    }
+    out
+}
+
+/// An orthonormal Krylov basis `q_0 = ψ/‖ψ‖, …` with the tridiagonal projection of `H`
+/// and the norm of the residual left after the last vector.
+#[allow(clippy::type_complexity)]
+fn lanczos<S, V, T, A>(space: &S, operator: &A, psi: &V, norm: T::Real, krylov: usize) -> (Vec<V>, Vec<T::Real>, Vec<T::Real>, T::Real)
+where
+    S: VectorSpace<Vector=V> + VAXPYMut<Vector=V, Scalar=T> + DotV<Vector=V, Scalar=T> + VScaleMut<Vector=V, Scalar=T>,
+    T: ComplexField,
+    V: Clone + FromIterator<T> + StridedMut<Scalar=T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>,
+    A: LinearOperator<Domain=S, Codomain=S>
+{
+    let zero = T::Real::additive_identity();
+    let one = T::Real::multiplicative_identity();
+
+    let mut first: V = psi.clone();
+    space.vscale_mut(&mut first, &T::from_real(one / norm));
+    let mut basis: Vec<V> = vec![first];
+    let mut diagonal: Vec<T::Real> = Vec::with_capacity(krylov);
+    let mut off_diagonal: Vec<T::Real> = Vec::with_capacity(krylov);
+
+    loop {
+        let j: usize = basis.len() - 1;
+        let mut w: V = operator.apply(&basis[j]);
+        let alpha = inner(space, &basis[j], &w).real();
+        diagonal.push(alpha);
+
+        space.vaxpy_mut(&-T::from_real(alpha), &basis[j], &mut w);
+        if j > 0 {
+            space.vaxpy_mut(&-T::from_real(off_diagonal[j - 1]), &basis[j - 1], &mut w);
+        }
+        // Full reorthogonalisation keeps the basis orthonormal in finite precision.
+        for q in basis.iter() {
+            let projection: T = inner(space, q, &w);
+            space.vaxpy_mut(&-projection, q, &mut w);
+        }
+
+        // The basis is normalised, so breakdown is relative to the projection
+        // of the operator, never to the norm of ψ.
+        let beta = inner(space, &w, &w).real().sqrt();
+        let scale = off_diagonal.last().map_or(alpha.abs(), |&previous| previous.max(alpha.abs()));
+        if basis.len() == krylov || beta <= T::Real::epsilon() * scale {
+            let residual = if basis.len() == krylov { beta } else { zero };
+            return (basis, diagonal, off_diagonal, residual)
+        }
+        off_diagonal.push(beta);
+        space.vscale_mut(&mut w, &T::from_real(one / beta));
+        basis.push(w);
+    }
+}
+
+/// The first column of `e^{τT}` for a real symmetric tridiagonal `T`.
+fn exponentiate<R, T>(diagonal: &[R], off_diagonal: &[R], tau: T) -> Result<Vec<T>, DecompError>
+where
+    R: RealField,
+    T: ComplexField<Real=R>
+{
+    let m: usize = diagonal.len();
+    let mut entries: Vec<R> = vec![R::additive_identity(); m * m];
+    for (i, &val) in diagonal.iter().enumerate() {
+        entries[i * m + i] = val;
+    }
+    for (i, &val) in off_diagonal.iter().enumerate() {
+        entries[i * m + i + 1] = val;
+        entries[(i + 1) * m + i] = val;
+    }
+
+    let (values, vectors): (Vec<R>, Vec<Vec<R>>) = eigh(&Buffer::new(m, m, entries))?.into_parts();
+    Ok((0..m)
+        .map(|j| {
+            values.iter()
+                .zip(&vectors)
+                .map(|(&lambda, v)| (tau.scale(lambda)).exp().scale(v[j] * v[0]))
+                .fold(T::additive_identity(), |acc, val| acc + val)
+        })
+        .collect())
+}
@@ -0,0 +1,171 @@
+use std::iter::FromIterator;
+
+use algebra::{AdditiveIdentity, ComplexField, MultiplicativeIdentity, RealField};
+
+use crate::Dense;
+use super::{back_substitute, finite, gather, identity, square, tolerance, DecompError};
+
+/// The factorisation `PA = LU` with a unit lower-triangular `L`.
+///
+/// Both factors share the storage of one matrix: `L` below the diagonal and
+/// `U` on and above it.
+#[derive(Clone, Debug)]
+pub struct LU<M>
+{
+    factors: M,
+    permutation: Vec<usize>,
+    odd: bool
+}
+
+/// Factorises a square matrix with partial (row) pivoting.
+pub fn lu<M>(matrix: &M) -> Result<LU<M>, DecompError>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    let n: usize = square(matrix)?;
+    finite(matrix.as_slice(), n)?;
+    let mut factors: M = matrix.clone();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    let mut odd: bool = false;
+
+    let a: &mut [M::Scalar] = factors.as_mut_slice();
+    let tol = tolerance(a, n);
+
+    for k in 0..n {
+        // Finite entries can still overflow during elimination.
+        if let Some(row) = (k..n).find(|&i| !a[i * n + k].modulus().is_finite()) {
+            return Err(DecompError::NotFinite { row, column: k })
+        }
+        let pivot: usize = (k..n)
+            .max_by(|&i, &j| {
+                a[i * n + k].modulus()
+                    .partial_cmp(&a[j * n + k].modulus())
+                    .expect("finite moduli are comparable")
+            })
+            .unwrap_or(k);
+
+        if a[pivot * n + k].modulus() <= tol {
+            return Err(DecompError::Singular { pivot: k })
+        }
+        if pivot != k {
+            for j in 0..n {
+                a.swap(k * n + j, pivot * n + j);
+            }
+            permutation.swap(k, pivot);
+            odd = !odd;
+        }
+
+        let diagonal: M::Scalar = a[k * n + k];
+        for i in k + 1..n {
+            let factor: M::Scalar = a[i * n + k] / diagonal;
+            a[i * n + k] = factor;
+            for j in k + 1..n {
+                let update: M::Scalar = factor * a[k * n + j];
+                a[i * n + j] -= update;
+            }
+        }
+    }
+    Ok(LU { factors, permutation, odd })
+}
+
+impl<M> LU<M>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    /// The unit lower-triangular factor.
+    pub fn l(&self) -> M
+    {
+        let n: usize = self.factors.rows();
+        let mut lower: M = self.factors.clone();
+        for (idx, val) in lower.as_mut_slice().iter_mut().enumerate() {
+            let (i, j) = (idx / n, idx % n);
+            if i == j {
+                *val = M::Scalar::multiplicative_identity();
+            } else if j > i {
+                *val = M::Scalar::additive_identity();
+            }
+        }
+        lower
+    }
+
+    /// The upper-triangular factor.
+    pub fn u(&self) -> M
+    {
+        let n: usize = self.factors.rows();
+        let mut upper: M = self.factors.clone();
+        for (idx, val) in upper.as_mut_slice().iter_mut().enumerate() {
+            if idx % n < idx / n {
+                *val = M::Scalar::additive_identity();
+            }
+        }
+        upper
+    }
+
+    /// Row `i` of `PA` is row `permutation()[i]` of `A`.
+    pub fn permutation(&self) -> &[usize]
+    {
+        &self.permutation
+    }
+
+    pub fn det(&self) -> M::Scalar
+    {
+        let n: usize = self.factors.rows();
+        let a: &[M::Scalar] = self.factors.as_slice();
+        let product: M::Scalar = (0..n)
+            .map(|i| a[i * n + i])
+            .fold(M::Scalar::multiplicative_identity(), |acc, val| acc * val);
+        if self.odd { -product } else { product }
+    }
+
+    pub fn solve<V>(&self, b: &V) -> Result<V, DecompError>
+    where
+        V: FromIterator<M::Scalar>,
+        for<'a> &'a V: IntoIterator<Item=&'a M::Scalar>
+    {
+        let n: usize = self.factors.rows();
+        let rhs: Vec<M::Scalar> = gather(b, n)?;
+        let mut x: Vec<M::Scalar> = self.permutation
+            .iter()
+            .map(|&i| rhs[i])
+            .collect();
+        self.solve_in_place(&mut x);
+        Ok(x.into_iter().collect())
+    }
+
+    pub fn inverse(&self) -> M
+    {
+        let n: usize = self.factors.rows();
+        let mut inverse: M = self.factors.clone();
+        let mut unit: Vec<M::Scalar> = vec![M::Scalar::additive_identity(); n * n];
+        identity(&mut unit, n);
+
+        let out: &mut [M::Scalar] = inverse.as_mut_slice();
+        for j in 0..n {
+            let mut column: Vec<M::Scalar> = self.permutation
+                .iter()
+                .map(|&i| unit[i * n + j])
+                .collect();
+            self.solve_in_place(&mut column);
+            for (i, val) in column.into_iter().enumerate() {
+                out[i * n + j] = val;
+            }
+        }
+        inverse
+    }
+
+    /// Solves `LUx = y` for an already permuted right-hand side.
+    fn solve_in_place(&self, x: &mut [M::Scalar])
+    {
+        let n: usize = self.factors.rows();
+        let a: &[M::Scalar] = self.factors.as_slice();
+        for i in 0..n {
+            for j in 0..i {
+                let update: M::Scalar = a[i * n + j] * x[j];
+                x[i] -= update;
+            }
+        }
+        back_substitute(a, n, x);
+    }
+}
@@ -0,0 +1,283 @@
+//! Dense matrix decompositions and matrix functions.
+//!
+//! The kernels work on any matrix implementing `Dense`, such as those
+//! generated by `mspace!`, and are generic over the `ComplexField` scalars in
+//! `algebra`. Factors are returned in the matrix type that was decomposed and
+//! right-hand sides are any vector that can be iterated and collected, such as
+//! those generated by `vspace!`.
+//!
+//! The exact kernels in `rref` instead take a matrix as a slice of rows over an
+//! exact `Field` such as `Rational`, and never compare against a tolerance.
+
+use std::{fmt, error, iter::FromIterator};
+
+use algebra::{AdditiveIdentity, ComplexField, DotV, MultiplicativeIdentity, RealField, Strided};
+
+use crate::Dense;
+
+mod lu;
+pub use lu::*;
+
+mod qr;
+pub use qr::*;
+
+mod cholesky;
+pub use cholesky::*;
+
+mod eigen;
+pub use eigen::*;
+
+mod svd;
+pub use svd::*;
+
+mod expm;
+pub use expm::*;
+
+mod rref;
+pub use rref::*;
+
+/// The number of sweeps the Jacobi methods perform before giving up.
+pub(crate) const MAX_SWEEPS: usize = 100;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DecompError
+{
+    /// The decomposition is only defined for square matrices.
+    NotSquare { rows: usize, columns: usize },
+
+    /// A pivot vanished, so the matrix has no inverse.
+    Singular { pivot: usize },
+
+    /// A diagonal entry was not positive during a Cholesky factorisation.
+    NotPositiveDefinite { pivot: usize },
+
+    /// A right-hand side does not match the dimension of the matrix.
+    DimensionMismatch { expected: usize, found: usize },
+
+    /// The entries at `(row, column)` and `(column, row)` are not conjugates.
+    NotHermitian { row: usize, column: usize },
+
+    /// An iterative method did not converge within its iteration budget.
+    NoConvergence { iterations: usize },
+
+    /// The entry at `(row, column)` is infinite or NaN.
+    NotFinite { row: usize, column: usize }
+}
+
+impl fmt::Display for DecompError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            DecompError::NotSquare { rows, columns } => {
+                write!(f, "expected a square matrix, found {} rows and {} columns", rows, columns)
+            },
+            DecompError::Singular { pivot } => write!(f, "matrix is singular at pivot {}", pivot),
+            DecompError::NotPositiveDefinite { pivot } => {
+                write!(f, "matrix is not positive definite at pivot {}", pivot)
+            },
+            DecompError::DimensionMismatch { expected, found } => {
+                write!(f, "expected a vector of dimension {}, found {}", expected, found)
+            },
+            DecompError::NotHermitian { row, column } => {
+                write!(f, "matrix is not Hermitian at ({}, {})", row, column)
+            },
+            DecompError::NoConvergence { iterations } => {
+                write!(f, "no convergence after {} iterations", iterations)
+            },
+            DecompError::NotFinite { row, column } => {
+                write!(f, "matrix entry ({}, {}) is not finite", row, column)
+            }
+        }
+    }
+}
+
+impl error::Error for DecompError { }
+
+/// Solves `Ax = b` through an LU factorisation with partial pivoting.
+pub fn solve<M, V>(matrix: &M, b: &V) -> Result<V, DecompError>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField,
+    V: FromIterator<M::Scalar>,
+    for<'a> &'a V: IntoIterator<Item=&'a M::Scalar>
+{
+    lu(matrix)?.solve(b)
+}
+
+/// The determinant, which is zero whenever the LU factorisation finds a vanishing pivot.
+pub fn det<M>(matrix: &M) -> Result<M::Scalar, DecompError>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    match lu(matrix) {
+        Ok(factors) => Ok(factors.det()),
+        Err(DecompError::Singular { .. }) => Ok(M::Scalar::additive_identity()),
+        Err(err) => Err(err)
+    }
+}
+
+/// The inverse through an LU factorisation with partial pivoting.
+pub fn inverse<M>(matrix: &M) -> Result<M, DecompError>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    Ok(lu(matrix)?.inverse())
+}
+
+/// A row-major scratch matrix for kernels that decompose intermediate results.
+#[derive(Clone, Debug)]
+pub(crate) struct Buffer<T>
+{
+    rows: usize,
+    columns: usize,
+    entries: Vec<T>
+}
+
+impl<T> Buffer<T>
+{
+    pub(crate) fn new(rows: usize, columns: usize, entries: Vec<T>) -> Self
+    {
+        assert!(entries.len() == rows * columns);
+        Buffer { rows, columns, entries }
+    }
+}
+
+impl<T> Dense for Buffer<T>
+{
+    type Scalar = T;
+
+    fn rows(&self) -> usize
+    {
+        self.rows
+    }
+
+    fn columns(&self) -> usize
+    {
+        self.columns
+    }
+
+    fn as_slice(&self) -> &[T]
+    {
+        &self.entries
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T]
+    {
+        &mut self.entries
+    }
+}
+
+/// The Hermitian inner product `x*y`.
+pub(crate) fn inner<S, V, T>(space: &S, x: &V, y: &V) -> T
+where
+    S: DotV<Vector=V, Scalar=T>,
+    T: ComplexField,
+    V: FromIterator<T> + Strided<Scalar=T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    let conjugate: V = x.into_iter().map(|val| val.conjugate()).collect();
+    space.dotv(&conjugate, y)
+}
+
+/// The dimension of a square matrix.
+pub(crate) fn square<M>(matrix: &M) -> Result<usize, DecompError>
+where
+    M: Dense
+{
+    match (matrix.rows(), matrix.columns()) {
+        (rows, columns) if rows == columns => Ok(rows),
+        (rows, columns) => Err(DecompError::NotSquare { rows, columns })
+    }
+}
+
+/// Checks that every entry of a row-major buffer with `columns` columns is finite.
+pub(crate) fn finite<T>(entries: &[T], columns: usize) -> Result<(), DecompError>
+where
+    T: ComplexField
+{
+    match entries.iter().position(|val| !val.real().is_finite() || !val.imaginary().is_finite()) {
+        Some(idx) => Err(DecompError::NotFinite { row: idx / columns, column: idx % columns }),
+        None => Ok(())
+    }
+}
+
+/// Copies a right-hand side into a buffer, checking its dimension.
+pub(crate) fn gather<V, T>(b: &V, dimension: usize) -> Result<Vec<T>, DecompError>
+where
+    T: Copy,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    let buf: Vec<T> = b.into_iter().copied().collect();
+    match buf.len() {
+        found if found == dimension => Ok(buf),
+        found => Err(DecompError::DimensionMismatch { expected: dimension, found })
+    }
+}
+
+/// The threshold below which a pivot is treated as zero.
+pub(crate) fn tolerance<T>(entries: &[T], dimension: usize) -> T::Real
+where
+    T: ComplexField
+{
+    let scale: T::Real = entries
+        .iter()
+        .map(|val| val.modulus())
+        .fold(T::Real::additive_identity(), |acc, val| acc.max(val));
+    scale * T::Real::epsilon() * T::Real::from_f64(dimension as f64)
+}
+
+/// Overwrites a square row-major buffer with the identity.
+pub(crate) fn identity<T>(entries: &mut [T], dimension: usize)
+where
+    T: ComplexField
+{
+    for (idx, val) in entries.iter_mut().enumerate() {
+        *val = if idx / dimension == idx % dimension {
+            T::multiplicative_identity()
+        } else {
+            T::additive_identity()
+        };
+    }
+}
+
+/// Solves `Ux = b` in place for an upper-triangular row-major `U`.
+pub(crate) fn back_substitute<T>(upper: &[T], n: usize, x: &mut [T])
+where
+    T: ComplexField
+{
+    for i in (0..n).rev() {
+        let mut acc: T = x[i];
+        for j in i + 1..n {
+            acc -= upper[i * n + j] * x[j];
+        }
+        x[i] = acc / upper[i * n + i];
+    }
+}
+
+/// The Jacobi rotation `J` that diagonalises `J*AJ` for the Hermitian block
+/// `A = [[app, apq], [apq*, aqq]]`, returned as `(c, s e^{iφ}, s e^{-iφ})` where
+/// `J_pp = J_qq = c`, `J_pq = s e^{iφ}` and `J_qp = -s e^{-iφ}`.
+///
+/// `apq` must be non-zero.
+pub(crate) fn rotation<T>(app: T::Real, aqq: T::Real, apq: T) -> (T, T, T)
+where
+    T: ComplexField
+{
+    let one = T::Real::multiplicative_identity();
+    let two = one + one;
+
+    // apq = |apq| e^{iφ}
+    let modulus = apq.modulus();
+    let phase: T = apq.scale(one / modulus);
+    let theta = (aqq - app) / (two * modulus);
+    let t = {
+        let magnitude = one / (theta.abs() + (theta * theta + one).sqrt());
+        if theta < T::Real::additive_identity() { -magnitude } else { magnitude }
+    };
+    let c = one / (t * t + one).sqrt();
+    let s = t * c;
+    (T::from_real(c), phase.scale(s), phase.conjugate().scale(s))
+}
@@ -0,0 +1,119 @@
+use std::iter::FromIterator;
+
+use algebra::{AdditiveIdentity, ComplexField, MultiplicativeIdentity, RealField};
+
+use crate::Dense;
+use super::{back_substitute, gather, identity, square, tolerance, DecompError};
+
+/// The factorisation `A = QR` with a unitary `Q` and an upper-triangular `R`.
+#[derive(Clone, Debug)]
+pub struct QR<M>
+{
+    q: M,
+    r: M
+}
+
+/// Factorises a square matrix with Householder reflections.
+pub fn qr<M>(matrix: &M) -> Result<QR<M>, DecompError>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    let n: usize = square(matrix)?;
+    let mut r: M = matrix.clone();
+    let mut q: M = matrix.clone();
+    identity(q.as_mut_slice(), n);
+
+    let zero = <M::Scalar as ComplexField>::Real::additive_identity();
+    let one = <M::Scalar as ComplexField>::Real::multiplicative_identity();
+    let two = <M::Scalar as ComplexField>::Real::from_f64(2.0);
+
+    for k in 0..n.saturating_sub(1) {
+        let a: &mut [M::Scalar] = r.as_mut_slice();
+
+        // Reflect `x = R[k.., k]` onto `alpha e_1`, choosing the phase of
+        // `alpha` opposite to `x_0` to avoid cancellation.
+        let mut v: Vec<M::Scalar> = (k..n).map(|i| a[i * n + k]).collect();
+        let norm = v.iter().map(|val| val.modulus_squared()).fold(zero, |acc, val| acc + val).sqrt();
+        if norm == zero {
+            continue;
+        }
+        let head = v[0].modulus();
+        let phase: M::Scalar = if head == zero {
+            M::Scalar::multiplicative_identity()
+        } else {
+            v[0].scale(one / head)
+        };
+        v[0] += phase.scale(norm);
+
+        let v_norm = v.iter().map(|val| val.modulus_squared()).fold(zero, |acc, val| acc + val).sqrt();
+        v.iter_mut().for_each(|val| *val = val.scale(one / v_norm));
+
+        // R <- (I - 2vv*) R
+        for j in k..n {
+            let s: M::Scalar = (k..n)
+                .map(|i| v[i - k].conjugate() * a[i * n + j])
+                .fold(M::Scalar::additive_identity(), |acc, val| acc + val);
+            for i in k..n {
+                a[i * n + j] -= (v[i - k] * s).scale(two);
+            }
+        }
+        for i in k + 1..n {
+            a[i * n + k] = M::Scalar::additive_identity();
+        }
+
+        // Q <- Q (I - 2vv*)
+        let b: &mut [M::Scalar] = q.as_mut_slice();
+        for row in 0..n {
+            let s: M::Scalar = (k..n)
+                .map(|i| b[row * n + i] * v[i - k])
+                .fold(M::Scalar::additive_identity(), |acc, val| acc + val);
+            for i in k..n {
+                b[row * n + i] -= (s * v[i - k].conjugate()).scale(two);
+            }
+        }
+    }
+    Ok(QR { q, r })
+}
+
+impl<M> QR<M>
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    pub fn q(&self) -> &M
+    {
+        &self.q
+    }
+
+    pub fn r(&self) -> &M
+    {
+        &self.r
+    }
+
+    /// Solves `Ax = b` as `Rx = Q*b`.
+    pub fn solve<V>(&self, b: &V) -> Result<V, DecompError>
+    where
+        V: FromIterator<M::Scalar>,
+        for<'a> &'a V: IntoIterator<Item=&'a M::Scalar>
+    {
+        let n: usize = self.r.rows();
+        let rhs: Vec<M::Scalar> = gather(b, n)?;
+        let (q, r) = (self.q.as_slice(), self.r.as_slice());
+
+        let tol = tolerance(r, n);
+        if let Some(pivot) = (0..n).find(|&i| r[i * n + i].modulus() <= tol) {
+            return Err(DecompError::Singular { pivot })
+        }
+
+        let mut x: Vec<M::Scalar> = (0..n)
+            .map(|j| {
+                (0..n)
+                    .map(|i| q[i * n + j].conjugate() * rhs[i])
+                    .fold(M::Scalar::additive_identity(), |acc, val| acc + val)
+            })
+            .collect();
+        back_substitute(r, n, &mut x);
+        Ok(x.into_iter().collect())
+    }
+}
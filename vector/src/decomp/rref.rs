@@ -0,0 +1,211 @@
+use std::iter::FromIterator;
+use std::ops::Div;
+
+use algebra::{Field, Ring};
+
+use super::DecompError;
+
+/// The reduced row echelon form of a matrix, computed without round-off.
+///
+/// The matrix is given by its rows, such as `vspace!` vectors, over an exact
+/// field like `Rational` or `Gf<P>`: a pivot is any non-zero entry, so no
+/// tolerance is involved.
+#[derive(Clone, Debug)]
+pub struct Rref<T>
+{
+    rows: usize,
+    columns: usize,
+    entries: Vec<T>,
+    pivots: Vec<usize>
+}
+
+/// Row-reduces the matrix with the given rows by Gauss-Jordan elimination.
+pub fn rref<V, T>(rows: &[V]) -> Result<Rref<T>, DecompError>
+where
+    T: Field + PartialEq,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    let (columns, entries): (usize, Vec<T>) = flatten(rows)?;
+    Ok(reduce(rows.len(), columns, entries, columns))
+}
+
+/// A basis of the nullspace `{x : Ax = 0}` of the matrix with the given rows.
+pub fn nullspace<V, T, W>(rows: &[V]) -> Result<Vec<W>, DecompError>
+where
+    T: Field + PartialEq,
+    W: FromIterator<T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    Ok(rref(rows)?.nullspace())
+}
+
+/// The exact inverse of a square matrix given by its rows, by row-reducing
+/// `[A | I]`.
+pub fn exact_inverse<V, T>(rows: &[V]) -> Result<Vec<V>, DecompError>
+where
+    T: Field + PartialEq,
+    V: FromIterator<T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    let (n, entries): (usize, Vec<T>) = flatten(rows)?;
+    if n != rows.len() {
+        return Err(DecompError::NotSquare { rows: rows.len(), columns: n })
+    }
+    let mut augmented: Vec<T> = Vec::with_capacity(2 * n * n);
+    for (i, row) in entries.chunks(n.max(1)).take(n).enumerate() {
+        augmented.extend(row.iter().cloned());
+        augmented.extend((0..n).map(|j| if i == j { T::multiplicative_identity() } else { T::additive_identity() }));
+    }
+
+    let reduced: Rref<T> = reduce(n, 2 * n, augmented, n);
+    if let Some(pivot) = (0..n).find(|&k| reduced.pivots.get(k) != Some(&k)) {
+        return Err(DecompError::Singular { pivot })
+    }
+    Ok(reduced.entries
+        .chunks(2 * n)
+        .map(|row| row[n..].iter().cloned().collect())
+        .collect())
+}
+
+/// The determinant by fraction-free (Bareiss) elimination.
+///
+/// Every division is exact, so this also works over rings with an exact
+/// quotient such as the integers, where the entries stay integral and grow
+/// no larger than the minors of the matrix.
+pub fn exact_det<V, T>(rows: &[V]) -> Result<T, DecompError>
+where
+    T: Ring + Div<Output=T> + PartialEq,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    let (n, mut a): (usize, Vec<T>) = flatten(rows)?;
+    if n != rows.len() {
+        return Err(DecompError::NotSquare { rows: rows.len(), columns: n })
+    }
+    let zero: T = T::additive_identity();
+    let mut previous: T = T::multiplicative_identity();
+    let mut negate: bool = false;
+    for k in 0..n {
+        if a[k * n + k] == zero {
+            match (k + 1..n).find(|&i| a[i * n + k] != zero) {
+                Some(pivot) => {
+                    for j in 0..n {
+                        a.swap(k * n + j, pivot * n + j);
+                    }
+                    negate = !negate;
+                },
+                None => return Ok(zero)
+            }
+        }
+        for i in k + 1..n {
+            for j in k + 1..n {
+                let minor: T = a[k * n + k].clone() * a[i * n + j].clone() - a[i * n + k].clone() * a[k * n + j].clone();
+                a[i * n + j] = minor / previous.clone();
+            }
+        }
+        previous = a[k * n + k].clone();
+    }
+    Ok(if negate { -previous } else { previous })
+}
+
+impl<T> Rref<T>
+where
+    T: Field + PartialEq
+{
+    pub fn rank(&self) -> usize
+    {
+        self.pivots.len()
+    }
+
+    /// The column of the leading one in each of the first `rank` rows.
+    pub fn pivots(&self) -> &[usize]
+    {
+        &self.pivots
+    }
+
+    /// The reduced rows, with the zero rows last.
+    pub fn rows<W>(&self) -> Vec<W>
+    where
+        W: FromIterator<T>
+    {
+        (0..self.rows)
+            .map(|i| self.entries[i * self.columns..(i + 1) * self.columns].iter().cloned().collect())
+            .collect()
+    }
+
+    /// A basis of the nullspace, one vector for each column without a pivot.
+    pub fn nullspace<W>(&self) -> Vec<W>
+    where
+        W: FromIterator<T>
+    {
+        (0..self.columns)
+            .filter(|column| !self.pivots.contains(column))
+            .map(|free| {
+                let mut x: Vec<T> = vec![T::additive_identity(); self.columns];
+                x[free] = T::multiplicative_identity();
+                for (i, &pivot) in self.pivots.iter().enumerate() {
+                    x[pivot] = -self.entries[i * self.columns + free].clone();
+                }
+                x.into_iter().collect()
+            })
+            .collect()
+    }
+}
+
+/// Copies the rows into a row-major buffer, checking they agree in length.
+fn flatten<V, T>(rows: &[V]) -> Result<(usize, Vec<T>), DecompError>
+where
+    T: Clone,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    let mut entries: Vec<T> = Vec::new();
+    let mut columns: Option<usize> = None;
+    for row in rows {
+        let before: usize = entries.len();
+        entries.extend(row.into_iter().cloned());
+        let found: usize = entries.len() - before;
+        match columns {
+            Some(expected) if expected != found => return Err(DecompError::DimensionMismatch { expected, found }),
+            _ => columns = Some(found)
+        }
+    }
+    Ok((columns.unwrap_or(0), entries))
+}
+
+/// Gauss-Jordan elimination, pivoting only in the first `limit` columns.
+fn reduce<T>(rows: usize, columns: usize, mut entries: Vec<T>, limit: usize) -> Rref<T>
+where
+    T: Field + PartialEq
+{
+    let zero: T = T::additive_identity();
+    let mut pivots: Vec<usize> = Vec::new();
+    for column in 0..limit {
+        let rank: usize = pivots.len();
+        let pivot: usize = match (rank..rows).find(|&i| entries[i * columns + column] != zero) {
+            Some(pivot) => pivot,
+            None => continue
+        };
+        for j in 0..columns {
+            entries.swap(rank * columns + j, pivot * columns + j);
+        }
+
+        let scale: T = T::multiplicative_identity() / entries[rank * columns + column].clone();
+        for j in column..columns {
+            entries[rank * columns + j] *= &scale;
+        }
+        for i in (0..rows).filter(|&i| i != rank) {
+            let factor: T = entries[i * columns + column].clone();
+            if factor == zero {
+                continue
+            }
+            for j in column..columns {
+                let update: T = factor.clone() * entries[rank * columns + j].clone();
+                entries[i * columns + j] -= update;
+            }
+        }
+        pivots.push(column);
+        if pivots.len() == rows {
+            break
+        }
+    }
+    Rref { rows, columns, entries, pivots }
+}
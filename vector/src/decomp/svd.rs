@@ -0,0 +1,300 @@
+use std::iter::FromIterator;
+
+use algebra::{AdditiveIdentity, ComplexField, MultiplicativeIdentity, RealField};
+
+use crate::Dense;
+use super::{finite, rotation, DecompError, MAX_SWEEPS};
+
+/// The singular value decomposition `A = UΣV*` of an `m × n` matrix.
+///
+/// Singular values are sorted descending. `left()[k]` and `right()[k]` are the
+/// unit singular vectors of `values()[k]`; any left or right vectors beyond the
+/// `min(m, n)` singular values complete an orthonormal basis.
+#[derive(Clone, Debug)]
+pub struct SVD<U, V, R>
+{
+    values: Vec<R>,
+    left: Vec<U>,
+    right: Vec<V>
+}
+
+impl<U, V, R> SVD<U, V, R>
+where
+    R: RealField
+{
+    pub fn values(&self) -> &[R]
+    {
+        &self.values
+    }
+
+    /// The columns of `U`, each of dimension `m`.
+    pub fn left(&self) -> &[U]
+    {
+        &self.left
+    }
+
+    /// The columns of `V`, each of dimension `n`.
+    pub fn right(&self) -> &[V]
+    {
+        &self.right
+    }
+
+    pub fn into_parts(self) -> (Vec<R>, Vec<U>, Vec<V>)
+    {
+        (self.values, self.left, self.right)
+    }
+
+    /// The number of singular values above `tol`.
+    pub fn rank(&self, tol: R) -> usize
+    {
+        self.values.iter().filter(|&&val| val > tol).count()
+    }
+
+    /// The 2-norm condition number `σ_max / σ_min`, which is infinite for a rank-deficient matrix.
+    pub fn cond(&self) -> R
+    {
+        match (self.values.first(), self.values.last()) {
+            (Some(&max), Some(&min)) if min > R::additive_identity() => max / min,
+            _ => R::from_f64(f64::INFINITY)
+        }
+    }
+
+    /// The Moore–Penrose pseudo-inverse `VΣ⁺U*` as a row-major `n × m` matrix,
+    /// discarding singular values at or below `tol`.
+    pub fn pinv<T, P>(&self, tol: R) -> P
+    where
+        T: ComplexField<Real=R>,
+        P: FromIterator<T>,
+        for<'a> &'a U: IntoIterator<Item=&'a T>,
+        for<'a> &'a V: IntoIterator<Item=&'a T>
+    {
+        let kept: Vec<(R, Vec<T>, Vec<T>)> = self.values
+            .iter()
+            .zip(self.left.iter().zip(self.right.iter()))
+            .filter(|(&val, _)| val > tol)
+            .map(|(&val, (u, v))| (val, u.into_iter().copied().collect(), v.into_iter().copied().collect()))
+            .collect();
+
+        let m: usize = self.left.first().map_or(0, |u| u.into_iter().count());
+        let n: usize = self.right.first().map_or(0, |v| v.into_iter().count());
+        (0..n * m)
+            .map(|idx| {
+                let (i, j) = (idx / m, idx % m);
+                kept.iter()
+                    .map(|(val, u, v)| (v[i] * u[j].conjugate()).scale(R::multiplicative_identity() / *val))
+                    .fold(T::additive_identity(), |acc, val| acc + val)
+            })
+            .collect()
+    }
+}
+
+/// The thin SVD with `min(m, n)` singular triplets, computed by one-sided Jacobi rotations.
+///
+/// Columns of the taller orientation of `A` are rotated pairwise until they are
+/// mutually orthogonal; their norms are then the singular values.
+pub fn svd<M, U, V>(matrix: &M) -> Result<SVD<U, V, <M::Scalar as ComplexField>::Real>, DecompError>
+where
+    M: Dense,
+    M::Scalar: ComplexField,
+    U: FromIterator<M::Scalar>,
+    V: FromIterator<M::Scalar>
+{
+    decompose(matrix, false)
+}
+
+/// The full SVD, with `m` left and `n` right singular vectors.
+pub fn svd_full<M, U, V>(matrix: &M) -> Result<SVD<U, V, <M::Scalar as ComplexField>::Real>, DecompError>
+where
+    M: Dense,
+    M::Scalar: ComplexField,
+    U: FromIterator<M::Scalar>,
+    V: FromIterator<M::Scalar>
+{
+    decompose(matrix, true)
+}
+
+/// The number of singular values above `tol`.
+pub fn rank<M>(matrix: &M, tol: <M::Scalar as ComplexField>::Real) -> Result<usize, DecompError>
+where
+    M: Dense,
+    M::Scalar: ComplexField
+{
+    Ok(svd::<M, Vec<M::Scalar>, Vec<M::Scalar>>(matrix)?.rank(tol))
+}
+
+/// The Moore–Penrose pseudo-inverse as a row-major `n × m` matrix.
+pub fn pinv<M, P>(matrix: &M, tol: <M::Scalar as ComplexField>::Real) -> Result<P, DecompError>
+where
+    M: Dense,
+    M::Scalar: ComplexField,
+    P: FromIterator<M::Scalar>
+{
+    Ok(svd::<M, Vec<M::Scalar>, Vec<M::Scalar>>(matrix)?.pinv(tol))
+}
+
+/// The 2-norm condition number `σ_max / σ_min`.
+pub fn cond<M>(matrix: &M) -> Result<<M::Scalar as ComplexField>::Real, DecompError>
+where
+    M: Dense,
+    M::Scalar: ComplexField
+{
+    Ok(svd::<M, Vec<M::Scalar>, Vec<M::Scalar>>(matrix)?.cond())
+}
+
+fn decompose<M, U, V>(matrix: &M, full: bool) -> Result<SVD<U, V, <M::Scalar as ComplexField>::Real>, DecompError>
+where
+    M: Dense,
+    M::Scalar: ComplexField,
+    U: FromIterator<M::Scalar>,
+    V: FromIterator<M::Scalar>
+{
+    let (m, n) = (matrix.rows(), matrix.columns());
+    let entries = matrix.as_slice();
+    finite(entries, n)?;
+
+    // A wide matrix is decomposed through its adjoint, A* = V Σ U*.
+    let (values, left, right) = if m >= n {
+        let columns = (0..n).map(|j| (0..m).map(|i| entries[i * n + j]).collect()).collect();
+        one_sided(columns, m, full)?
+    } else {
+        let columns = (0..m).map(|i| entries[i * n..(i + 1) * n].iter().map(|val| val.conjugate()).collect()).collect();
+        let (values, left, right) = one_sided(columns, n, full)?;
+        (values, right, left)
+    };
+
+    Ok(SVD {
+        values,
+        left: left.into_iter().map(|u| u.into_iter().collect()).collect(),
+        right: right.into_iter().map(|v| v.into_iter().collect()).collect()
+    })
+}
+
+/// Orthogonalises the `n` columns of a tall `m × n` matrix, returning the singular
+/// values with the left and right singular vectors as plain buffers.
+#[allow(clippy::type_complexity)]
+fn one_sided<T>(mut columns: Vec<Vec<T>>, m: usize, full: bool) -> Result<(Vec<T::Real>, Vec<Vec<T>>, Vec<Vec<T>>), DecompError>
+where
+    T: ComplexField
+{
+    let n: usize = columns.len();
+    let mut v: Vec<Vec<T>> = (0..n)
+        .map(|j| (0..n).map(|i| if i == j { T::multiplicative_identity() } else { T::additive_identity() }).collect())
+        .collect();
+
+    let zero = T::Real::additive_identity();
+    let eps = T::Real::epsilon();
+    // Columns below this squared norm are rounding noise and are left alone.
+    let negligible = columns.iter().map(|col| norm_squared(col)).fold(zero, |acc, val| acc + val) * eps * eps;
+
+    let mut converged: bool = false;
+    for _ in 0..MAX_SWEEPS {
+        let mut rotated: bool = false;
+        for p in 0..n {
+            for q in p + 1..n {
+                let alpha = norm_squared(&columns[p]);
+                let beta = norm_squared(&columns[q]);
+                let gamma = inner(&columns[p], &columns[q]);
+                if alpha <= negligible || beta <= negligible || gamma.modulus() <= eps * (alpha * beta).sqrt() {
+                    continue;
+                }
+                rotated = true;
+
+                // Rotating the columns diagonalises the Gram block [[α, γ], [γ*, β]].
+                let jacobi = rotation(alpha, beta, gamma);
+                rotate(&mut columns, p, q, jacobi);
+                rotate(&mut v, p, q, jacobi);
+            }
+        }
+        if !rotated {
+            converged = true;
+            break;
+        }
+    }
+    if !converged {
+        return Err(DecompError::NoConvergence { iterations: MAX_SWEEPS })
+    }
+
+    let norms: Vec<T::Real> = columns.iter().map(|col| norm_squared(col).sqrt()).collect();
+    let mut order: Vec<usize> = (0..n).collect();
+    order.sort_by(|&i, &j| norms[j].partial_cmp(&norms[i]).expect("singular values must be comparable"));
+
+    let largest = order.first().map_or(zero, |&i| norms[i]);
+    let tol = largest * eps * T::Real::from_f64(m as f64);
+
+    let values: Vec<T::Real> = order.iter().map(|&j| norms[j]).collect();
+    let right: Vec<Vec<T>> = order.iter().map(|&j| v[j].clone()).collect();
+    let mut left: Vec<Vec<T>> = order
+        .iter()
+        .filter(|&&j| norms[j] > tol)
+        .map(|&j| columns[j].iter().map(|val| val.scale(T::Real::multiplicative_identity() / norms[j])).collect())
+        .collect();
+    complete(&mut left, m, if full { m } else { n });
+
+    Ok((values, left, right))
+}
+
+/// Replaces columns `p` and `q` with `(x_p c - x_q s e^{-iφ}, x_p s e^{iφ} + x_q c)`.
+fn rotate<T>(columns: &mut [Vec<T>], p: usize, q: usize, (c, sp, sq): (T, T, T))
+where
+    T: ComplexField
+{
+    let (head, tail) = columns.split_at_mut(q);
+    for (xp, xq) in head[p].iter_mut().zip(tail[0].iter_mut()) {
+        let (lhs, rhs) = (*xp, *xq);
+        *xp = lhs * c - rhs * sq;
+        *xq = lhs * sp + rhs * c;
+    }
+}
+
+/// Extends an orthonormal set to `count` vectors by orthogonalising standard basis vectors.
+///
+/// A basis vector is accepted once its residual exceeds `1 / 2m`; some basis vector
+/// always has a residual of at least `1 / m` while the set is incomplete.
+fn complete<T>(basis: &mut Vec<Vec<T>>, m: usize, count: usize)
+where
+    T: ComplexField
+{
+    let threshold = T::Real::from_f64(0.5 / m as f64);
+    for e in 0..m {
+        if basis.len() >= count {
+            break;
+        }
+        let mut w: Vec<T> = (0..m)
+            .map(|i| if i == e { T::multiplicative_identity() } else { T::additive_identity() })
+            .collect();
+        // Two passes of Gram-Schmidt keep the result orthogonal to working precision.
+        for _ in 0..2 {
+            for b in basis.iter() {
+                let projection = inner(b, &w);
+                for (wi, bi) in w.iter_mut().zip(b) {
+                    *wi -= projection * *bi;
+                }
+            }
+        }
+        let norm = norm_squared(&w);
+        if norm > threshold {
+            let scale = T::Real::multiplicative_identity() / norm.sqrt();
+            basis.push(w.into_iter().map(|val| val.scale(scale)).collect());
+        }
+    }
+}
+
+/// The inner product `x*y`.
+fn inner<T>(x: &[T], y: &[T]) -> T
+where
+    T: ComplexField
+{
+    x.iter()
+        .zip(y)
+        .map(|(a, b)| a.conjugate() * *b)
+        .fold(T::additive_identity(), |acc, val| acc + val)
+}
+
+fn norm_squared<T>(x: &[T]) -> T::Real
+where
+    T: ComplexField
+{
+    x.iter()
+        .map(|val| val.modulus_squared())
+        .fold(T::Real::additive_identity(), |acc, val| acc + val)
+}
@@ -0,0 +1,262 @@
+//! Lazy vector expressions that are evaluated in a single fused loop.
+//!
+//! `lazy(&x)` borrows a vector as the leaf of an expression tree; `+`, `-`,
+//! unary `-` and multiplication by a scalar (or `scale`, for any scalar type)
+//! build larger trees without touching any entries. Assigning the tree into a
+//! destination evaluates every entry once, so `a * x + b * y - z` makes one
+//! pass and allocates nothing:
+//!
+//! ```
+//! use algebra::*;
+//! use vector::vspace;
+//! use vector::expr::{lazy, Expression};
+//!
+//! vspace! {
+//!     TripleSpace {
+//!         vector: Triple,
+//!         dimension: 3
+//!     }
+//! }
+//!
+//! let x: Triple<f64> = vec![1.0, 2.0, 3.0].into_iter().collect();
+//! let y: Triple<f64> = vec![0.0, 1.0, 0.0].into_iter().collect();
+//! let z: Triple<f64> = vec![1.0, 1.0, 1.0].into_iter().collect();
+//! let (a, b): (f64, f64) = (2.0, -4.0);
+//!
+//! let mut w: Triple<f64> = vec![0.0; 3].into_iter().collect();
+//! (a * lazy(&x) + b * lazy(&y) - lazy(&z)).assign(&mut w);
+//! assert_eq!(w.into_iter().copied().collect::<Vec<f64>>(), vec![1.0, -1.0, 5.0]);
+//! ```
+
+use std::iter::FromIterator;
+use std::ops::{Add, Mul, Neg, Sub};
+
+mod ops;
+
+/// A vector whose entries are computed on demand.
+pub trait Expression
+{
+    type Scalar: Copy;
+
+    /// The number of entries.
+    fn dimension(&self) -> usize;
+
+    /// The entry at `idx`, which must be below `dimension()`.
+    fn at(&self, idx: usize) -> Self::Scalar;
+
+    /// Overwrites `destination` with the expression in one pass.
+    fn assign<V>(&self, destination: &mut V)
+    where
+        V: AsMut<[Self::Scalar]> + ?Sized
+    {
+        let entries: &mut [Self::Scalar] = destination.as_mut();
+        assert_eq!(entries.len(), self.dimension(), "the destination has the wrong dimension");
+        for (idx, entry) in entries.iter_mut().enumerate() {
+            *entry = self.at(idx);
+        }
+    }
+
+    /// Adds the expression to `destination` in one pass.
+    fn add_assign<V>(&self, destination: &mut V)
+    where
+        V: AsMut<[Self::Scalar]> + ?Sized,
+        Self::Scalar: Add<Output=Self::Scalar>
+    {
+        let entries: &mut [Self::Scalar] = destination.as_mut();
+        assert_eq!(entries.len(), self.dimension(), "the destination has the wrong dimension");
+        for (idx, entry) in entries.iter_mut().enumerate() {
+            *entry = *entry + self.at(idx);
+        }
+    }
+
+    /// The product `factor · self`.
+    ///
+    /// `factor * expression` is only implemented for the primitive and complex
+    /// scalars; this works for any scalar type, such as `Gf<P>` or `Fixed`.
+    fn scale(self, factor: Self::Scalar) -> Scaled<Self, Self::Scalar>
+    where
+        Self: Sized,
+        Self::Scalar: Mul<Output=Self::Scalar>
+    {
+        Scaled { expression: self, factor }
+    }
+
+    /// Collects the expression into a new vector.
+    fn eval<V>(&self) -> V
+    where
+        V: FromIterator<Self::Scalar>
+    {
+        (0..self.dimension()).map(|idx| self.at(idx)).collect()
+    }
+}
+
+/// Borrows a vector as the leaf of an expression.
+pub fn lazy<V, T>(vector: &V) -> Leaf<'_, T>
+where
+    V: AsRef<[T]> + ?Sized
+{
+    Leaf { entries: vector.as_ref() }
+}
+
+/// A borrowed vector, see `lazy`.
+#[derive(Clone, Copy, Debug)]
+pub struct Leaf<'a, T>
+{
+    entries: &'a [T]
+}
+
+impl<T> Expression for Leaf<'_, T>
+where
+    T: Copy
+{
+    type Scalar = T;
+
+    #[inline]
+    fn dimension(&self) -> usize
+    {
+        self.entries.len()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> T
+    {
+        self.entries[idx]
+    }
+}
+
+/// The entrywise sum `lhs + rhs`.
+#[derive(Clone, Copy, Debug)]
+pub struct Sum<L, R>
+{
+    lhs: L,
+    rhs: R
+}
+
+impl<L, R> Sum<L, R>
+where
+    L: Expression,
+    R: Expression<Scalar=L::Scalar>
+{
+    fn new(lhs: L, rhs: R) -> Self
+    {
+        assert_eq!(lhs.dimension(), rhs.dimension(), "the operands have different dimensions");
+        Sum { lhs, rhs }
+    }
+}
+
+impl<L, R> Expression for Sum<L, R>
+where
+    L: Expression,
+    R: Expression<Scalar=L::Scalar>,
+    L::Scalar: Add<Output=L::Scalar>
+{
+    type Scalar = L::Scalar;
+
+    #[inline]
+    fn dimension(&self) -> usize
+    {
+        self.lhs.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> L::Scalar
+    {
+        self.lhs.at(idx) + self.rhs.at(idx)
+    }
+}
+
+/// The entrywise difference `lhs - rhs`.
+#[derive(Clone, Copy, Debug)]
+pub struct Difference<L, R>
+{
+    lhs: L,
+    rhs: R
+}
+
+impl<L, R> Difference<L, R>
+where
+    L: Expression,
+    R: Expression<Scalar=L::Scalar>
+{
+    fn new(lhs: L, rhs: R) -> Self
+    {
+        assert_eq!(lhs.dimension(), rhs.dimension(), "the operands have different dimensions");
+        Difference { lhs, rhs }
+    }
+}
+
+impl<L, R> Expression for Difference<L, R>
+where
+    L: Expression,
+    R: Expression<Scalar=L::Scalar>,
+    L::Scalar: Sub<Output=L::Scalar>
+{
+    type Scalar = L::Scalar;
+
+    #[inline]
+    fn dimension(&self) -> usize
+    {
+        self.lhs.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> L::Scalar
+    {
+        self.lhs.at(idx) - self.rhs.at(idx)
+    }
+}
+
+/// The product `factor · expression`.
+#[derive(Clone, Copy, Debug)]
+pub struct Scaled<E, T>
+{
+    expression: E,
+    factor: T
+}
+
+impl<E, T> Expression for Scaled<E, T>
+where
+    E: Expression<Scalar=T>,
+    T: Copy + Mul<Output=T>
+{
+    type Scalar = T;
+
+    #[inline]
+    fn dimension(&self) -> usize
+    {
+        self.expression.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> T
+    {
+        self.factor * self.expression.at(idx)
+    }
+}
+
+/// The additive inverse `-expression`.
+#[derive(Clone, Copy, Debug)]
+pub struct Negated<E>
+{
+    expression: E
+}
+
+impl<E> Expression for Negated<E>
+where
+    E: Expression,
+    E::Scalar: Neg<Output=E::Scalar>
+{
+    type Scalar = E::Scalar;
+
+    #[inline]
+    fn dimension(&self) -> usize
+    {
+        self.expression.dimension()
+    }
+
+    #[inline]
+    fn at(&self, idx: usize) -> E::Scalar
+    {
+        -self.expression.at(idx)
+    }
+}
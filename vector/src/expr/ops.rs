@@ -0,0 +1,91 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use algebra::Complex;
+
+use super::{Difference, Expression, Leaf, Negated, Scaled, Sum};
+
+/// Implements the arithmetic operators that grow an expression node into a larger tree.
+macro_rules! operators
+{
+    ([$($generics:tt)*] $node:ty) => {
+        impl<$($generics)*, Rhs> Add<Rhs> for $node
+        where
+            $node: Expression,
+            Rhs: Expression<Scalar=<$node as Expression>::Scalar>
+        {
+            type Output = Sum<$node, Rhs>;
+
+            fn add(self, rhs: Rhs) -> Self::Output
+            {
+                Sum::new(self, rhs)
+            }
+        }
+
+        impl<$($generics)*, Rhs> Sub<Rhs> for $node
+        where
+            $node: Expression,
+            Rhs: Expression<Scalar=<$node as Expression>::Scalar>
+        {
+            type Output = Difference<$node, Rhs>;
+
+            fn sub(self, rhs: Rhs) -> Self::Output
+            {
+                Difference::new(self, rhs)
+            }
+        }
+
+        impl<$($generics)*> Mul<<$node as Expression>::Scalar> for $node
+        where
+            $node: Expression
+        {
+            type Output = Scaled<$node, <$node as Expression>::Scalar>;
+
+            fn mul(self, factor: <$node as Expression>::Scalar) -> Self::Output
+            {
+                Scaled { expression: self, factor }
+            }
+        }
+
+        impl<$($generics)*> Neg for $node
+        where
+            $node: Expression
+        {
+            type Output = Negated<$node>;
+
+            fn neg(self) -> Self::Output
+            {
+                Negated { expression: self }
+            }
+        }
+
+        operators!(@scalars [$($generics)*] $node, f32, f64, i8, i16, i32, i64, i128, isize);
+        operators!(@scalars [$($generics)*] $node, u8, u16, u32, u64, u128, usize);
+        operators!(@scalars [$($generics)*] $node, Complex<f32>, Complex<f64>);
+    };
+
+    (@scalars $generics:tt $node:ty, $($scalar:ty),*) => {
+        $(
+            operators!(@scalar $generics $node, $scalar);
+        )*
+    };
+
+    (@scalar [$($generics:tt)*] $node:ty, $scalar:ty) => {
+        impl<$($generics)*> Mul<$node> for $scalar
+        where
+            $node: Expression<Scalar=$scalar>
+        {
+            type Output = Scaled<$node, $scalar>;
+
+            fn mul(self, expression: $node) -> Self::Output
+            {
+                Scaled { expression, factor: self }
+            }
+        }
+    };
+}
+
+operators!(['a, T] Leaf<'a, T>);
+operators!([L, R] Sum<L, R>);
+operators!([L, R] Difference<L, R>);
+operators!([E, T] Scaled<E, T>);
+operators!([E] Negated<E>);
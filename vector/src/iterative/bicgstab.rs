@@ -0,0 +1,92 @@
+use std::iter::FromIterator;
+
+use algebra::{ComplexField, DotV, LinearOperator, StridedMut, VAXPYMut, VScaleMut, VectorSpace};
+
+use crate::decomp::inner;
+use super::{norm, small, zero, Convergence, Preconditioner, Report, Status};
+
+/// Right-preconditioned BiCGSTAB for a general `A`.
+///
+/// Each iteration applies the operator twice; an iteration that converges at
+/// its half step stops there.
+pub fn bicgstab<S, V, T, A, P>(
+    space: &S,
+    operator: &A,
+    b: &V,
+    preconditioner: &P,
+    convergence: &Convergence<T::Real>
+) -> Report<V, T::Real>
+where
+    S: VectorSpace<Vector=V> + VAXPYMut<Vector=V, Scalar=T> + DotV<Vector=V, Scalar=T> + VScaleMut<Vector=V, Scalar=T>,
+    T: ComplexField,
+    V: Clone + FromIterator<T> + StridedMut<Scalar=T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>,
+    A: LinearOperator<Domain=S, Codomain=S>,
+    P: Preconditioner<V>
+{
+    let (zero_scalar, one) = (T::additive_identity(), T::multiplicative_identity());
+    let reference = norm(space, b);
+    let mut x: V = zero(space, b);
+    let mut r: V = b.clone();
+    let shadow: V = b.clone();
+    let mut residuals: Vec<T::Real> = vec![reference];
+
+    let mut p: V = zero(space, b);
+    let mut v: V = zero(space, b);
+    let (mut rho, mut alpha, mut omega) = (one, one, one);
+
+    let mut iterations: usize = 0;
+    let status: Status = loop {
+        if small(residuals[iterations], reference, convergence) {
+            break Status::Converged;
+        }
+        if iterations == convergence.max_iterations {
+            break Status::MaxIterations;
+        }
+
+        let next: T = inner(space, &shadow, &r);
+        if next == zero_scalar {
+            break Status::Breakdown;
+        }
+        // p ← r + β(p - ωv)
+        let beta: T = (next / rho) * (alpha / omega);
+        rho = next;
+        space.vaxpy_mut(&-omega, &v, &mut p);
+        space.vscale_mut(&mut p, &beta);
+        space.vaxpy_mut(&one, &r, &mut p);
+
+        let y: V = preconditioner.precondition(&p);
+        v = operator.apply(&y);
+        let projection: T = inner(space, &shadow, &v);
+        if projection == zero_scalar {
+            break Status::Breakdown;
+        }
+        alpha = rho / projection;
+        space.vaxpy_mut(&alpha, &y, &mut x);
+        space.vaxpy_mut(&-alpha, &v, &mut r);
+        iterations += 1;
+
+        let half = norm(space, &r);
+        if small(half, reference, convergence) {
+            residuals.push(half);
+            continue;
+        }
+
+        let z: V = preconditioner.precondition(&r);
+        let t: V = operator.apply(&z);
+        let energy: T = inner(space, &t, &t);
+        if energy == zero_scalar {
+            residuals.push(half);
+            break Status::Breakdown;
+        }
+        omega = inner(space, &t, &r) / energy;
+        space.vaxpy_mut(&omega, &z, &mut x);
+        space.vaxpy_mut(&-omega, &t, &mut r);
+        residuals.push(norm(space, &r));
+        if omega == zero_scalar {
+            break Status::Breakdown;
+        }
+    };
+
+    Report { solution: x, iterations, residuals, status }
+}
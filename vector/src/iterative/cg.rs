@@ -0,0 +1,63 @@
+use std::iter::FromIterator;
+
+use algebra::{ComplexField, DotV, LinearOperator, StridedMut, VAXPYMut, VScaleMut, VectorSpace};
+
+use crate::decomp::inner;
+use super::{norm, small, zero, Convergence, Preconditioner, Report, Status};
+
+/// Preconditioned conjugate gradients for a Hermitian positive definite `A`.
+///
+/// The preconditioner must also be Hermitian positive definite.
+pub fn cg<S, V, T, A, P>(
+    space: &S,
+    operator: &A,
+    b: &V,
+    preconditioner: &P,
+    convergence: &Convergence<T::Real>
+) -> Report<V, T::Real>
+where
+    S: VectorSpace<Vector=V> + VAXPYMut<Vector=V, Scalar=T> + DotV<Vector=V, Scalar=T> + VScaleMut<Vector=V, Scalar=T>,
+    T: ComplexField,
+    V: Clone + FromIterator<T> + StridedMut<Scalar=T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>,
+    A: LinearOperator<Domain=S, Codomain=S>,
+    P: Preconditioner<V>
+{
+    let reference = norm(space, b);
+    let mut x: V = zero(space, b);
+    let mut r: V = b.clone();
+    let mut residuals: Vec<T::Real> = vec![reference];
+
+    let mut z: V = preconditioner.precondition(&r);
+    let mut p: V = z.clone();
+    let mut rho: T = inner(space, &r, &z);
+
+    let mut iterations: usize = 0;
+    let status: Status = loop {
+        if small(residuals[iterations], reference, convergence) {
+            break Status::Converged;
+        }
+        if iterations == convergence.max_iterations {
+            break Status::MaxIterations;
+        }
+
+        let q: V = operator.apply(&p);
+        let curvature: T = inner(space, &p, &q);
+        if rho == T::additive_identity() || curvature == T::additive_identity() {
+            break Status::Breakdown;
+        }
+        let alpha: T = rho / curvature;
+        space.vaxpy_mut(&alpha, &p, &mut x);
+        space.vaxpy_mut(&-alpha, &q, &mut r);
+        iterations += 1;
+        residuals.push(norm(space, &r));
+
+        z = preconditioner.precondition(&r);
+        let next: T = inner(space, &r, &z);
+        space.vscale_mut(&mut p, &(next / rho));
+        space.vaxpy_mut(&T::multiplicative_identity(), &z, &mut p);
+        rho = next;
+    };
+
+    Report { solution: x, iterations, residuals, status }
+}
@@ -0,0 +1,136 @@
+use std::iter::FromIterator;
+
+use algebra::{AdditiveIdentity, ComplexField, DotV, LinearOperator, MultiplicativeIdentity, StridedMut, VAXPYMut, VScaleMut, VectorSpace};
+
+use crate::decomp::inner;
+use super::{norm, small, zero, Convergence, Preconditioner, Report, Status};
+
+/// Restarted GMRES(`restart`) with right preconditioning for a general `A`.
+///
+/// Each cycle builds an Arnoldi basis of at most `restart` vectors and
+/// minimises the residual over it with Givens rotations, so the recorded
+/// residuals are those of the unpreconditioned system and never increase.
+pub fn gmres<S, V, T, A, P>(
+    space: &S,
+    operator: &A,
+    b: &V,
+    preconditioner: &P,
+    restart: usize,
+    convergence: &Convergence<T::Real>
+) -> Report<V, T::Real>
+where
+    S: VectorSpace<Vector=V> + VAXPYMut<Vector=V, Scalar=T> + DotV<Vector=V, Scalar=T> + VScaleMut<Vector=V, Scalar=T>,
+    T: ComplexField,
+    V: Clone + FromIterator<T> + StridedMut<Scalar=T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>,
+    A: LinearOperator<Domain=S, Codomain=S>,
+    P: Preconditioner<V>
+{
+    let restart: usize = restart.max(1);
+    let reference = norm(space, b);
+    let mut x: V = zero(space, b);
+    let mut residuals: Vec<T::Real> = Vec::new();
+    let mut iterations: usize = 0;
+    let mut breakdown: bool = false;
+
+    let status: Status = loop {
+        let mut r: V = operator.apply(&x);
+        space.vscale_mut(&mut r, &-T::multiplicative_identity());
+        space.vaxpy_mut(&T::multiplicative_identity(), b, &mut r);
+        let beta = norm(space, &r);
+        match residuals.last_mut() {
+            // The restart replaces the estimate from the previous cycle.
+            Some(last) => *last = beta,
+            None => residuals.push(beta)
+        }
+        if small(beta, reference, convergence) {
+            break Status::Converged;
+        }
+        // An invariant Krylov subspace that does not contain the solution.
+        if breakdown {
+            break Status::Breakdown;
+        }
+        if iterations == convergence.max_iterations {
+            break Status::MaxIterations;
+        }
+
+        space.vscale_mut(&mut r, &T::from_real(T::Real::multiplicative_identity() / beta));
+        let mut basis: Vec<V> = vec![r];
+        let mut directions: Vec<V> = Vec::with_capacity(restart);
+        let mut hessenberg: Vec<Vec<T>> = Vec::with_capacity(restart);
+        let mut rotations: Vec<(T, T)> = Vec::with_capacity(restart);
+        let mut g: Vec<T> = vec![T::from_real(beta)];
+
+        while directions.len() < restart && iterations < convergence.max_iterations {
+            let j: usize = directions.len();
+            let z: V = preconditioner.precondition(&basis[j]);
+            let mut w: V = operator.apply(&z);
+            directions.push(z);
+
+            // Modified Gram-Schmidt against the Arnoldi basis.
+            let mut column: Vec<T> = Vec::with_capacity(j + 2);
+            for q in basis.iter() {
+                let h: T = inner(space, q, &w);
+                space.vaxpy_mut(&-h, q, &mut w);
+                column.push(h);
+            }
+            let h = norm(space, &w);
+            column.push(T::from_real(h));
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (upper, lower) = (column[i], column[i + 1]);
+                column[i] = c * upper + s * lower;
+                column[i + 1] = c * lower - s.conjugate() * upper;
+            }
+            let (c, s) = givens(column[j], h);
+            column[j] = c * column[j] + s * column[j + 1];
+            column[j + 1] = T::additive_identity();
+            g.push(-s.conjugate() * g[j]);
+            g[j] = c * g[j];
+            rotations.push((c, s));
+            hessenberg.push(column);
+
+            iterations += 1;
+            residuals.push(g[j + 1].modulus());
+            if h == T::Real::additive_identity() {
+                breakdown = true;
+                break;
+            }
+            if small(residuals[residuals.len() - 1], reference, convergence) {
+                break;
+            }
+            space.vscale_mut(&mut w, &T::from_real(T::Real::multiplicative_identity() / h));
+            basis.push(w);
+        }
+
+        // Back substitution for the upper-triangular least-squares system.
+        let k: usize = hessenberg.len();
+        let mut y: Vec<T> = g[..k].to_vec();
+        for i in (0..k).rev() {
+            for l in i + 1..k {
+                let update: T = hessenberg[l][i] * y[l];
+                y[i] -= update;
+            }
+            y[i] = if hessenberg[i][i] == T::additive_identity() { T::additive_identity() } else { y[i] / hessenberg[i][i] };
+        }
+        for (coefficient, z) in y.iter().zip(&directions) {
+            space.vaxpy_mut(coefficient, z, &mut x);
+        }
+    };
+
+    Report { solution: x, iterations, residuals, status }
+}
+
+/// The rotation `(c, s)` with `[c s; -s̄ c] [a; b] = [ν; 0]` for a real `b ≥ 0`.
+fn givens<T>(a: T, b: T::Real) -> (T, T)
+where
+    T: ComplexField
+{
+    let modulus = a.modulus();
+    if modulus == T::Real::additive_identity() {
+        return (T::additive_identity(), T::multiplicative_identity())
+    }
+    let nu = (modulus * modulus + b * b).sqrt();
+    let phase: T = a.scale(T::Real::multiplicative_identity() / modulus);
+    (T::from_real(modulus / nu), phase.scale(b / nu))
+}
@@ -0,0 +1,219 @@
+//! Iterative Krylov solvers for `Ax = b`.
+//!
+//! The solvers never look inside the operator: `A` is any `LinearOperator` on
+//! the space, so a dense `mspace!` matrix, a sparse matrix or a matrix-free
+//! product wrapped in an `FnOperator` all work.
+//! Vectors are only touched through `VAXPYMut`, `DotV` and `VScaleMut`, plus a
+//! conjugating copy for Hermitian inner products.
+
+use std::iter::FromIterator;
+
+use algebra::{ComplexField, DotV, RealField, Strided, VScaleMut};
+
+use crate::Dense;
+use crate::decomp::{inner, DecompError};
+use crate::sparse::CsrMatrix;
+
+mod cg;
+pub use cg::*;
+
+mod gmres;
+pub use gmres::*;
+
+mod bicgstab;
+pub use bicgstab::*;
+
+/// When an iterative solver stops.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Convergence<R>
+{
+    /// The solver stops once `‖b - Ax‖ ≤ tolerance · ‖b‖`.
+    pub tolerance: R,
+
+    /// The number of iterations after which the solver gives up.
+    ///
+    /// An iteration applies the operator once in `cg` and `gmres` and twice
+    /// in `bicgstab`; `gmres` also applies it once per restart to recompute
+    /// the residual.
+    pub max_iterations: usize
+}
+
+impl<R> Convergence<R>
+{
+    pub fn new(tolerance: R, max_iterations: usize) -> Self
+    {
+        Convergence { tolerance, max_iterations }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status
+{
+    Converged,
+
+    /// The iteration budget ran out before the tolerance was met.
+    MaxIterations,
+
+    /// A scalar the recurrence divides by vanished.
+    Breakdown
+}
+
+/// The outcome of an iterative solve.
+#[derive(Clone, Debug)]
+pub struct Report<V, R>
+{
+    solution: V,
+    iterations: usize,
+    residuals: Vec<R>,
+    status: Status
+}
+
+impl<V, R> Report<V, R>
+{
+    pub fn solution(&self) -> &V
+    {
+        &self.solution
+    }
+
+    pub fn into_solution(self) -> V
+    {
+        self.solution
+    }
+
+    pub fn iterations(&self) -> usize
+    {
+        self.iterations
+    }
+
+    /// The residual norm `‖b - Ax‖` before the first and after every iteration.
+    pub fn residuals(&self) -> &[R]
+    {
+        &self.residuals
+    }
+
+    pub fn status(&self) -> Status
+    {
+        self.status
+    }
+
+    pub fn converged(&self) -> bool
+    {
+        self.status == Status::Converged
+    }
+}
+
+/// An approximate inverse `M⁻¹` applied to residuals.
+///
+/// Any `Fn(&V) -> V` is a preconditioner, so user-supplied ones are closures.
+pub trait Preconditioner<V>
+{
+    fn precondition(&self, residual: &V) -> V;
+}
+
+impl<V, F> Preconditioner<V> for F
+where
+    F: Fn(&V) -> V
+{
+    fn precondition(&self, residual: &V) -> V
+    {
+        self(residual)
+    }
+}
+
+/// The trivial preconditioner `M = I`.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Identity;
+
+impl<V> Preconditioner<V> for Identity
+where
+    V: Clone
+{
+    fn precondition(&self, residual: &V) -> V
+    {
+        residual.clone()
+    }
+}
+
+/// The Jacobi preconditioner `M = diag(A)`.
+#[derive(Clone, Debug)]
+pub struct Jacobi<T>
+{
+    inverse: Vec<T>
+}
+
+impl<T> Jacobi<T>
+where
+    T: ComplexField
+{
+    /// Inverts the diagonal of `A`, failing with the index of the first zero.
+    pub fn new(diagonal: Vec<T>) -> Result<Self, DecompError>
+    {
+        if let Some(pivot) = diagonal.iter().position(|&val| val == T::additive_identity()) {
+            return Err(DecompError::Singular { pivot })
+        }
+        Ok(Jacobi {
+            inverse: diagonal.into_iter().map(|val| T::multiplicative_identity() / val).collect()
+        })
+    }
+
+    pub fn from_dense<M>(matrix: &M) -> Result<Self, DecompError>
+    where
+        M: Dense<Scalar=T>
+    {
+        let columns: usize = matrix.columns();
+        Jacobi::new((0..matrix.rows()).map(|i| matrix.as_slice()[i * columns + i]).collect())
+    }
+
+    pub fn from_csr(matrix: &CsrMatrix<T>) -> Result<Self, DecompError>
+    {
+        Jacobi::new((0..matrix.rows()).map(|i| matrix.get(i, i).copied().unwrap_or_else(T::additive_identity)).collect())
+    }
+}
+
+impl<T, V> Preconditioner<V> for Jacobi<T>
+where
+    T: ComplexField,
+    V: FromIterator<T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    fn precondition(&self, residual: &V) -> V
+    {
+        residual
+            .into_iter()
+            .zip(&self.inverse)
+            .map(|(&r, &d)| r * d)
+            .collect()
+    }
+}
+
+/// The Euclidean norm `‖x‖`.
+pub(crate) fn norm<S, V, T>(space: &S, x: &V) -> T::Real
+where
+    S: DotV<Vector=V, Scalar=T>,
+    T: ComplexField,
+    V: FromIterator<T> + Strided<Scalar=T>,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    inner(space, x, x).real().sqrt()
+}
+
+/// A zero vector of the same dimension as `x`.
+pub(crate) fn zero<S, V, T>(space: &S, x: &V) -> V
+where
+    S: VScaleMut<Vector=V, Scalar=T>,
+    T: ComplexField,
+    V: Clone
+{
+    let mut output: V = x.clone();
+    space.vscale_mut(&mut output, &T::additive_identity());
+    output
+}
+
+/// Whether `residual ≤ tolerance · reference`, treating a zero reference as absolute.
+pub(crate) fn small<R>(residual: R, reference: R, convergence: &Convergence<R>) -> bool
+where
+    R: RealField
+{
+    let scale = if reference == R::additive_identity() { R::multiplicative_identity() } else { reference };
+    residual <= convergence.tolerance * scale
+}
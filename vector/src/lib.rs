@@ -1,12 +1,40 @@
+mod serialization;
 mod array;
 mod space;
 
-#[allow(unused_macros)]
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+
+pub mod view;
+pub use view::{VectorView, VectorViewMut};
+
+mod matrix;
+pub use matrix::Dense;
+
+pub mod decomp;
+
+pub mod sparse;
+
+pub mod iterative;
+
+pub mod operator;
+
+pub mod expr;
 
+#[cfg(feature = "npy")]
+pub mod npy;
+
+pub mod mtx;
+
+pub mod packed;
+
+#[allow(unused_macros)]
 pub enum Implements {
     BinOps,
     UniOps,
-    BlasOps
+    BlasOps,
+    CrossOps
 }
 
 pub enum BinOps {
@@ -28,6 +56,15 @@ pub enum BlasOps {
     DotVMut     
 }
 
+pub enum CrossOps {
+    VCross
+}
+
+pub enum MatOps {
+    GEMV,
+    GEMVMut
+}
+
 #[macro_export]
 macro_rules! vspace 
 {
@@ -35,7 +72,7 @@ macro_rules! vspace
     (
         $space:ident {
             vector: $name:ident,
-            dimension: $length:expr
+            dimension: $length:tt
         }
     ) => {
         vspace! {
@@ -50,7 +87,29 @@ macro_rules! vspace
     (
         $space:ident {
             vector: $name:ident,
-            dimension: $length:expr,
+            dimension: $length:tt,
+            packed: Gf2
+        }
+    ) => {
+        paste::item! {
+            pub use [< $space:lower >]::{$name, $space};
+
+            #[allow(unused_imports)]
+            mod [< $space:lower >]
+            {
+                use vector::vspace;
+
+                vspace!(@imports);
+
+                packed_base!($length, $name, $space);
+            }
+        }
+    };
+
+    (
+        $space:ident {
+            vector: $name:ident,
+            dimension: $length:tt,
             using: $inner:ty
         }
     ) => {
@@ -68,7 +127,8 @@ macro_rules! vspace
                 Implements::BlasOps::VAXPY,
                 Implements::BlasOps::VAXPYMut,
                 Implements::BlasOps::DotV,
-                Implements::BlasOps::DotVMut                
+                Implements::BlasOps::DotVMut,
+                Implements::CrossOps::VCross
             }
         }
     };
@@ -77,7 +137,7 @@ macro_rules! vspace
     (
         $space:ident {
             vector: $name:ident,
-            dimension: $length:expr,
+            dimension: $length:tt,
             using: $inner:ty,
             $(Implements::$kind:ident::$branch:ident),*
         }
@@ -95,20 +155,29 @@ macro_rules! vspace
                 vector_base!($length, $name, $inner, T);
                 vectorspace!($length, $name, $space, $inner, T);    
 
-                vspace!(@implements $name, $space, $($kind, $branch),*);
+                vspace!(@implements $length, $name, $space, $($kind, $branch),*);
             }
         }
     };
 
-    (@implements $name:ident, $space:ident, $($kind:ident, $branch:ident),*) => {
+    (@implements $length:tt, $name:ident, $space:ident, $($kind:ident, $branch:ident),*) => {
         $(
-            $kind!(@ $branch $name, $space);
-        )*      
+            vspace!(@implement $length, $name, $space, $kind, $branch);
+        )*
+    };
+
+    (@implement $length:tt, $name:ident, $space:ident, CrossOps, $branch:ident) => {
+        CrossOps!(@ $branch $length, $name, $space);
+    };
+
+    (@implement $length:tt, $name:ident, $space:ident, $kind:ident, $branch:ident) => {
+        $kind!(@ $branch $name, $space);
     };
 
     (@imports) => {
-        use std::{marker, fmt, ops, iter};
+        use std::{marker, fmt, ops, iter, convert};
         use iter::FromIterator;
+        use convert::TryFrom;
         use marker::PhantomData;
         use fmt::Debug;
         use ops::{AddAssign, Add, MulAssign, Mul, Index, IndexMut, Neg};
@@ -116,4 +185,113 @@ macro_rules! vspace
         use algebra::*;
         use vector::*;
     };
-} 
+}
+
+#[macro_export]
+macro_rules! mspace 
+{
+    /********************* Convenience DSL ************************/
+    (
+        $space:ident {
+            matrix: $name:ident,
+            dimension: $length:expr
+        }
+    ) => {
+        mspace! {
+            $space {
+                matrix: $name,
+                rows: $length,
+                columns: $length,
+                using: Vec<T>
+            }
+        }
+    };
+
+    (
+        $space:ident {
+            matrix: $name:ident,
+            dimension: $length:expr,
+            using: $inner:ty
+        }
+    ) => {
+        mspace! {
+            $space {
+                matrix: $name,
+                rows: $length,
+                columns: $length,
+                using: $inner
+            }
+        }
+    };
+
+    (
+        $space:ident {
+            matrix: $name:ident,
+            rows: $rows:expr,
+            columns: $columns:expr
+        }
+    ) => {
+        mspace! {
+            $space {
+                matrix: $name,
+                rows: $rows,
+                columns: $columns,
+                using: Vec<T>
+            }
+        }
+    };
+
+    (
+        $space:ident {
+            matrix: $name:ident,
+            rows: $rows:expr,
+            columns: $columns:expr,
+            using: $inner:ty
+        }
+    ) => {
+        mspace! {
+            $space {
+                matrix: $name,
+                rows: $rows,
+                columns: $columns,
+                using: $inner,
+                Implements::BinOps::VAddMut,
+                Implements::BinOps::VAdd,
+                Implements::BinOps::VScale,
+                Implements::BinOps::VScaleMut,
+                Implements::UniOps::VAdditiveInverse,
+                Implements::UniOps::VAdditiveInverseMut,
+                Implements::MatOps::GEMV,
+                Implements::MatOps::GEMVMut
+            }
+        }
+    };
+
+    /********************* Implementation ************************/
+    (
+        $space:ident {
+            matrix: $name:ident,
+            rows: $rows:expr,
+            columns: $columns:expr,
+            using: $inner:ty,
+            $(Implements::$kind:ident::$branch:ident),*
+        }
+    ) => {
+        paste::item! {
+            pub use [< $space:lower >]::{$name, $space};
+
+            #[allow(unused_imports)]
+            mod [< $space:lower >]
+            {
+                use vector::vspace;
+    
+                vspace!(@imports);
+    
+                matrix_base!($rows, $columns, $name, $inner, T);
+                vectorspace!($rows * $columns, $name, $space, $inner, T);    
+
+                vspace!(@implements ($rows * $columns), $name, $space, $($kind, $branch),*);
+            }
+        }
+    };
+}
@@ -0,0 +1,19 @@
+mod structural;
+mod ops;
+
+/// Row-major access to the storage of a dense matrix.
+///
+/// The matrices generated by `mspace!` implement this trait so that the
+/// kernels in `decomp` can work on them without knowing their storage.
+pub trait Dense
+{
+    type Scalar;
+
+    fn rows(&self) -> usize;
+
+    fn columns(&self) -> usize;
+
+    fn as_slice(&self) -> &[Self::Scalar];
+
+    fn as_mut_slice(&mut self) -> &mut [Self::Scalar];
+}
@@ -0,0 +1,45 @@
+#[macro_export]
+macro_rules! MatOps {
+    (@GEMV $name:ident, $space:ident) => {
+        impl<T, X, Y> GEMV<X, Y> for $name<T>
+        where
+            T: Ring + Copy,
+            X: Index<usize, Output=T> + Entrywise,
+            Y: Clone + IndexMut<usize, Output=T> + Entrywise
+        {
+            type Scalar = T;
+
+            fn gemv(&self, alpha: &Self::Scalar, x: &X, beta: &Self::Scalar, y: &Y) -> Y
+            {
+                let mut output: Y = y.clone();
+                self.gemv_mut(alpha, x, beta, &mut output);
+                output
+            }
+        }
+    };
+
+    (@GEMVMut $name:ident, $space:ident) => {
+        impl<T, X, Y> GEMVMut<X, Y> for $name<T>
+        where
+            T: Ring + Copy,
+            X: Index<usize, Output=T> + Entrywise,
+            Y: IndexMut<usize, Output=T> + Entrywise
+        {
+            type Scalar = T;
+
+            fn gemv_mut(&self, alpha: &Self::Scalar, x: &X, beta: &Self::Scalar, y: &mut Y)
+            {
+                let columns: usize = self.columns();
+                assert_eq!(x.length(), columns, "x needs an entry for every column");
+                assert_eq!(y.length(), self.rows(), "y needs an entry for every row");
+                for (i, row) in self.as_slice().chunks(columns).enumerate() {
+                    let acc: T = row
+                        .iter()
+                        .enumerate()
+                        .fold(T::additive_identity(), |acc, (j, val)| acc + *val * x[j]);
+                    y[i] = *alpha * acc + *beta * y[i];
+                }
+            }
+        }
+    };
+}
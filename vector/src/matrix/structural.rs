@@ -0,0 +1,113 @@
+#[macro_export]
+macro_rules! matrix_base {
+    ($rows:expr, $columns:expr, $name:ident, $inner:ty, $T:ident) => {
+        #[derive(Clone)]
+        pub struct $name<$T>($inner);  
+
+        serde_base!($rows * $columns, $name, $inner, $T);
+
+        impl<$T> $name<$T>
+        {
+            pub fn new(inner: $inner) -> Self 
+            {   
+                assert!(inner.len() == $rows * $columns);
+                $name(inner)
+            }
+        }        
+
+        impl<$T> From<$inner> for $name<$T>
+        {
+            fn from(inner: $inner) -> Self {
+                Self::new( inner )
+            }
+        }
+
+        impl<'a,$T> IntoIterator for &'a $name<$T>
+        {
+            type Item = &'a $T;
+            type IntoIter = std::slice::Iter<'a,$T>;
+
+            fn into_iter(self) -> Self::IntoIter
+            {
+                self.0.iter()
+            }
+        }
+
+        impl<$T> FromIterator<$T> for $name<$T>
+        where
+            $inner: TryFrom<Vec<$T>>
+        {
+            fn from_iter<I>(iterator: I) -> Self
+            where
+                I: IntoIterator<Item=$T>
+            {
+                let buf: Vec<$T> = iterator
+                    .into_iter()
+                    .collect();
+                assert!(buf.len() == $rows * $columns);
+                match <$inner>::try_from(buf) {
+                    Ok(inner) => Self::new(inner),
+                    Err(_) => unreachable!()
+                }
+            }
+        }
+
+        impl<$T> Index<(usize, usize)> for $name<$T>
+        {
+            type Output = $T;
+
+            fn index(&self, (row, column): (usize, usize)) -> &Self::Output 
+            {
+                assert!(row < $rows && column < $columns);
+                &self.0[row * $columns + column]
+            }
+        }
+
+        impl<$T> IndexMut<(usize, usize)> for $name<$T>
+        {
+            fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut Self::Output 
+            {
+                assert!(row < $rows && column < $columns);
+                &mut self.0[row * $columns + column]
+            }
+        }
+
+        impl<$T> Dense for $name<$T>
+        {
+            type Scalar = $T;
+
+            fn rows(&self) -> usize
+            {
+                $rows
+            }
+
+            fn columns(&self) -> usize
+            {
+                $columns
+            }
+
+            fn as_slice(&self) -> &[$T]
+            {
+                &self.0[..]
+            }
+
+            fn as_mut_slice(&mut self) -> &mut [$T]
+            {
+                &mut self.0[..]
+            }
+        }
+
+        impl<$T> Debug for $name<$T>
+        where
+            $T: Debug
+        {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+            {
+                let rows: Vec<&[$T]> = self.0[..]
+                    .chunks($columns)
+                    .collect();
+                write!(f, "{:?}", rows)
+            }
+        }
+    };
+} 
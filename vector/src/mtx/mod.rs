@@ -0,0 +1,570 @@
+//! Reading and writing Matrix Market `.mtx` files.
+//!
+//! Both the sparse `coordinate` and the dense `array` formats are read, with
+//! `real`, `complex`, `integer` and `pattern` fields and `general`,
+//! `symmetric`, `skew-symmetric` and `hermitian` symmetry. Symmetric storage is
+//! expanded on reading, so every reader hands out the full matrix. Indices in
+//! the file count from one and are converted to the zero-based indices used by
+//! the rest of the crate.
+
+use std::{error, fmt, io::{self, BufRead, BufReader, Read}};
+use std::iter::FromIterator;
+use std::ops::{Add, Neg};
+
+use algebra::{AdditiveIdentity, Complex, FiniteDimensional, MultiplicativeIdentity};
+
+use crate::Dense;
+use crate::sparse::{CooMatrix, CscMatrix, CsrMatrix};
+
+mod write;
+pub use write::*;
+
+const BANNER: &str = "%%matrixmarket";
+
+#[derive(Debug)]
+pub enum MtxError
+{
+    Io(io::Error),
+
+    /// The file is malformed at `line`, which counts from one.
+    Parse { line: usize, reason: String },
+
+    /// Entries of this field cannot be read as the requested element type.
+    FieldMismatch { field: Field, element: &'static str },
+
+    /// The stored matrix, as `(rows, columns)`, does not have the shape being read.
+    DimensionMismatch { expected: (usize, usize), found: (usize, usize) },
+
+    /// A zero-based `(row, column)` entry being written lies outside a matrix of this shape.
+    EntryOutOfBounds { entry: (usize, usize), shape: (usize, usize) }
+}
+
+impl fmt::Display for MtxError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            MtxError::Io(error) => write!(f, "{}", error),
+            MtxError::Parse { line, reason } => write!(f, "line {}: {}", line, reason),
+            MtxError::FieldMismatch { field, element } => {
+                write!(f, "cannot read a {} matrix as {}", field, element)
+            },
+            MtxError::DimensionMismatch { expected, found } => {
+                write!(f, "expected a {}x{} matrix, found {}x{}", expected.0, expected.1, found.0, found.1)
+            },
+            MtxError::EntryOutOfBounds { entry, shape } => {
+                write!(f, "entry ({}, {}) is out of bounds for a {}x{} matrix", entry.0, entry.1, shape.0, shape.1)
+            }
+        }
+    }
+}
+
+impl error::Error for MtxError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        match self {
+            MtxError::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<io::Error> for MtxError
+{
+    fn from(error: io::Error) -> Self
+    {
+        MtxError::Io(error)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Format
+{
+    /// Sparse `row column value` lines.
+    Coordinate,
+
+    /// Dense values in column-major order.
+    Array
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Field
+{
+    Real,
+    Complex,
+    Integer,
+
+    /// Positions only, read as ones.
+    Pattern
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symmetry
+{
+    General,
+
+    /// Only the lower triangle is stored and `A_ji = A_ij`.
+    Symmetric,
+
+    /// Only the strict lower triangle is stored and `A_ji = -A_ij`.
+    SkewSymmetric,
+
+    /// Only the lower triangle is stored and `A_ji = conj(A_ij)`, so the
+    /// diagonal is real.
+    Hermitian
+}
+
+impl fmt::Display for Format
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.write_str(match self {
+            Format::Coordinate => "coordinate",
+            Format::Array => "array"
+        })
+    }
+}
+
+impl fmt::Display for Field
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.write_str(match self {
+            Field::Real => "real",
+            Field::Complex => "complex",
+            Field::Integer => "integer",
+            Field::Pattern => "pattern"
+        })
+    }
+}
+
+impl fmt::Display for Symmetry
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.write_str(match self {
+            Symmetry::General => "general",
+            Symmetry::Symmetric => "symmetric",
+            Symmetry::SkewSymmetric => "skew-symmetric",
+            Symmetry::Hermitian => "hermitian"
+        })
+    }
+}
+
+/// A scalar that can be stored in a Matrix Market file.
+pub trait Element: Copy + PartialEq + Add<Output=Self> + Neg<Output=Self>
+    + AdditiveIdentity<Output=Self> + MultiplicativeIdentity<Output=Self>
+{
+    /// The field this type is written as.
+    const FIELD: Field;
+
+    /// Whether entries of `field` can be read as this type without loss.
+    fn accepts(field: Field) -> bool;
+
+    /// Parses the value tokens of one entry of a non-pattern `field`.
+    fn parse(field: Field, tokens: &[&str]) -> Option<Self>;
+
+    /// Appends the value tokens of one entry.
+    fn format(self, output: &mut String);
+
+    fn conjugate(self) -> Self;
+}
+
+macro_rules! element
+{
+    (@real $($primitive:ty),*) => {
+        $(
+            impl Element for $primitive
+            {
+                const FIELD: Field = Field::Real;
+
+                fn accepts(field: Field) -> bool
+                {
+                    field != Field::Complex
+                }
+
+                fn parse(_: Field, tokens: &[&str]) -> Option<Self>
+                {
+                    tokens[0].parse().ok()
+                }
+
+                fn format(self, output: &mut String)
+                {
+                    output.push_str(&format!("{:e}", self));
+                }
+
+                fn conjugate(self) -> Self
+                {
+                    self
+                }
+            }
+        )*
+    };
+
+    (@integer $($primitive:ty),*) => {
+        $(
+            impl Element for $primitive
+            {
+                const FIELD: Field = Field::Integer;
+
+                fn accepts(field: Field) -> bool
+                {
+                    field == Field::Integer || field == Field::Pattern
+                }
+
+                fn parse(_: Field, tokens: &[&str]) -> Option<Self>
+                {
+                    tokens[0].parse().ok()
+                }
+
+                fn format(self, output: &mut String)
+                {
+                    output.push_str(&self.to_string());
+                }
+
+                fn conjugate(self) -> Self
+                {
+                    self
+                }
+            }
+        )*
+    };
+
+    (@complex $($primitive:ty),*) => {
+        $(
+            impl Element for Complex<$primitive>
+            {
+                const FIELD: Field = Field::Complex;
+
+                fn accepts(_: Field) -> bool
+                {
+                    true
+                }
+
+                fn parse(field: Field, tokens: &[&str]) -> Option<Self>
+                {
+                    let re: $primitive = tokens[0].parse().ok()?;
+                    let im: $primitive = match field {
+                        Field::Complex => tokens[1].parse().ok()?,
+                        _ => 0.0
+                    };
+                    Some(Complex::new(re, im))
+                }
+
+                fn format(self, output: &mut String)
+                {
+                    output.push_str(&format!("{:e} {:e}", self.re, self.im));
+                }
+
+                fn conjugate(self) -> Self
+                {
+                    self.conj()
+                }
+            }
+        )*
+    };
+}
+
+element!(@real f32, f64);
+element!(@integer i32, i64);
+element!(@complex f32, f64);
+
+/// The banner line of a file.
+#[derive(Clone, Copy, Debug)]
+struct Header
+{
+    format: Format,
+    field: Field,
+    symmetry: Symmetry
+}
+
+/// The non-blank, non-comment lines of a file with their line numbers.
+struct Lines<R>
+{
+    lines: io::Lines<BufReader<R>>,
+    number: usize
+}
+
+impl<R> Lines<R>
+where
+    R: Read
+{
+    fn new(reader: R) -> Self
+    {
+        Lines { lines: BufReader::new(reader).lines(), number: 0 }
+    }
+
+    fn next_raw(&mut self) -> Result<Option<String>, MtxError>
+    {
+        match self.lines.next() {
+            Some(line) => {
+                self.number += 1;
+                Ok(Some(line?))
+            },
+            None => Ok(None)
+        }
+    }
+
+    fn next_data(&mut self) -> Result<Option<String>, MtxError>
+    {
+        while let Some(line) = self.next_raw()? {
+            let trimmed: &str = line.trim();
+            if !trimmed.is_empty() && !trimmed.starts_with('%') {
+                return Ok(Some(line))
+            }
+        }
+        Ok(None)
+    }
+
+    fn error(&self, reason: impl Into<String>) -> MtxError
+    {
+        MtxError::Parse { line: self.number, reason: reason.into() }
+    }
+
+    fn header(&mut self) -> Result<Header, MtxError>
+    {
+        let line: String = self.next_raw()?.unwrap_or_default().to_lowercase();
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        if tokens.first() != Some(&BANNER) {
+            return Err(self.error("missing %%MatrixMarket banner"))
+        }
+        if tokens.len() != 5 {
+            return Err(self.error("expected 'matrix <format> <field> <symmetry>' after the banner"))
+        }
+        if tokens[1] != "matrix" {
+            return Err(self.error(format!("unsupported object '{}'", tokens[1])))
+        }
+        let format: Format = match tokens[2] {
+            "coordinate" => Format::Coordinate,
+            "array" => Format::Array,
+            other => return Err(self.error(format!("unknown format '{}'", other)))
+        };
+        let field: Field = match tokens[3] {
+            "real" | "double" => Field::Real,
+            "complex" => Field::Complex,
+            "integer" => Field::Integer,
+            "pattern" => Field::Pattern,
+            other => return Err(self.error(format!("unknown field '{}'", other)))
+        };
+        let symmetry: Symmetry = match tokens[4] {
+            "general" => Symmetry::General,
+            "symmetric" => Symmetry::Symmetric,
+            "skew-symmetric" => Symmetry::SkewSymmetric,
+            "hermitian" => Symmetry::Hermitian,
+            other => return Err(self.error(format!("unknown symmetry '{}'", other)))
+        };
+
+        match (format, field, symmetry) {
+            (Format::Array, Field::Pattern, _) => Err(self.error("pattern matrices must use the coordinate format")),
+            (_, Field::Pattern, Symmetry::SkewSymmetric) => Err(self.error("pattern matrices cannot be skew-symmetric")),
+            (_, field, Symmetry::Hermitian) if field != Field::Complex => {
+                Err(self.error("only complex matrices can be hermitian"))
+            },
+            _ => Ok(Header { format, field, symmetry })
+        }
+    }
+
+    /// Parses a line of exactly `count` whitespace-separated unsigned integers.
+    fn integers(&mut self, count: usize) -> Result<Vec<usize>, MtxError>
+    {
+        let line: String = self.next_data()?.ok_or_else(|| self.error("missing size line"))?;
+        let sizes: Vec<usize> = line
+            .split_whitespace()
+            .map(|token| token.parse().map_err(|_| self.error(format!("'{}' is not a size", token))))
+            .collect::<Result<_, _>>()?;
+        if sizes.len() != count {
+            return Err(self.error(format!("expected {} sizes, found {}", count, sizes.len())))
+        }
+        Ok(sizes)
+    }
+
+    /// Parses the next entry, checking it has a value for every token `field` needs.
+    fn entry<T>(&mut self, field: Field, indices: usize, declared: usize, read: usize) -> Result<(Vec<usize>, T), MtxError>
+    where
+        T: Element
+    {
+        let line: String = self.next_data()?.ok_or_else(|| {
+            MtxError::Parse { line: self.number + 1, reason: format!("expected {} entries, found {}", declared, read) }
+        })?;
+        let tokens: Vec<&str> = line.split_whitespace().collect();
+        let values: usize = match field {
+            Field::Pattern => 0,
+            Field::Complex => 2,
+            _ => 1
+        };
+        if tokens.len() != indices + values {
+            return Err(self.error(format!("expected {} tokens, found {}", indices + values, tokens.len())))
+        }
+        let position: Vec<usize> = tokens[..indices]
+            .iter()
+            .map(|token| token.parse().map_err(|_| self.error(format!("'{}' is not an index", token))))
+            .collect::<Result<_, _>>()?;
+        let value: T = match field {
+            Field::Pattern => T::multiplicative_identity(),
+            _ => T::parse(field, &tokens[indices..])
+                .ok_or_else(|| self.error(format!("'{}' is not a {} value", tokens[indices..].join(" "), field)))?
+        };
+        Ok((position, value))
+    }
+}
+
+/// The triplets of the full matrix, with symmetric storage expanded.
+fn parse<R, T>(reader: R) -> Result<CooMatrix<T>, MtxError>
+where
+    R: Read,
+    T: Element
+{
+    let mut lines: Lines<R> = Lines::new(reader);
+    let header: Header = lines.header()?;
+    if !T::accepts(header.field) {
+        return Err(MtxError::FieldMismatch { field: header.field, element: std::any::type_name::<T>() })
+    }
+
+    let sizes: Vec<usize> = match header.format {
+        Format::Coordinate => lines.integers(3)?,
+        Format::Array => lines.integers(2)?
+    };
+    let (rows, columns) = (sizes[0], sizes[1]);
+    if header.symmetry != Symmetry::General && rows != columns {
+        return Err(lines.error(format!("a {} matrix must be square, found {}x{}", header.symmetry, rows, columns)))
+    }
+
+    let mut matrix: CooMatrix<T> = CooMatrix::new(rows, columns);
+    let mut push = |lines: &Lines<R>, row: usize, column: usize, value: T| -> Result<(), MtxError> {
+        if header.symmetry == Symmetry::Hermitian && row == column && value != value.conjugate() {
+            return Err(lines.error(format!("diagonal entry ({}, {}) of a hermitian matrix is not real", row + 1, column + 1)))
+        }
+        matrix
+            .push(row, column, value)
+            .map_err(|_| lines.error(format!("entry ({}, {}) is outside the {}x{} matrix", row + 1, column + 1, rows, columns)))?;
+        if row != column {
+            let mirrored: Option<T> = match header.symmetry {
+                Symmetry::General => None,
+                Symmetry::Symmetric => Some(value),
+                Symmetry::SkewSymmetric => Some(-value),
+                Symmetry::Hermitian => Some(value.conjugate())
+            };
+            if let Some(mirrored) = mirrored {
+                matrix
+                    .push(column, row, mirrored)
+                    .map_err(|_| lines.error("mirrored entry is outside the matrix"))?;
+            }
+        }
+        Ok(())
+    };
+
+    match header.format {
+        Format::Coordinate => {
+            let declared: usize = sizes[2];
+            for read in 0..declared {
+                let (position, value) = lines.entry::<T>(header.field, 2, declared, read)?;
+                if position[0] == 0 || position[1] == 0 {
+                    return Err(lines.error("indices count from one"))
+                }
+                push(&lines, position[0] - 1, position[1] - 1, value)?;
+            }
+        },
+        Format::Array => {
+            // The sizes come from the file, so the number of stored entries is
+            // checked before any is read.
+            let declared: usize = rows
+                .checked_mul(columns)
+                .and_then(|size| match header.symmetry {
+                    Symmetry::General => Some(size),
+                    Symmetry::SkewSymmetric => Some((size - rows) / 2),
+                    _ => size.checked_add(rows).map(|size| size / 2)
+                })
+                .ok_or_else(|| lines.error(format!("a {}x{} matrix is too large", rows, columns)))?;
+
+            // Column-major, keeping only the stored part of each column.
+            let symmetry: Symmetry = header.symmetry;
+            let positions = (0..columns).flat_map(|column| {
+                let first: usize = match symmetry {
+                    Symmetry::General => 0,
+                    Symmetry::SkewSymmetric => column + 1,
+                    _ => column
+                };
+                (first..rows).map(move |row| (row, column))
+            });
+            for (read, (row, column)) in positions.enumerate() {
+                let (_, value) = lines.entry::<T>(header.field, 0, declared, read)?;
+                push(&lines, row, column, value)?;
+            }
+        }
+    }
+
+    if lines.next_data()?.is_some() {
+        return Err(lines.error("more entries than declared"))
+    }
+    Ok(matrix)
+}
+
+/// Reads a matrix in either format as coordinate triplets.
+pub fn read_coo<R, T>(reader: R) -> Result<CooMatrix<T>, MtxError>
+where
+    R: Read,
+    T: Element
+{
+    parse(reader)
+}
+
+pub fn read_csr<R, T>(reader: R) -> Result<CsrMatrix<T>, MtxError>
+where
+    R: Read,
+    T: Element
+{
+    Ok(parse(reader)?.to_csr())
+}
+
+pub fn read_csc<R, T>(reader: R) -> Result<CscMatrix<T>, MtxError>
+where
+    R: Read,
+    T: Element
+{
+    Ok(parse(reader)?.to_csc())
+}
+
+/// Reads a matrix of `space` from a file in either format.
+pub fn read_dense<R, S, M, T>(space: &S, reader: R) -> Result<M, MtxError>
+where
+    R: Read,
+    S: FiniteDimensional<Vector=M>,
+    M: Dense<Scalar=T> + FromIterator<T>,
+    T: Element
+{
+    let coo: CooMatrix<T> = parse(reader)?;
+    let mut matrix: M = vec![T::additive_identity(); space.dimension()].into_iter().collect();
+    let (expected, found) = ((matrix.rows(), matrix.columns()), (coo.rows(), coo.columns()));
+    if expected != found {
+        return Err(MtxError::DimensionMismatch { expected, found })
+    }
+    let columns: usize = matrix.columns();
+    let entries: &mut [T] = matrix.as_mut_slice();
+    for &(row, column, value) in coo.entries() {
+        entries[row * columns + column] = entries[row * columns + column] + value;
+    }
+    Ok(matrix)
+}
+
+/// Reads a vector of `space` from a single column or a single row in either format.
+pub fn read_vector<R, S, V, T>(space: &S, reader: R) -> Result<V, MtxError>
+where
+    R: Read,
+    S: FiniteDimensional<Vector=V>,
+    V: FromIterator<T>,
+    T: Element
+{
+    let coo: CooMatrix<T> = parse(reader)?;
+    let dimension: usize = space.dimension();
+    let found: (usize, usize) = (coo.rows(), coo.columns());
+    if found != (dimension, 1) && found != (1, dimension) {
+        return Err(MtxError::DimensionMismatch { expected: (dimension, 1), found })
+    }
+    let mut entries: Vec<T> = vec![T::additive_identity(); dimension];
+    for &(row, column, value) in coo.entries() {
+        // One of the two indices is always zero.
+        entries[row + column] = entries[row + column] + value;
+    }
+    Ok(entries.into_iter().collect())
+}
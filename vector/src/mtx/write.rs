@@ -0,0 +1,114 @@
+use std::io::Write;
+
+use algebra::Strided;
+
+use crate::Dense;
+use crate::sparse::{CscMatrix, CsrMatrix};
+use super::{Element, Field, Format, MtxError, Symmetry};
+
+fn banner<T>(format: Format, symmetry: Symmetry) -> String
+where
+    T: Element
+{
+    format!("%%MatrixMarket matrix {} {} {}\n", format, T::FIELD, symmetry)
+}
+
+/// Writes an array-format file from entries in column-major order.
+fn write_array<W, I, T>(mut writer: W, rows: usize, columns: usize, entries: I) -> Result<(), MtxError>
+where
+    W: Write,
+    I: IntoIterator<Item=T>,
+    T: Element
+{
+    let mut output: String = banner::<T>(Format::Array, Symmetry::General);
+    output.push_str(&format!("{} {}\n", rows, columns));
+    for value in entries {
+        value.format(&mut output);
+        output.push('\n');
+    }
+    writer.write_all(output.as_bytes())?;
+    Ok(())
+}
+
+/// Writes a dense matrix in the array format.
+pub fn write_dense<W, M, T>(writer: W, matrix: &M) -> Result<(), MtxError>
+where
+    W: Write,
+    M: Dense<Scalar=T>,
+    T: Element
+{
+    let (rows, columns) = (matrix.rows(), matrix.columns());
+    let entries: &[T] = matrix.as_slice();
+    let column_major = (0..columns).flat_map(|column| (0..rows).map(move |row| entries[row * columns + column]));
+    write_array(writer, rows, columns, column_major)
+}
+
+/// Writes a vector, which may be a view, as a single column in the array format.
+pub fn write_vector<W, V, T>(writer: W, vector: &V) -> Result<(), MtxError>
+where
+    W: Write,
+    V: Strided<Scalar=T> + ?Sized,
+    T: Element
+{
+    write_array(writer, vector.len(), 1, vector.entries().copied())
+}
+
+/// Writes zero-based `(row, column, value)` triplets in the coordinate format.
+///
+/// With any `symmetry` but `General` only the entries in the stored triangle
+/// are written and the caller is trusted that the rest of the matrix mirrors
+/// them. `Hermitian` is written as `Symmetric` for non-complex elements.
+pub fn write_coordinate<W, I, T>(mut writer: W, rows: usize, columns: usize, symmetry: Symmetry, entries: I) -> Result<(), MtxError>
+where
+    W: Write,
+    I: IntoIterator<Item=(usize, usize, T)>,
+    T: Element
+{
+    if symmetry != Symmetry::General && rows != columns {
+        return Err(MtxError::DimensionMismatch { expected: (rows, rows), found: (rows, columns) })
+    }
+    let symmetry: Symmetry = match symmetry {
+        Symmetry::Hermitian if T::FIELD != Field::Complex => Symmetry::Symmetric,
+        symmetry => symmetry
+    };
+    let mut stored: Vec<(usize, usize, T)> = Vec::new();
+    for (row, column, value) in entries {
+        if row >= rows || column >= columns {
+            return Err(MtxError::EntryOutOfBounds { entry: (row, column), shape: (rows, columns) })
+        }
+        let kept: bool = match symmetry {
+            Symmetry::General => true,
+            Symmetry::SkewSymmetric => row > column,
+            _ => row >= column
+        };
+        if kept {
+            stored.push((row, column, value));
+        }
+    }
+
+    let mut output: String = banner::<T>(Format::Coordinate, symmetry);
+    output.push_str(&format!("{} {} {}\n", rows, columns, stored.len()));
+    for (row, column, value) in stored {
+        output.push_str(&format!("{} {} ", row + 1, column + 1));
+        value.format(&mut output);
+        output.push('\n');
+    }
+    writer.write_all(output.as_bytes())?;
+    Ok(())
+}
+
+pub fn write_csr<W, T>(writer: W, matrix: &CsrMatrix<T>) -> Result<(), MtxError>
+where
+    W: Write,
+    T: Element
+{
+    write_coordinate(writer, matrix.rows(), matrix.columns(), Symmetry::General, matrix.iter().map(|(i, j, &val)| (i, j, val)))
+}
+
+pub fn write_csc<W, T>(writer: W, matrix: &CscMatrix<T>) -> Result<(), MtxError>
+where
+    W: Write,
+    T: Element
+{
+    write_coordinate(writer, matrix.rows(), matrix.columns(), Symmetry::General, matrix.iter().map(|(i, j, &val)| (i, j, val)))
+}
@@ -0,0 +1,422 @@
+//! Reading and writing NumPy `.npy` files and `.npz` archives.
+//!
+//! Arrays are read in either byte order and in C or Fortran order, and are
+//! always handed out in C (row-major) order to match `Dense`. Files are written
+//! little-endian in C order, exactly as `numpy.save` does. Vectors and matrices
+//! are read through their space so that the shape can be checked before the
+//! generated constructors would panic.
+//!
+//! The module is behind the `npy` feature, which pulls in `zip` for archives.
+
+use std::{error, fmt, io::{self, Read, Write}};
+use std::iter::FromIterator;
+
+use algebra::{AdditiveIdentity, Complex, FiniteDimensional, Strided};
+
+use crate::Dense;
+
+mod npz;
+pub use npz::*;
+
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+/// The header is padded so that the data starts on this boundary.
+const ALIGNMENT: usize = 64;
+
+/// The header leaves this many digits for the length of the first axis.
+const GROWTH_AXIS_MAX_DIGITS: usize = 21;
+
+#[derive(Debug)]
+pub enum NpyError
+{
+    Io(io::Error),
+
+    /// The file is not a valid `.npy` file.
+    InvalidHeader(String),
+
+    /// The file is not a valid `.npz` archive.
+    InvalidArchive(String),
+
+    /// The stored dtype is not the element type being read.
+    DtypeMismatch { expected: String, found: String },
+
+    /// The stored array does not have the shape of the vector or matrix being read.
+    DimensionMismatch { expected: Vec<usize>, found: Vec<usize> },
+
+    /// A `.npz` archive has no array with this name.
+    MissingArray(String)
+}
+
+impl fmt::Display for NpyError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            NpyError::Io(error) => write!(f, "{}", error),
+            NpyError::InvalidHeader(reason) => write!(f, "invalid .npy header: {}", reason),
+            NpyError::InvalidArchive(reason) => write!(f, "invalid .npz archive: {}", reason),
+            NpyError::DtypeMismatch { expected, found } => {
+                write!(f, "expected dtype '{}', found '{}'", expected, found)
+            },
+            NpyError::DimensionMismatch { expected, found } => {
+                write!(f, "expected shape {:?}, found {:?}", expected, found)
+            },
+            NpyError::MissingArray(name) => write!(f, "the archive has no array '{}'", name)
+        }
+    }
+}
+
+impl error::Error for NpyError
+{
+    fn source(&self) -> Option<&(dyn error::Error + 'static)>
+    {
+        match self {
+            NpyError::Io(error) => Some(error),
+            _ => None
+        }
+    }
+}
+
+impl From<io::Error> for NpyError
+{
+    fn from(error: io::Error) -> Self
+    {
+        NpyError::Io(error)
+    }
+}
+
+/// A scalar with a fixed NumPy dtype.
+pub trait Element: Copy + AdditiveIdentity<Output=Self>
+{
+    /// The dtype kind: `'f'`, `'i'` or `'c'`.
+    const KIND: char;
+
+    /// The item size in bytes.
+    const SIZE: usize;
+
+    /// Decodes `SIZE` bytes.
+    fn decode(bytes: &[u8], big_endian: bool) -> Self;
+
+    /// Appends the little-endian encoding.
+    fn encode(self, output: &mut Vec<u8>);
+}
+
+macro_rules! element
+{
+    ($($primitive:ty: $kind:expr, $size:expr);*) => {
+        $(
+            impl Element for $primitive
+            {
+                const KIND: char = $kind;
+
+                const SIZE: usize = $size;
+
+                fn decode(bytes: &[u8], big_endian: bool) -> Self
+                {
+                    let mut buffer = [0u8; $size];
+                    buffer.copy_from_slice(bytes);
+                    if big_endian { <$primitive>::from_be_bytes(buffer) } else { <$primitive>::from_le_bytes(buffer) }
+                }
+
+                fn encode(self, output: &mut Vec<u8>)
+                {
+                    output.extend_from_slice(&self.to_le_bytes());
+                }
+            }
+        )*
+    };
+
+    (@complex $($primitive:ty: $size:expr);*) => {
+        $(
+            impl Element for Complex<$primitive>
+            {
+                const KIND: char = 'c';
+
+                const SIZE: usize = 2 * $size;
+
+                fn decode(bytes: &[u8], big_endian: bool) -> Self
+                {
+                    let (re, im) = bytes.split_at($size);
+                    Complex::new(<$primitive>::decode(re, big_endian), <$primitive>::decode(im, big_endian))
+                }
+
+                fn encode(self, output: &mut Vec<u8>)
+                {
+                    self.re.encode(output);
+                    self.im.encode(output);
+                }
+            }
+        )*
+    };
+}
+
+element!(f32: 'f', 4; f64: 'f', 8; i32: 'i', 4; i64: 'i', 8);
+element!(@complex f32: 4; f64: 8);
+
+/// The little-endian dtype string of `T`, such as `<f8`.
+fn descr<T>() -> String
+where
+    T: Element
+{
+    format!("<{}{}", T::KIND, T::SIZE)
+}
+
+/// An array read from a `.npy` file, with its entries in C order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NpyArray<T>
+{
+    shape: Vec<usize>,
+    data: Vec<T>
+}
+
+impl<T> NpyArray<T>
+{
+    pub fn shape(&self) -> &[usize]
+    {
+        &self.shape
+    }
+
+    pub fn data(&self) -> &[T]
+    {
+        &self.data
+    }
+
+    pub fn into_data(self) -> Vec<T>
+    {
+        self.data
+    }
+
+    /// The array as a vector of `space`, which must match its one-dimensional shape.
+    pub fn into_vector<S, V>(self, space: &S) -> Result<V, NpyError>
+    where
+        S: FiniteDimensional<Vector=V>,
+        V: FromIterator<T>
+    {
+        if self.shape != [space.dimension()] {
+            return Err(NpyError::DimensionMismatch { expected: vec![space.dimension()], found: self.shape })
+        }
+        Ok(self.data.into_iter().collect())
+    }
+
+    /// The array as a matrix of `space`, which must match its two-dimensional shape.
+    pub fn into_matrix<S, M>(self, space: &S) -> Result<M, NpyError>
+    where
+        S: FiniteDimensional<Vector=M>,
+        M: Dense<Scalar=T> + FromIterator<T>,
+        T: Element
+    {
+        if self.shape.iter().product::<usize>() != space.dimension() {
+            return Err(NpyError::DimensionMismatch { expected: vec![space.dimension()], found: self.shape })
+        }
+        let matrix: M = self.data.into_iter().collect();
+        let expected: Vec<usize> = vec![matrix.rows(), matrix.columns()];
+        if self.shape != expected {
+            return Err(NpyError::DimensionMismatch { expected, found: self.shape })
+        }
+        Ok(matrix)
+    }
+}
+
+/// Reads an array of `T` from a `.npy` file.
+pub fn read<R, T>(mut reader: R) -> Result<NpyArray<T>, NpyError>
+where
+    R: Read,
+    T: Element
+{
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if &magic[..6] != MAGIC {
+        return Err(NpyError::InvalidHeader("missing magic string".to_string()))
+    }
+    let length: usize = match magic[6] {
+        1 => {
+            let mut bytes = [0u8; 2];
+            reader.read_exact(&mut bytes)?;
+            u16::from_le_bytes(bytes) as usize
+        },
+        2 | 3 => {
+            let mut bytes = [0u8; 4];
+            reader.read_exact(&mut bytes)?;
+            u32::from_le_bytes(bytes) as usize
+        },
+        version => return Err(NpyError::InvalidHeader(format!("unsupported version {}", version)))
+    };
+    let mut header: Vec<u8> = vec![0; length];
+    reader.read_exact(&mut header)?;
+    let header: String = String::from_utf8(header).map_err(|_| NpyError::InvalidHeader("not UTF-8".to_string()))?;
+    let (dtype, fortran_order, shape) = parse_header(&header)?;
+
+    let big_endian: bool = match dtype.chars().next() {
+        Some('<') | Some('|') => false,
+        Some('>') => true,
+        Some('=') => cfg!(target_endian = "big"),
+        _ => return Err(NpyError::InvalidHeader(format!("no byte order in dtype '{}'", dtype)))
+    };
+    if dtype[1..] != descr::<T>()[1..] {
+        return Err(NpyError::DtypeMismatch { expected: descr::<T>(), found: dtype })
+    }
+
+    // The shape comes from the file, so its size is checked before anything is allocated.
+    let length: usize = shape
+        .iter()
+        .try_fold(T::SIZE, |length, &dimension| length.checked_mul(dimension))
+        .ok_or_else(|| NpyError::InvalidHeader(format!("the shape {:?} is too large", shape)))?;
+    let mut bytes: Vec<u8> = Vec::new();
+    reader.take(length as u64).read_to_end(&mut bytes)?;
+    if bytes.len() != length {
+        return Err(NpyError::Io(io::Error::new(io::ErrorKind::UnexpectedEof, "the array data is truncated")))
+    }
+    let data: Vec<T> = bytes.chunks(T::SIZE).map(|chunk| T::decode(chunk, big_endian)).collect();
+    let data: Vec<T> = if fortran_order { c_order(&data, &shape) } else { data };
+    Ok(NpyArray { shape, data })
+}
+
+/// Reads a vector of `space` from a `.npy` file holding a one-dimensional array.
+pub fn read_vector<R, S, V, T>(space: &S, reader: R) -> Result<V, NpyError>
+where
+    R: Read,
+    S: FiniteDimensional<Vector=V>,
+    V: FromIterator<T>,
+    T: Element
+{
+    read(reader)?.into_vector(space)
+}
+
+/// Reads a matrix of `space` from a `.npy` file holding a two-dimensional array.
+pub fn read_matrix<R, S, M, T>(space: &S, reader: R) -> Result<M, NpyError>
+where
+    R: Read,
+    S: FiniteDimensional<Vector=M>,
+    M: Dense<Scalar=T> + FromIterator<T>,
+    T: Element
+{
+    read(reader)?.into_matrix(space)
+}
+
+/// Writes a one-dimensional array, which may be a view.
+pub fn write_vector<W, V, T>(writer: W, vector: &V) -> Result<(), NpyError>
+where
+    W: Write,
+    V: Strided<Scalar=T> + ?Sized,
+    T: Element
+{
+    write(writer, &[vector.len()], vector.entries().copied())
+}
+
+/// Writes a two-dimensional array in C order.
+pub fn write_matrix<W, M, T>(writer: W, matrix: &M) -> Result<(), NpyError>
+where
+    W: Write,
+    M: Dense<Scalar=T>,
+    T: Element
+{
+    write(writer, &[matrix.rows(), matrix.columns()], matrix.as_slice().iter().copied())
+}
+
+/// Writes an array of the given shape from its entries in C order.
+pub fn write<W, I, T>(mut writer: W, shape: &[usize], entries: I) -> Result<(), NpyError>
+where
+    W: Write,
+    I: IntoIterator<Item=T>,
+    T: Element
+{
+    let dimensions: Vec<String> = shape.iter().map(|length| length.to_string()).collect();
+    let shape_literal: String = match dimensions.len() {
+        1 => format!("({},)", dimensions[0]),
+        _ => format!("({})", dimensions.join(", "))
+    };
+    let mut header: String = format!("{{'descr': '{}', 'fortran_order': False, 'shape': {}, }}", descr::<T>(), shape_literal);
+    // NumPy leaves room for the first axis to grow in place before aligning.
+    if let Some(first) = dimensions.first() {
+        header.extend(std::iter::repeat_n(' ', GROWTH_AXIS_MAX_DIGITS.saturating_sub(first.len())));
+    }
+    let unpadded: usize = MAGIC.len() + 2 + 2 + header.len() + 1;
+    header.extend(std::iter::repeat_n(' ', ALIGNMENT - unpadded % ALIGNMENT));
+    header.push('\n');
+    if header.len() > u16::MAX as usize {
+        return Err(NpyError::InvalidHeader("the shape is too long for a version 1.0 header".to_string()))
+    }
+
+    let mut bytes: Vec<u8> = Vec::with_capacity(MAGIC.len() + 4 + header.len());
+    bytes.extend_from_slice(MAGIC);
+    bytes.extend_from_slice(&[1, 0]);
+    bytes.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(header.as_bytes());
+
+    let count: usize = shape.iter().product();
+    let mut written: usize = 0;
+    for entry in entries {
+        entry.encode(&mut bytes);
+        written += 1;
+    }
+    if written != count {
+        return Err(NpyError::DimensionMismatch { expected: shape.to_vec(), found: vec![written] })
+    }
+    writer.write_all(&bytes)?;
+    Ok(())
+}
+
+/// Extracts `descr`, `fortran_order` and `shape` from the Python dictionary literal.
+fn parse_header(header: &str) -> Result<(String, bool, Vec<usize>), NpyError>
+{
+    let invalid = |reason: &str| NpyError::InvalidHeader(reason.to_string());
+    let value = |key: &str| -> Result<&str, NpyError> {
+        let start: usize = header
+            .find(&format!("'{}'", key))
+            .ok_or_else(|| invalid(&format!("missing key '{}'", key)))?;
+        let rest: &str = &header[start + key.len() + 2..];
+        let colon: usize = rest.find(':').ok_or_else(|| invalid("missing ':'"))?;
+        Ok(rest[colon + 1..].trim_start())
+    };
+
+    let descr: &str = value("descr")?;
+    let quote: char = descr.chars().next().filter(|&c| c == '\'' || c == '"').ok_or_else(|| invalid("unquoted descr"))?;
+    let end: usize = descr[1..].find(quote).ok_or_else(|| invalid("unterminated descr"))?;
+    let descr: String = descr[1..=end].to_string();
+
+    let fortran_order: &str = value("fortran_order")?;
+    let fortran_order: bool = if fortran_order.starts_with("True") {
+        true
+    } else if fortran_order.starts_with("False") {
+        false
+    } else {
+        return Err(invalid("fortran_order is not a boolean"))
+    };
+
+    let shape: &str = value("shape")?;
+    if !shape.starts_with('(') {
+        return Err(invalid("shape is not a tuple"))
+    }
+    let end: usize = shape.find(')').ok_or_else(|| invalid("unterminated shape"))?;
+    let shape: Vec<usize> = shape[1..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|dimension| !dimension.is_empty())
+        .map(|dimension| dimension.trim_end_matches('L').parse().map_err(|_| invalid("shape has a non-integer entry")))
+        .collect::<Result<_, _>>()?;
+
+    Ok((descr, fortran_order, shape))
+}
+
+/// Reorders entries stored in Fortran (column-major) order into C order.
+fn c_order<T>(data: &[T], shape: &[usize]) -> Vec<T>
+where
+    T: Copy
+{
+    let mut strides: Vec<usize> = Vec::with_capacity(shape.len());
+    let mut stride: usize = 1;
+    for &length in shape {
+        strides.push(stride);
+        stride *= length;
+    }
+    (0..data.len())
+        .map(|mut idx| {
+            let mut offset: usize = 0;
+            for (&length, &stride) in shape.iter().zip(&strides).rev() {
+                offset += (idx % length) * stride;
+                idx /= length;
+            }
+            data[offset]
+        })
+        .collect()
+}
@@ -0,0 +1,129 @@
+use std::io::{Read, Seek, Write};
+use std::iter::FromIterator;
+
+use algebra::{FiniteDimensional, Strided};
+use zip::{result::ZipError, write::FileOptions, CompressionMethod, ZipArchive, ZipWriter};
+
+use crate::Dense;
+use super::{read, write_matrix, write_vector, Element, NpyArray, NpyError};
+
+fn archive_error(error: ZipError) -> NpyError
+{
+    match error {
+        ZipError::Io(error) => NpyError::Io(error),
+        other => NpyError::InvalidArchive(other.to_string())
+    }
+}
+
+/// The arrays of a `.npz` archive, as written by `numpy.savez` or `numpy.savez_compressed`.
+pub struct NpzReader<R>
+{
+    archive: ZipArchive<R>
+}
+
+impl<R> NpzReader<R>
+where
+    R: Read + Seek
+{
+    pub fn new(reader: R) -> Result<Self, NpyError>
+    {
+        Ok(NpzReader { archive: ZipArchive::new(reader).map_err(archive_error)? })
+    }
+
+    /// The names of the arrays, without the `.npy` extension.
+    pub fn names(&self) -> Vec<String>
+    {
+        self.archive
+            .file_names()
+            .map(|name| name.trim_end_matches(".npy").to_string())
+            .collect()
+    }
+
+    pub fn array<T>(&mut self, name: &str) -> Result<NpyArray<T>, NpyError>
+    where
+        T: Element
+    {
+        let file = match self.archive.by_name(&format!("{}.npy", name)) {
+            Ok(file) => file,
+            Err(ZipError::FileNotFound) => return Err(NpyError::MissingArray(name.to_string())),
+            Err(error) => return Err(archive_error(error))
+        };
+        read(file)
+    }
+
+    /// The array `name` as a vector of `space`.
+    pub fn vector<S, V, T>(&mut self, space: &S, name: &str) -> Result<V, NpyError>
+    where
+        S: FiniteDimensional<Vector=V>,
+        V: FromIterator<T>,
+        T: Element
+    {
+        self.array(name)?.into_vector(space)
+    }
+
+    /// The array `name` as a matrix of `space`.
+    pub fn matrix<S, M, T>(&mut self, space: &S, name: &str) -> Result<M, NpyError>
+    where
+        S: FiniteDimensional<Vector=M>,
+        M: Dense<Scalar=T> + FromIterator<T>,
+        T: Element
+    {
+        self.array(name)?.into_matrix(space)
+    }
+}
+
+/// Writes named arrays into a `.npz` archive.
+pub struct NpzWriter<W>
+where
+    W: Write + Seek
+{
+    archive: ZipWriter<W>,
+    options: FileOptions
+}
+
+impl<W> NpzWriter<W>
+where
+    W: Write + Seek
+{
+    /// An uncompressed archive, like `numpy.savez`.
+    pub fn new(writer: W) -> Self
+    {
+        NpzWriter {
+            archive: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Stored)
+        }
+    }
+
+    /// A deflated archive, like `numpy.savez_compressed`.
+    pub fn compressed(writer: W) -> Self
+    {
+        NpzWriter {
+            archive: ZipWriter::new(writer),
+            options: FileOptions::default().compression_method(CompressionMethod::Deflated)
+        }
+    }
+
+    pub fn add_vector<V, T>(&mut self, name: &str, vector: &V) -> Result<(), NpyError>
+    where
+        V: Strided<Scalar=T> + ?Sized,
+        T: Element
+    {
+        self.archive.start_file(format!("{}.npy", name), self.options).map_err(archive_error)?;
+        write_vector(&mut self.archive, vector)
+    }
+
+    pub fn add_matrix<M, T>(&mut self, name: &str, matrix: &M) -> Result<(), NpyError>
+    where
+        M: Dense<Scalar=T>,
+        T: Element
+    {
+        self.archive.start_file(format!("{}.npy", name), self.options).map_err(archive_error)?;
+        write_matrix(&mut self.archive, matrix)
+    }
+
+    /// Writes the central directory and returns the underlying writer.
+    pub fn finish(mut self) -> Result<W, NpyError>
+    {
+        self.archive.finish().map_err(archive_error)
+    }
+}
@@ -0,0 +1,144 @@
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+
+use algebra::{CodomainVector, ComplexField, DomainVector, FiniteDimensional, LinearOperator, VectorSpace};
+
+/// The Kronecker product `A ⊗ B` acting on the tensor product of the spaces.
+///
+/// Entry `i · dim(B) + j` of a product vector is the coefficient of `eᵢ ⊗ eⱼ`,
+/// so `A` acts on the leading factor. Neither factor is ever expanded, so a
+/// product of `k` operators costs `k` small applications per slice.
+pub struct Kronecker<A, B, D, C>
+{
+    outer: A,
+    inner: B,
+    _spaces: PhantomData<(D, C)>
+}
+
+impl<A, B, D, C> Kronecker<A, B, D, C>
+where
+    A: LinearOperator,
+    B: LinearOperator,
+    A::Domain: FiniteDimensional + Default,
+    A::Codomain: FiniteDimensional + Default,
+    B::Domain: FiniteDimensional + Default,
+    B::Codomain: FiniteDimensional + Default,
+    D: FiniteDimensional + Default,
+    C: FiniteDimensional + Default
+{
+    /// Panics unless `dim(D) = dim(A::Domain) · dim(B::Domain)` and likewise for `C`.
+    pub fn new(outer: A, inner: B) -> Self
+    {
+        let domain = (A::Domain::default().dimension(), B::Domain::default().dimension());
+        let codomain = (A::Codomain::default().dimension(), B::Codomain::default().dimension());
+        assert_eq!(D::default().dimension(), domain.0 * domain.1, "the domain is not the tensor product");
+        assert_eq!(C::default().dimension(), codomain.0 * codomain.1, "the codomain is not the tensor product");
+        Kronecker { outer, inner, _spaces: PhantomData }
+    }
+
+    /// The factors `(A, B)`.
+    pub fn factors(&self) -> (&A, &B)
+    {
+        (&self.outer, &self.inner)
+    }
+}
+
+impl<A, B, D, C> Clone for Kronecker<A, B, D, C>
+where
+    A: Clone,
+    B: Clone
+{
+    fn clone(&self) -> Self
+    {
+        Kronecker { outer: self.outer.clone(), inner: self.inner.clone(), _spaces: PhantomData }
+    }
+}
+
+impl<A, B, D, C, T> LinearOperator for Kronecker<A, B, D, C>
+where
+    T: ComplexField,
+    A: LinearOperator,
+    B: LinearOperator,
+    A::Domain: FiniteDimensional<Scalar=T> + Default,
+    A::Codomain: FiniteDimensional + Default,
+    B::Domain: FiniteDimensional<Scalar=T> + Default,
+    B::Codomain: FiniteDimensional + Default,
+    D: VectorSpace<Scalar=T>,
+    C: VectorSpace<Scalar=T>,
+    DomainVector<A>: FromIterator<T>,
+    CodomainVector<A>: FromIterator<T>,
+    DomainVector<B>: FromIterator<T>,
+    CodomainVector<B>: FromIterator<T>,
+    D::Vector: FromIterator<T>,
+    C::Vector: FromIterator<T>,
+    for<'a> &'a DomainVector<A>: IntoIterator<Item=&'a T>,
+    for<'a> &'a CodomainVector<A>: IntoIterator<Item=&'a T>,
+    for<'a> &'a DomainVector<B>: IntoIterator<Item=&'a T>,
+    for<'a> &'a CodomainVector<B>: IntoIterator<Item=&'a T>,
+    for<'a> &'a D::Vector: IntoIterator<Item=&'a T>,
+    for<'a> &'a C::Vector: IntoIterator<Item=&'a T>
+{
+    type Domain = D;
+
+    type Codomain = C;
+
+    fn apply(&self, x: &D::Vector) -> C::Vector
+    {
+        let input = (A::Domain::default().dimension(), B::Domain::default().dimension());
+        let output = (A::Codomain::default().dimension(), B::Codomain::default().dimension());
+        product(
+            x.into_iter().copied().collect(),
+            input,
+            output,
+            |slice| convert(&self.outer.apply(&slice.iter().copied().collect())),
+            |slice| convert(&self.inner.apply(&slice.iter().copied().collect()))
+        )
+        .into_iter()
+        .collect()
+    }
+
+    /// `(A ⊗ B)ᴴ = Aᴴ ⊗ Bᴴ`.
+    fn adjoint_apply(&self, y: &C::Vector) -> D::Vector
+    {
+        let input = (A::Codomain::default().dimension(), B::Codomain::default().dimension());
+        let output = (A::Domain::default().dimension(), B::Domain::default().dimension());
+        product(
+            y.into_iter().copied().collect(),
+            input,
+            output,
+            |slice| convert(&self.outer.adjoint_apply(&slice.iter().copied().collect())),
+            |slice| convert(&self.inner.adjoint_apply(&slice.iter().copied().collect()))
+        )
+        .into_iter()
+        .collect()
+    }
+}
+
+fn convert<V, T>(vector: &V) -> Vec<T>
+where
+    T: Copy,
+    for<'a> &'a V: IntoIterator<Item=&'a T>
+{
+    vector.into_iter().copied().collect()
+}
+
+/// Applies `B` to every row and then `A` to every column of `x` viewed as a
+/// `input.0 × input.1` row-major matrix.
+fn product<T, F, G>(x: Vec<T>, input: (usize, usize), output: (usize, usize), outer: F, inner: G) -> Vec<T>
+where
+    T: ComplexField,
+    F: Fn(&[T]) -> Vec<T>,
+    G: Fn(&[T]) -> Vec<T>
+{
+    let intermediate: Vec<T> = x.chunks(input.1).flat_map(&inner).collect();
+    let mut result: Vec<T> = vec![T::additive_identity(); output.0 * output.1];
+    let mut column: Vec<T> = Vec::with_capacity(input.0);
+    for k in 0..output.1 {
+        column.clear();
+        column.extend((0..input.0).map(|i| intermediate[i * output.1 + k]));
+        for (l, val) in outer(&column).into_iter().enumerate() {
+            result[l * output.1 + k] = val;
+        }
+    }
+    result
+}
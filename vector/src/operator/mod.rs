@@ -0,0 +1,224 @@
+//! `LinearOperator` implementations for the matrices in this crate.
+//!
+//! A matrix does not know which vector spaces it maps between, so it is bound
+//! to a domain and a codomain by wrapping it in a `MatrixOperator`. The
+//! dimensions are checked once when the operator is built.
+
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+
+use algebra::{AdditiveIdentity, ComplexField, Entrywise, FiniteDimensional, GEMVMut, LinearOperator, VectorSpace};
+
+use crate::Dense;
+use crate::sparse::{CscMatrix, CsrMatrix};
+
+mod kronecker;
+pub use kronecker::*;
+
+/// A dense or sparse matrix acting as an operator `Domain → Codomain`.
+pub struct MatrixOperator<M, D, C>
+{
+    matrix: M,
+    _spaces: PhantomData<(D, C)>
+}
+
+impl<M, D, C> MatrixOperator<M, D, C>
+{
+    pub fn matrix(&self) -> &M
+    {
+        &self.matrix
+    }
+
+    pub fn into_matrix(self) -> M
+    {
+        self.matrix
+    }
+}
+
+impl<M, D, C> Clone for MatrixOperator<M, D, C>
+where
+    M: Clone
+{
+    fn clone(&self) -> Self
+    {
+        MatrixOperator { matrix: self.matrix.clone(), _spaces: PhantomData }
+    }
+}
+
+impl<M, D, C> MatrixOperator<M, D, C>
+where
+    M: Dense,
+    D: FiniteDimensional + Default,
+    C: FiniteDimensional + Default
+{
+    /// Binds a dense matrix; panics unless it is `dim(C) × dim(D)`.
+    pub fn new(matrix: M) -> Self
+    {
+        check(matrix.rows(), matrix.columns(), &D::default(), &C::default());
+        MatrixOperator { matrix, _spaces: PhantomData }
+    }
+}
+
+impl<T, D, C> MatrixOperator<CsrMatrix<T>, D, C>
+where
+    D: FiniteDimensional + Default,
+    C: FiniteDimensional + Default
+{
+    /// Binds a CSR matrix; panics unless it is `dim(C) × dim(D)`.
+    pub fn from_csr(matrix: CsrMatrix<T>) -> Self
+    {
+        check(matrix.rows(), matrix.columns(), &D::default(), &C::default());
+        MatrixOperator { matrix, _spaces: PhantomData }
+    }
+}
+
+impl<T, D, C> MatrixOperator<CscMatrix<T>, D, C>
+where
+    D: FiniteDimensional + Default,
+    C: FiniteDimensional + Default
+{
+    /// Binds a CSC matrix; panics unless it is `dim(C) × dim(D)`.
+    pub fn from_csc(matrix: CscMatrix<T>) -> Self
+    {
+        check(matrix.rows(), matrix.columns(), &D::default(), &C::default());
+        MatrixOperator { matrix, _spaces: PhantomData }
+    }
+}
+
+fn check<D, C>(rows: usize, columns: usize, domain: &D, codomain: &C)
+where
+    D: FiniteDimensional,
+    C: FiniteDimensional
+{
+    assert!(
+        rows == codomain.dimension() && columns == domain.dimension(),
+        "a {}×{} matrix cannot map a space of dimension {} to one of dimension {}",
+        rows, columns, domain.dimension(), codomain.dimension()
+    );
+}
+
+impl<M, D, C, T> LinearOperator for MatrixOperator<M, D, C>
+where
+    M: Dense<Scalar=T>,
+    T: ComplexField,
+    D: VectorSpace<Scalar=T>,
+    C: VectorSpace<Scalar=T>,
+    D::Vector: FromIterator<T> + Index<usize, Output=T> + Entrywise,
+    C::Vector: FromIterator<T> + IndexMut<usize, Output=T> + Entrywise
+{
+    type Domain = D;
+
+    type Codomain = C;
+
+    fn apply(&self, x: &D::Vector) -> C::Vector
+    {
+        assert_eq!(x.length(), self.matrix.columns(), "x needs an entry for every column");
+        self.matrix
+            .as_slice()
+            .chunks(self.matrix.columns())
+            .map(|row| row.iter().enumerate().fold(T::additive_identity(), |acc, (j, &val)| acc + val * x[j]))
+            .collect()
+    }
+
+    fn apply_mut(&self, x: &D::Vector, y: &mut C::Vector)
+    {
+        assert_eq!(x.length(), self.matrix.columns(), "x needs an entry for every column");
+        assert_eq!(y.length(), self.matrix.rows(), "y needs an entry for every row");
+        for (i, row) in self.matrix.as_slice().chunks(self.matrix.columns()).enumerate() {
+            y[i] = row.iter().enumerate().fold(T::additive_identity(), |acc, (j, &val)| acc + val * x[j]);
+        }
+    }
+
+    fn adjoint_apply(&self, y: &C::Vector) -> D::Vector
+    {
+        let (rows, columns) = (self.matrix.rows(), self.matrix.columns());
+        let entries: &[T] = self.matrix.as_slice();
+        (0..columns)
+            .map(|j| (0..rows).fold(T::additive_identity(), |acc, i| acc + entries[i * columns + j].conjugate() * y[i]))
+            .collect()
+    }
+}
+
+impl<T, D, C> LinearOperator for MatrixOperator<CsrMatrix<T>, D, C>
+where
+    T: ComplexField,
+    D: VectorSpace<Scalar=T>,
+    C: VectorSpace<Scalar=T>,
+    D::Vector: FromIterator<T> + Index<usize, Output=T> + Entrywise,
+    C::Vector: FromIterator<T> + IndexMut<usize, Output=T> + Entrywise
+{
+    type Domain = D;
+
+    type Codomain = C;
+
+    fn apply(&self, x: &D::Vector) -> C::Vector
+    {
+        let mut y: C::Vector = zeros(self.matrix.rows());
+        self.apply_mut(x, &mut y);
+        y
+    }
+
+    fn apply_mut(&self, x: &D::Vector, y: &mut C::Vector)
+    {
+        self.matrix.gemv_mut(&T::multiplicative_identity(), x, &T::additive_identity(), y);
+    }
+
+    fn adjoint_apply(&self, y: &C::Vector) -> D::Vector
+    {
+        adjoint(self.matrix.columns(), self.matrix.iter(), y)
+    }
+}
+
+impl<T, D, C> LinearOperator for MatrixOperator<CscMatrix<T>, D, C>
+where
+    T: ComplexField,
+    D: VectorSpace<Scalar=T>,
+    C: VectorSpace<Scalar=T>,
+    D::Vector: FromIterator<T> + Index<usize, Output=T> + Entrywise,
+    C::Vector: FromIterator<T> + IndexMut<usize, Output=T> + Entrywise
+{
+    type Domain = D;
+
+    type Codomain = C;
+
+    fn apply(&self, x: &D::Vector) -> C::Vector
+    {
+        let mut y: C::Vector = zeros(self.matrix.rows());
+        self.apply_mut(x, &mut y);
+        y
+    }
+
+    fn apply_mut(&self, x: &D::Vector, y: &mut C::Vector)
+    {
+        self.matrix.gemv_mut(&T::multiplicative_identity(), x, &T::additive_identity(), y);
+    }
+
+    fn adjoint_apply(&self, y: &C::Vector) -> D::Vector
+    {
+        adjoint(self.matrix.columns(), self.matrix.iter(), y)
+    }
+}
+
+fn zeros<V, T>(dimension: usize) -> V
+where
+    V: FromIterator<T>,
+    T: AdditiveIdentity<Output=T>
+{
+    (0..dimension).map(|_| T::additive_identity()).collect()
+}
+
+/// Scatters `Aᴴy` from the stored `(row, column, value)` triplets of `A`.
+fn adjoint<'a, I, T, X, Y>(columns: usize, triplets: I, y: &Y) -> X
+where
+    I: Iterator<Item=(usize, usize, &'a T)>,
+    T: ComplexField + 'a,
+    X: FromIterator<T>,
+    Y: Index<usize, Output=T>
+{
+    let mut output: Vec<T> = vec![T::additive_identity(); columns];
+    for (i, j, val) in triplets {
+        output[j] += val.conjugate() * y[i];
+    }
+    output.into_iter().collect()
+}
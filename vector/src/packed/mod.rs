@@ -0,0 +1,447 @@
+//! Bit-packed vectors over `GF(2)`.
+//!
+//! `vspace!` generates these with `packed: Gf2` in place of `using:`. Entry
+//! `k` is bit `k % 64` of word `k / 64`, so addition is a word-wise XOR and the
+//! dot product is the parity of the popcount of a word-wise AND.
+
+use algebra::Gf2;
+
+/// The entries of a packed vector, as references to the constants of `Gf2`.
+#[derive(Clone, Debug)]
+pub struct Bits<'a>
+{
+    words: &'a [u64],
+    idx: usize,
+    dimension: usize
+}
+
+impl<'a> Bits<'a>
+{
+    pub fn new(words: &'a [u64], dimension: usize) -> Self
+    {
+        assert!(dimension <= 64 * words.len(), "the words hold fewer than {} bits", dimension);
+        Bits { words, idx: 0, dimension }
+    }
+}
+
+/// Entry `idx` of a packed vector.
+#[inline]
+pub fn bit(words: &[u64], idx: usize) -> &'static Gf2
+{
+    if (words[idx / 64] >> (idx % 64)) & 1 == 1 { &Gf2::ONE } else { &Gf2::ZERO }
+}
+
+impl<'a> Iterator for Bits<'a>
+{
+    type Item = &'a Gf2;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.idx == self.dimension {
+            return None
+        }
+        self.idx += 1;
+        Some(bit(self.words, self.idx - 1))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let remaining: usize = self.dimension - self.idx;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Bits<'_> { }
+
+#[macro_export]
+macro_rules! packed_base {
+    ($length:expr, $name:ident, $space:ident) => {
+        const WORDS: usize = ($length as usize).div_ceil(64);
+
+        /// A vector over `GF(2)` packed 64 entries to a word; the bits past
+        /// the dimension are always clear.
+        #[derive(Clone, Copy, PartialEq, Eq, Hash)]
+        pub struct $name([u64; WORDS]);
+
+        impl $name
+        {
+            pub fn zeros() -> Self
+            {
+                $name([0; WORDS])
+            }
+
+            /// The packed words, entry `k` being bit `k % 64` of word `k / 64`.
+            pub fn words(&self) -> &[u64]
+            {
+                &self.0[..]
+            }
+
+            pub fn get(&self, idx: usize) -> Gf2
+            {
+                self[idx]
+            }
+
+            pub fn set(&mut self, idx: usize, value: Gf2)
+            {
+                assert!(idx < $length, "index {} out of bounds for dimension {}", idx, $length);
+                let mask: u64 = 1 << (idx % 64);
+                if value == Gf2::ONE {
+                    self.0[idx / 64] |= mask;
+                } else {
+                    self.0[idx / 64] &= !mask;
+                }
+            }
+
+            pub fn flip(&mut self, idx: usize)
+            {
+                assert!(idx < $length, "index {} out of bounds for dimension {}", idx, $length);
+                self.0[idx / 64] ^= 1 << (idx % 64);
+            }
+
+            /// The Hamming weight, the number of non-zero entries.
+            pub fn weight(&self) -> usize
+            {
+                self.0.iter().map(|word| word.count_ones() as usize).sum()
+            }
+        }
+
+        impl<'a> IntoIterator for &'a $name
+        {
+            type Item = &'a Gf2;
+            type IntoIter = packed::Bits<'a>;
+
+            fn into_iter(self) -> Self::IntoIter
+            {
+                packed::Bits::new(&self.0[..], $length)
+            }
+        }
+
+        impl Entrywise for $name
+        {
+            type Scalar = Gf2;
+
+            type Iter<'a> = packed::Bits<'a>;
+
+            fn length(&self) -> usize
+            {
+                $length
+            }
+
+            fn iter_entries(&self) -> Self::Iter<'_>
+            {
+                self.into_iter()
+            }
+
+            fn packed_words(&self) -> Option<&[u64]>
+            {
+                Some(&self.0[..])
+            }
+        }
+
+        impl EntrywiseMut for $name
+        {
+            fn update_entries<F>(&mut self, mut f: F)
+            where
+                F: FnMut(&mut Gf2)
+            {
+                for idx in 0..$length {
+                    let mut value: Gf2 = self.get(idx);
+                    f(&mut value);
+                    self.set(idx, value);
+                }
+            }
+
+            fn packed_words_mut(&mut self) -> Option<&mut [u64]>
+            {
+                Some(&mut self.0[..])
+            }
+        }
+
+        impl FromIterator<Gf2> for $name
+        {
+            fn from_iter<I>(iterator: I) -> Self
+            where
+                I: IntoIterator<Item=Gf2>
+            {
+                let mut buf: Self = Self::zeros();
+                let mut len: usize = 0;
+                for (idx, value) in iterator.into_iter().enumerate() {
+                    assert!(idx < $length);
+                    buf.set(idx, value);
+                    len += 1;
+                }
+                assert!(len == $length);
+                buf
+            }
+        }
+
+        impl<'a> FromIterator<&'a Gf2> for $name
+        {
+            fn from_iter<I>(iterator: I) -> Self
+            where
+                I: IntoIterator<Item=&'a Gf2>
+            {
+                iterator.into_iter().copied().collect()
+            }
+        }
+
+        impl Index<usize> for $name
+        {
+            type Output = Gf2;
+
+            fn index(&self, idx: usize) -> &Self::Output
+            {
+                assert!(idx < $length, "index {} out of bounds for dimension {}", idx, $length);
+                packed::bit(&self.0[..], idx)
+            }
+        }
+
+        impl Debug for $name
+        {
+            /// The entries as a string of bits, first entry first.
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+            {
+                for value in self {
+                    write!(f, "{}", value)?;
+                }
+                Ok(())
+            }
+        }
+
+        pub struct $space;
+
+        impl $space
+        {
+            #[inline]
+            pub fn new() -> Self
+            {
+                $space
+            }
+        }
+
+        impl Default for $space
+        {
+            fn default() -> Self
+            {
+                Self::new()
+            }
+        }
+
+        impl VectorSpace for $space
+        {
+            type Scalar = Gf2;
+
+            type Vector = $name;
+        }
+
+        impl FiniteDimensional for $space
+        {
+            #[inline]
+            fn dimension(&self) -> usize
+            {
+                $length
+            }
+        }
+
+        impl VAdd for $space
+        {
+            type Vector = $name;
+
+            fn vadd(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector
+            {
+                let mut buf: Self::Vector = *lhs;
+                self.vadd_mut(&mut buf, rhs);
+                buf
+            }
+        }
+
+        impl VAddMut for $space
+        {
+            type Vector = $name;
+
+            fn vadd_mut(&self, lhs: &mut Self::Vector, rhs: &Self::Vector)
+            {
+                lhs.0
+                    .iter_mut()
+                    .zip(rhs.0.iter())
+                    .for_each(|(l, r)| *l ^= r);
+            }
+        }
+
+        impl VScale for $space
+        {
+            type Vector = $name;
+
+            type Scalar = Gf2;
+
+            fn vscale(&self, vector: &Self::Vector, scalar: &Self::Scalar) -> Self::Vector
+            {
+                let mut buf: Self::Vector = *vector;
+                self.vscale_mut(&mut buf, scalar);
+                buf
+            }
+        }
+
+        impl VScaleMut for $space
+        {
+            type Vector = $name;
+
+            type Scalar = Gf2;
+
+            fn vscale_mut(&self, vector: &mut Self::Vector, scalar: &Self::Scalar)
+            {
+                if *scalar == Gf2::ZERO {
+                    *vector = $name::zeros();
+                }
+            }
+        }
+
+        impl VAXPY for $space
+        {
+            type Vector = $name;
+
+            type Scalar = Gf2;
+
+            fn vaxpy<X, Y>(&self, alpha: &Self::Scalar, x: &X, y: &Y) -> Self::Vector
+            where
+                X: Entrywise<Scalar=Gf2> + ?Sized,
+                Y: Entrywise<Scalar=Gf2> + ?Sized
+            {
+                let mut output: Self::Vector = match y.packed_words() {
+                    Some(words) if y.length() == $length => {
+                        let mut buf: Self::Vector = $name::zeros();
+                        buf.0.copy_from_slice(words);
+                        buf
+                    },
+                    _ => y.iter_entries().copied().collect()
+                };
+                self.vaxpy_mut(alpha, x, &mut output);
+                output
+            }
+        }
+
+        impl VAXPYMut for $space
+        {
+            type Vector = $name;
+
+            type Scalar = Gf2;
+
+            fn vaxpy_mut<X, Y>(&self, alpha: &Self::Scalar, x: &X, y: &mut Y)
+            where
+                X: Entrywise<Scalar=Gf2> + ?Sized,
+                Y: EntrywiseMut<Scalar=Gf2> + ?Sized
+            {
+                assert_eq!(x.length(), y.length());
+                if *alpha == Gf2::ZERO {
+                    return
+                }
+                if let (Some(words), Some(output)) = (x.packed_words(), y.packed_words_mut()) {
+                    output
+                        .iter_mut()
+                        .zip(words.iter())
+                        .for_each(|(l, r)| *l ^= r);
+                    return
+                }
+                let mut entries = x.iter_entries();
+                y.update_entries(|l| {
+                    if let Some(r) = entries.next() {
+                        *l += *r;
+                    }
+                });
+            }
+        }
+
+        impl DotVMut for $space
+        {
+            type Vector = $name;
+
+            type Scalar = Gf2;
+
+            fn dotv_mut<X, Y>(&self, x: &X, y: &Y, output: &mut Self::Scalar)
+            where
+                X: Entrywise<Scalar=Gf2> + ?Sized,
+                Y: Entrywise<Scalar=Gf2> + ?Sized
+            {
+                *output += DotV::dotv(self, x, y);
+            }
+        }
+
+        impl DotV for $space
+        {
+            type Vector = $name;
+
+            type Scalar = Gf2;
+
+            fn dotv<X, Y>(&self, x: &X, y: &Y) -> Self::Scalar
+            where
+                X: Entrywise<Scalar=Gf2> + ?Sized,
+                Y: Entrywise<Scalar=Gf2> + ?Sized
+            {
+                assert_eq!(x.length(), y.length());
+                if let (Some(l), Some(r)) = (x.packed_words(), y.packed_words()) {
+                    let parity: u32 = l
+                        .iter()
+                        .zip(r.iter())
+                        .fold(0, |acc, (l, r)| acc ^ (l & r).count_ones()) & 1;
+                    return Gf2::new(parity as u64)
+                }
+                let ones: usize = x
+                    .iter_entries()
+                    .zip(y.iter_entries())
+                    .filter(|&(l, r)| *l == Gf2::ONE && *r == Gf2::ONE)
+                    .count();
+                Gf2::new(ones as u64)
+            }
+        }
+
+        impl VAdditiveInverse for $space
+        {
+            type Vector = $name;
+
+            /// Every vector is its own inverse in characteristic two.
+            fn additive_inv(&self, vector: &Self::Vector) -> Self::Vector
+            {
+                *vector
+            }
+        }
+
+        impl VAdditiveInverseMut for $space
+        {
+            type Vector = $name;
+
+            fn additive_inv_mut(&self, _vector: &mut Self::Vector)
+            {
+            }
+        }
+
+        impl VAdditiveIdentity for $space
+        {
+            type Output = $name;
+
+            fn additive_identity(&self) -> Self::Output
+            {
+                $name::zeros()
+            }
+        }
+
+        impl VMultiplicativeIdentity for $space
+        {
+            type Output = Gf2;
+
+            fn multiplicative_identity(&self) -> Self::Output
+            {
+                Gf2::ONE
+            }
+        }
+
+        impl VPartialEq for $space
+        {
+            type Vector = $name;
+
+            fn eq(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> bool
+            {
+                lhs == rhs
+            }
+        }
+    };
+}
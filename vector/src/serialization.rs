@@ -0,0 +1,94 @@
+/// Serialises generated types as a flat sequence of entries.
+///
+/// The `serde` feature is checked here, in the `vector` crate, because a
+/// `cfg` inside an exported macro would test the features of the crate that
+/// invokes it.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! serde_base {
+    ($length:expr, $name:ident, $inner:ty, $T:ident) => {
+        impl<$T> vector::serde::Serialize for $name<$T>
+        where
+            $T: vector::serde::Serialize
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: vector::serde::Serializer
+            {
+                serializer.collect_seq(self.0.iter())
+            }
+        }
+
+        impl<'de, $T> vector::serde::Deserialize<'de> for $name<$T>
+        where
+            $T: vector::serde::Deserialize<'de>,
+            $inner: TryFrom<Vec<$T>>
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: vector::serde::Deserializer<'de>
+            {
+                use vector::serde::de::Error;
+
+                let entries: Vec<$T> = vector::serde::Deserialize::deserialize(deserializer)?;
+                if entries.len() != $length {
+                    let expected: String = format!("a sequence of {} entries", $length);
+                    return Err(D::Error::invalid_length(entries.len(), &expected.as_str()))
+                }
+                match <$inner>::try_from(entries) {
+                    Ok(inner) => Ok($name(inner)),
+                    Err(_) => Err(D::Error::custom("the entries do not fit the storage"))
+                }
+            }
+        }
+    };
+
+    (@space $space:ident, $T:ident) => {
+        impl<$T> vector::serde::Serialize for $space<$T>
+        {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: vector::serde::Serializer
+            {
+                serializer.serialize_unit_struct(stringify!($space))
+            }
+        }
+
+        impl<'de, $T> vector::serde::Deserialize<'de> for $space<$T>
+        {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: vector::serde::Deserializer<'de>
+            {
+                struct Visitor<$T>(PhantomData<$T>);
+
+                impl<'de, $T> vector::serde::de::Visitor<'de> for Visitor<$T>
+                {
+                    type Value = $space<$T>;
+
+                    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+                    {
+                        write!(f, "the unit struct {}", stringify!($space))
+                    }
+
+                    fn visit_unit<E>(self) -> Result<Self::Value, E>
+                    {
+                        Ok($space::new())
+                    }
+                }
+
+                deserializer.deserialize_unit_struct(stringify!($space), Visitor(PhantomData))
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! serde_base {
+    ($length:expr, $name:ident, $inner:ty, $T:ident) => { };
+
+    (@space $space:ident, $T:ident) => { };
+}
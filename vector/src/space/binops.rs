@@ -3,7 +3,7 @@ macro_rules! BinOps {
     (@VAdd $name:ident, $space:ident) => {
         impl<T> VAdd for $space<T>
         where
-            for <'a> T: Copy + AddAssign<&'a T>,
+            T: Magma,
         {
             type Vector = $name<T>;
             
@@ -19,7 +19,7 @@ macro_rules! BinOps {
     (@VAddMut $name:ident, $space:ident) => {
         impl<T> VAddMut for $space<T>
         where
-            for <'a> T: Copy + AddAssign<&'a T>,
+            T: Magma,
         {
             type Vector = $name<T>;
             
@@ -37,7 +37,7 @@ macro_rules! BinOps {
     (@VScale $name:ident, $space:ident) => {
         impl<T> VScale for $space<T>
         where
-            for <'a> T: Copy + MulAssign<&'a T>,
+            T: Semiring,
         {
             type Vector = $name<T>;
 
@@ -55,7 +55,7 @@ macro_rules! BinOps {
     (@VScaleMut $name:ident, $space:ident) => {
         impl<T> VScaleMut for $space<T>
         where
-            for <'a> T: Copy + MulAssign<&'a T>,
+            T: Semiring,
         {
             type Vector = $name<T>;
 
@@ -3,16 +3,19 @@ macro_rules! BlasOps {
     (@VAXPY $name:ident, $space:ident) => {
         impl<T> VAXPY for $space<T>
         where
-            T: Copy + AddAssign<T>,
-            for <'a> &'a T: Mul<&'a T, Output=T>,
+            T: Semiring,
+            $name<T>: FromIterator<T>
         {
             type Vector = $name<T>;
 
             type Scalar = T;
 
-            fn vaxpy(&self, alpha: &Self::Scalar, x: &Self::Vector, y: &Self::Vector) -> Self::Vector
+            fn vaxpy<X, Y>(&self, alpha: &Self::Scalar, x: &X, y: &Y) -> Self::Vector
+            where
+                X: Entrywise<Scalar=T> + ?Sized,
+                Y: Entrywise<Scalar=T> + ?Sized
             {
-                let mut output: Self::Vector = y.clone();
+                let mut output: Self::Vector = y.iter_entries().cloned().collect();
                 self.vaxpy_mut(alpha, x, &mut output);
                 output
             }
@@ -22,22 +25,26 @@ macro_rules! BlasOps {
     (@VAXPYMut $name:ident, $space:ident) => {
         impl<T> VAXPYMut for $space<T>
         where
-            T: AddAssign<T>,
-            for <'a> &'a T: Mul<&'a T, Output=T>,
+            T: Semiring
         {
             type Vector = $name<T>;
 
             type Scalar = T;
 
-            fn vaxpy_mut(&self, a: &Self::Scalar, x: &Self::Vector, y: &mut Self::Vector)
+            fn vaxpy_mut<X, Y>(&self, a: &Self::Scalar, x: &X, y: &mut Y)
+            where
+                X: Entrywise<Scalar=T> + ?Sized,
+                Y: EntrywiseMut<Scalar=T> + ?Sized
             {
-                for (l,r) in y
-                    .0
-                    .iter_mut()
-                    .zip(x)
-                {
-                    l.add_assign( r * a )
-                }
+                assert_eq!(x.length(), y.length());
+                let mut entries = x.iter_entries();
+                y.update_entries(|l| {
+                    if let Some(r) = entries.next() {
+                        let mut product: T = r.clone();
+                        product.mul_assign(a);
+                        l.add_assign(product)
+                    }
+                });
             }
         }
     };
@@ -45,19 +52,26 @@ macro_rules! BlasOps {
     (@DotVMut $name:ident, $space:ident) => {
         impl<T> DotVMut for $space<T>
         where
-            T: AddAssign<T>,
-            for <'a> &'a T: Mul<&'a T,Output=T>
+            T: Semiring
         {
             type Vector = $name<T>;
 
             type Scalar = T;
         
-            fn dotv_mut(&self, x: &Self::Vector, y: &Self::Vector, output: &mut Self::Scalar)
+            fn dotv_mut<X, Y>(&self, x: &X, y: &Y, output: &mut Self::Scalar)
+            where
+                X: Entrywise<Scalar=T> + ?Sized,
+                Y: Entrywise<Scalar=T> + ?Sized
             {
+                assert_eq!(x.length(), y.length());
                 x
-                    .into_iter()
-                    .zip(y) 
-                    .for_each(|(xi,yi)| output.add_assign(xi * yi));
+                    .iter_entries()
+                    .zip(y.iter_entries())
+                    .for_each(|(xi,yi)| {
+                        let mut product: T = xi.clone();
+                        product.mul_assign(yi);
+                        output.add_assign(product)
+                    });
             }
         }
     };
@@ -65,20 +79,23 @@ macro_rules! BlasOps {
     (@DotV $name:ident, $space:ident) => {
         impl<T> DotV for $space<T>
         where
-            T: Add<T,Output=T> + AdditiveIdentity<Output=T>,
-            for <'a> &'a T: Mul<&'a T,Output=T>,
+            T: Semiring
         {
             type Vector = $name<T>;
 
             type Scalar = T;
         
-            fn dotv(&self, x: &Self::Vector, y: &Self::Vector) -> Self::Scalar
+            fn dotv<X, Y>(&self, x: &X, y: &Y) -> Self::Scalar
+            where
+                X: Entrywise<Scalar=T> + ?Sized,
+                Y: Entrywise<Scalar=T> + ?Sized
             {
+                assert_eq!(x.length(), y.length());
                 let acc: Self::Scalar = T::additive_identity();
                 x
-                    .into_iter()
-                    .zip(y)
-                    .map(|(xi,yi)| xi * yi)
+                    .iter_entries()
+                    .zip(y.iter_entries())
+                    .map(|(xi,yi)| xi.clone() * yi.clone())
                     .fold(acc, |acc, val| acc + val)          
             }
         }
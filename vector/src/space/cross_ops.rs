@@ -0,0 +1,43 @@
+/// Implements `VCross` for spaces declared with `dimension: 3`; no other
+/// dimension has a cross product.
+///
+/// ```compile_fail
+/// use algebra::*;
+/// use vector::vspace;
+///
+/// vspace! {
+///     PlaneSpace {
+///         vector: Plane,
+///         dimension: 2
+///     }
+/// }
+///
+/// let space: PlaneSpace<i64> = PlaneSpace::new();
+/// let x: Plane<i64> = vec![1, 0].into_iter().collect();
+/// space.cross(&x, &x);
+/// ```
+#[macro_export]
+macro_rules! CrossOps {
+    (@VCross 3, $name:ident, $space:ident) => {
+        impl<T> VCross for $space<T>
+        where
+            T: Ring,
+            $name<T>: FromIterator<T>
+        {
+            type Vector = $name<T>;
+
+            fn cross(&self, lhs: &Self::Vector, rhs: &Self::Vector) -> Self::Vector
+            {
+                let (a, b): (&Self::Vector, &Self::Vector) = (lhs, rhs);
+                let component = |i: usize, j: usize| -> T {
+                    a[i].clone() * b[j].clone() - a[j].clone() * b[i].clone()
+                };
+                let entries: [T; 3] = [component(1, 2), component(2, 0), component(0, 1)];
+                IntoIterator::into_iter(entries).collect()
+            }
+        }
+    };
+
+    (@VCross $length:tt, $name:ident, $space:ident) => {
+    };
+}
@@ -2,3 +2,4 @@ mod binops;
 mod uniops;
 mod structural;
 mod blas_ops;
+mod cross_ops;
@@ -16,13 +16,34 @@ macro_rules! vectorspace {
             }
         }
 
+        serde_base!(@space $space, $T);
+
+        impl<$T> Default for $space<$T>
+        {
+            fn default() -> Self
+            {
+                Self::new()
+            }
+        }
+
         impl<$T> VectorSpace for $space<$T>
         {
             type Scalar = $T;
 
-            type Vector = $space<$T>;
+            type Vector = $name<$T>;
         }
 
+        impl<$T> FiniteDimensional for $space<$T>
+        {
+            #[inline]
+            fn dimension(&self) -> usize
+            {
+                $length
+            }
+        }
+
+        impl<$T> Dimension<{ $length }> for $space<$T> {}
+
         impl<$T> VPartialEq for $space<$T>
         where
             $T: PartialEq
@@ -42,5 +63,21 @@ macro_rules! vectorspace {
                 return true
             }
         }
+
+        impl<$T> VApproxEq for $space<$T>
+        where
+            $T: ApproxEq
+        {
+            type Vector = $name<$T>;
+
+            type Epsilon = $T::Epsilon;
+
+            fn mismatch(&self, lhs: &Self::Vector, rhs: &Self::Vector, tolerance: Tolerance<Self::Epsilon>) -> Option<usize>
+            {
+                lhs.into_iter()
+                    .zip(rhs.into_iter())
+                    .position(|(l, r)| !l.approx_eq(r, tolerance))
+            }
+        }
     };
-} 
+}
@@ -3,8 +3,7 @@ macro_rules! UniOps {
     (@VAdditiveInverse $name:ident, $space:ident) => {
         impl<T> VAdditiveInverse for $space<T>
         where
-            T: Copy,
-            for <'a> &'a T: Neg<Output=T>
+            T: Group
         {
             type Vector = $name<T>;
 
@@ -20,8 +19,7 @@ macro_rules! UniOps {
     (@VAdditiveInverseMut $name:ident, $space:ident) => {
         impl<T> VAdditiveInverseMut for $space<T>
         where
-            T: Copy,
-            for <'a> &'a T: Neg<Output=T>
+            T: Group
         {
             type Vector = $name<T>;
 
@@ -30,7 +28,7 @@ macro_rules! UniOps {
                 vector
                     .0
                     .iter_mut()
-                    .for_each(|val| *val = (*val).neg() );
+                    .for_each(|val| *val = val.clone().neg() );
             }
         }
     };
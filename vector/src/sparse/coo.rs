@@ -0,0 +1,82 @@
+use std::ops::Add;
+
+use super::{Compressed, CscMatrix, CsrMatrix, SparseError};
+
+/// A sparse matrix in coordinate form, for assembling entries in any order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CooMatrix<T>
+{
+    rows: usize,
+    columns: usize,
+    entries: Vec<(usize, usize, T)>
+}
+
+impl<T> CooMatrix<T>
+{
+    pub fn new(rows: usize, columns: usize) -> Self
+    {
+        CooMatrix { rows, columns, entries: Vec::new() }
+    }
+
+    /// Collects `(row, column, value)` triplets, checking each against the shape.
+    pub fn from_triplets<I>(rows: usize, columns: usize, triplets: I) -> Result<Self, SparseError>
+    where
+        I: IntoIterator<Item=(usize, usize, T)>
+    {
+        let mut matrix: Self = CooMatrix::new(rows, columns);
+        for (row, column, value) in triplets {
+            matrix.push(row, column, value)?;
+        }
+        Ok(matrix)
+    }
+
+    /// Appends an entry; entries at the same position are summed on compression.
+    pub fn push(&mut self, row: usize, column: usize, value: T) -> Result<(), SparseError>
+    {
+        if row >= self.rows || column >= self.columns {
+            return Err(SparseError::OutOfBounds { row, column })
+        }
+        self.entries.push((row, column, value));
+        Ok(())
+    }
+
+    pub fn rows(&self) -> usize
+    {
+        self.rows
+    }
+
+    pub fn columns(&self) -> usize
+    {
+        self.columns
+    }
+
+    /// The number of stored triplets, counting duplicates.
+    pub fn nnz(&self) -> usize
+    {
+        self.entries.len()
+    }
+
+    pub fn entries(&self) -> &[(usize, usize, T)]
+    {
+        &self.entries
+    }
+}
+
+impl<T> CooMatrix<T>
+where
+    T: Copy + Add<T, Output=T>
+{
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    {
+        CsrMatrix::from_compressed(Compressed::from_triplets(self.rows, self.columns, self.entries.clone()))
+    }
+
+    pub fn to_csc(&self) -> CscMatrix<T>
+    {
+        let transposed: Vec<(usize, usize, T)> = self.entries
+            .iter()
+            .map(|&(row, column, value)| (column, row, value))
+            .collect();
+        CscMatrix::from_compressed(Compressed::from_triplets(self.columns, self.rows, transposed))
+    }
+}
@@ -0,0 +1,159 @@
+use std::iter::FromIterator;
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use algebra::{AdditiveIdentity, Entrywise, GEMV, GEMVMut};
+
+use super::{Compressed, CooMatrix, CsrMatrix, SparseError};
+
+/// A sparse matrix in compressed sparse column form.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CscMatrix<T>
+{
+    inner: Compressed<T>
+}
+
+impl<T> CscMatrix<T>
+{
+    pub(crate) fn from_compressed(inner: Compressed<T>) -> Self
+    {
+        CscMatrix { inner }
+    }
+
+    pub fn rows(&self) -> usize
+    {
+        self.inner.minor
+    }
+
+    pub fn columns(&self) -> usize
+    {
+        self.inner.major
+    }
+
+    /// The number of stored entries.
+    pub fn nnz(&self) -> usize
+    {
+        self.inner.nnz()
+    }
+
+    /// Column `j` is stored at `offsets()[j]..offsets()[j + 1]`.
+    pub fn offsets(&self) -> &[usize]
+    {
+        &self.inner.offsets
+    }
+
+    /// The row of each stored entry, sorted within a column.
+    pub fn indices(&self) -> &[usize]
+    {
+        &self.inner.indices
+    }
+
+    pub fn values(&self) -> &[T]
+    {
+        &self.inner.values
+    }
+
+    /// The stored entry at `(row, column)`, if any.
+    pub fn get(&self, row: usize, column: usize) -> Option<&T>
+    {
+        assert!(row < self.rows() && column < self.columns());
+        self.inner.get(column, row)
+    }
+
+    /// The stored entries as `(row, column, value)` in column-major order.
+    pub fn iter(&self) -> impl Iterator<Item=(usize, usize, &T)> + '_
+    {
+        self.inner.triplets().map(|(j, i, val)| (i, j, val))
+    }
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Copy
+{
+    pub fn to_coo(&self) -> CooMatrix<T>
+    {
+        CooMatrix::from_triplets(self.rows(), self.columns(), self.iter().map(|(i, j, &val)| (i, j, val)))
+            .expect("stored entries are in bounds")
+    }
+
+    pub fn to_csr(&self) -> CsrMatrix<T>
+    {
+        CsrMatrix::from_compressed(self.inner.transposed())
+    }
+
+    pub fn transpose(&self) -> CscMatrix<T>
+    {
+        CscMatrix::from_compressed(self.inner.transposed())
+    }
+
+    /// The row-major dense entries, collected into any vector or `mspace!` matrix.
+    pub fn to_dense<M>(&self) -> M
+    where
+        T: AdditiveIdentity<Output=T>,
+        M: FromIterator<T>
+    {
+        let columns: usize = self.columns();
+        let mut entries: Vec<T> = vec![T::additive_identity(); self.rows() * columns];
+        for (i, j, &val) in self.iter() {
+            entries[i * columns + j] = val;
+        }
+        entries.into_iter().collect()
+    }
+
+    pub fn add(&self, other: &Self) -> Result<Self, SparseError>
+    where
+        T: Add<T, Output=T>
+    {
+        let (expected, found) = ((self.rows(), self.columns()), (other.rows(), other.columns()));
+        if expected != found {
+            return Err(SparseError::DimensionMismatch { expected, found })
+        }
+        Ok(CscMatrix::from_compressed(self.inner.add(&other.inner)))
+    }
+
+    pub fn scale(&self, factor: &T) -> Self
+    where
+        T: Mul<T, Output=T>
+    {
+        let mut output: Self = self.clone();
+        output.scale_mut(factor);
+        output
+    }
+
+    pub fn scale_mut(&mut self, factor: &T)
+    where
+        T: Mul<T, Output=T>
+    {
+        self.inner.scale_mut(factor);
+    }
+}
+
+impl<T, X, Y> GEMV<X, Y> for CscMatrix<T>
+where
+    T: Copy + Add<T, Output=T> + Mul<T, Output=T> + AdditiveIdentity<Output=T>,
+    X: Index<usize, Output=T> + Entrywise,
+    Y: Clone + IndexMut<usize, Output=T> + Entrywise
+{
+    type Scalar = T;
+
+    fn gemv(&self, alpha: &Self::Scalar, x: &X, beta: &Self::Scalar, y: &Y) -> Y
+    {
+        let mut output: Y = y.clone();
+        self.gemv_mut(alpha, x, beta, &mut output);
+        output
+    }
+}
+
+impl<T, X, Y> GEMVMut<X, Y> for CscMatrix<T>
+where
+    T: Copy + Add<T, Output=T> + Mul<T, Output=T> + AdditiveIdentity<Output=T>,
+    X: Index<usize, Output=T> + Entrywise,
+    Y: IndexMut<usize, Output=T> + Entrywise
+{
+    type Scalar = T;
+
+    fn gemv_mut(&self, alpha: &Self::Scalar, x: &X, beta: &Self::Scalar, y: &mut Y)
+    {
+        self.inner.gemv_minor(alpha, x, beta, y);
+    }
+}
@@ -0,0 +1,230 @@
+//! Sparse matrix storage.
+//!
+//! `CooMatrix` collects `(row, column, value)` triplets and converts into the
+//! compressed `CsrMatrix` and `CscMatrix` formats, which act on `vspace!`
+//! vectors through the `GEMV` traits in `algebra`. Duplicate triplets are summed
+//! during compression and explicitly stored zeros are kept.
+
+use std::{fmt, error, cmp::Ordering};
+use std::ops::{Add, Index, IndexMut, Mul};
+
+use algebra::{AdditiveIdentity, Entrywise};
+
+mod coo;
+pub use coo::*;
+
+mod csr;
+pub use csr::*;
+
+mod csc;
+pub use csc::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SparseError
+{
+    /// An entry lies outside the dimensions of the matrix.
+    OutOfBounds { row: usize, column: usize },
+
+    /// Two matrices, as `(rows, columns)`, do not have the same shape.
+    DimensionMismatch { expected: (usize, usize), found: (usize, usize) }
+}
+
+impl fmt::Display for SparseError
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        match self {
+            SparseError::OutOfBounds { row, column } => {
+                write!(f, "entry ({}, {}) is out of bounds", row, column)
+            },
+            SparseError::DimensionMismatch { expected, found } => {
+                write!(f, "expected a {}x{} matrix, found {}x{}", expected.0, expected.1, found.0, found.1)
+            }
+        }
+    }
+}
+
+impl error::Error for SparseError { }
+
+/// Compressed storage along a major axis: the entries of major line `i` are
+/// `values[offsets[i]..offsets[i + 1]]` at the sorted minor positions in `indices`.
+///
+/// Rows are the major axis of CSR storage and columns that of CSC storage, so
+/// the same data read along the other axis is the transpose.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Compressed<T>
+{
+    pub(crate) major: usize,
+    pub(crate) minor: usize,
+    pub(crate) offsets: Vec<usize>,
+    pub(crate) indices: Vec<usize>,
+    pub(crate) values: Vec<T>
+}
+
+impl<T> Compressed<T>
+{
+    /// Sorts `(major, minor, value)` triplets into compressed form, summing duplicates.
+    pub(crate) fn from_triplets(major: usize, minor: usize, mut triplets: Vec<(usize, usize, T)>) -> Self
+    where
+        T: Copy + Add<T, Output=T>
+    {
+        triplets.sort_by_key(|&(i, j, _)| (i, j));
+
+        let mut offsets: Vec<usize> = vec![0; major + 1];
+        let mut indices: Vec<usize> = Vec::with_capacity(triplets.len());
+        let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+        let mut last: Option<(usize, usize)> = None;
+        for (i, j, val) in triplets {
+            if last == Some((i, j)) {
+                let acc = values.last_mut().expect("a duplicate follows a stored entry");
+                *acc = *acc + val;
+                continue;
+            }
+            last = Some((i, j));
+            offsets[i + 1] += 1;
+            indices.push(j);
+            values.push(val);
+        }
+        for i in 0..major {
+            offsets[i + 1] += offsets[i];
+        }
+        Compressed { major, minor, offsets, indices, values }
+    }
+
+    pub(crate) fn nnz(&self) -> usize
+    {
+        self.values.len()
+    }
+
+    pub(crate) fn get(&self, i: usize, j: usize) -> Option<&T>
+    {
+        let (start, end) = (self.offsets[i], self.offsets[i + 1]);
+        self.indices[start..end]
+            .binary_search(&j)
+            .ok()
+            .map(|idx| &self.values[start + idx])
+    }
+
+    /// The stored entries as `(major, minor, value)` in storage order.
+    pub(crate) fn triplets(&self) -> impl Iterator<Item=(usize, usize, &T)> + '_
+    {
+        (0..self.major).flat_map(move |i| {
+            (self.offsets[i]..self.offsets[i + 1]).map(move |idx| (i, self.indices[idx], &self.values[idx]))
+        })
+    }
+
+    /// The same matrix compressed along the other axis.
+    pub(crate) fn transposed(&self) -> Self
+    where
+        T: Copy
+    {
+        let mut offsets: Vec<usize> = vec![0; self.minor + 1];
+        for &j in &self.indices {
+            offsets[j + 1] += 1;
+        }
+        for j in 0..self.minor {
+            offsets[j + 1] += offsets[j];
+        }
+
+        // Visiting major lines in order leaves every transposed line sorted.
+        let mut cursor: Vec<usize> = offsets[..self.minor].to_vec();
+        let mut indices: Vec<usize> = vec![0; self.nnz()];
+        let mut values: Vec<T> = self.values.clone();
+        for (i, j, &val) in self.triplets() {
+            indices[cursor[j]] = i;
+            values[cursor[j]] = val;
+            cursor[j] += 1;
+        }
+        Compressed { major: self.minor, minor: self.major, offsets, indices, values }
+    }
+
+    pub(crate) fn add(&self, other: &Self) -> Self
+    where
+        T: Copy + Add<T, Output=T>
+    {
+        let mut offsets: Vec<usize> = vec![0; self.major + 1];
+        let mut indices: Vec<usize> = Vec::with_capacity(self.nnz() + other.nnz());
+        let mut values: Vec<T> = Vec::with_capacity(self.nnz() + other.nnz());
+        for i in 0..self.major {
+            let (mut l, l_end) = (self.offsets[i], self.offsets[i + 1]);
+            let (mut r, r_end) = (other.offsets[i], other.offsets[i + 1]);
+            while l < l_end && r < r_end {
+                let (j, k) = (self.indices[l], other.indices[r]);
+                match j.cmp(&k) {
+                    Ordering::Less => {
+                        indices.push(j);
+                        values.push(self.values[l]);
+                        l += 1;
+                    },
+                    Ordering::Greater => {
+                        indices.push(k);
+                        values.push(other.values[r]);
+                        r += 1;
+                    },
+                    Ordering::Equal => {
+                        indices.push(j);
+                        values.push(self.values[l] + other.values[r]);
+                        l += 1;
+                        r += 1;
+                    }
+                }
+            }
+            indices.extend_from_slice(&self.indices[l..l_end]);
+            values.extend_from_slice(&self.values[l..l_end]);
+            indices.extend_from_slice(&other.indices[r..r_end]);
+            values.extend_from_slice(&other.values[r..r_end]);
+            offsets[i + 1] = indices.len();
+        }
+        Compressed { major: self.major, minor: self.minor, offsets, indices, values }
+    }
+
+    pub(crate) fn scale_mut(&mut self, factor: &T)
+    where
+        T: Copy + Mul<T, Output=T>
+    {
+        self.values
+            .iter_mut()
+            .for_each(|val| *val = *val * *factor);
+    }
+
+    /// `y ← αAx + βy` when the major axis indexes `y`.
+    pub(crate) fn gemv_major<X, Y>(&self, alpha: &T, x: &X, beta: &T, y: &mut Y)
+    where
+        T: Copy + Add<T, Output=T> + Mul<T, Output=T> + AdditiveIdentity<Output=T>,
+        X: Index<usize, Output=T> + Entrywise + ?Sized,
+        Y: IndexMut<usize, Output=T> + Entrywise + ?Sized
+    {
+        check_gemv(x.length(), y.length(), self.minor, self.major);
+        for i in 0..self.major {
+            let acc: T = (self.offsets[i]..self.offsets[i + 1])
+                .fold(T::additive_identity(), |acc, idx| acc + self.values[idx] * x[self.indices[idx]]);
+            y[i] = *alpha * acc + *beta * y[i];
+        }
+    }
+
+    /// `y ← αAx + βy` when the minor axis indexes `y`.
+    pub(crate) fn gemv_minor<X, Y>(&self, alpha: &T, x: &X, beta: &T, y: &mut Y)
+    where
+        T: Copy + Add<T, Output=T> + Mul<T, Output=T> + AdditiveIdentity<Output=T>,
+        X: Index<usize, Output=T> + Entrywise + ?Sized,
+        Y: IndexMut<usize, Output=T> + Entrywise + ?Sized
+    {
+        check_gemv(x.length(), y.length(), self.major, self.minor);
+        for j in 0..self.minor {
+            y[j] = *beta * y[j];
+        }
+        for (i, j, &val) in self.triplets() {
+            y[j] = y[j] + *alpha * val * x[i];
+        }
+    }
+}
+
+/// Panics unless `x` has an entry for every column and `y` for every row.
+fn check_gemv(x: usize, y: usize, columns: usize, rows: usize)
+{
+    assert!(
+        x == columns && y == rows,
+        "a {}×{} matrix cannot map a vector of length {} to one of length {}",
+        rows, columns, x, y
+    );
+}
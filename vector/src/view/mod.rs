@@ -0,0 +1,355 @@
+//! Borrowed, possibly strided views into vectors.
+//!
+//! A view is a slice together with a stride and a number of entries, so
+//! blocks, every `k`-th entry and sub-ranges of a state vector can be handed
+//! to the BLAS traits without copying. Mutable views can only be split into
+//! contiguous pieces, which keeps them disjoint.
+
+use std::fmt;
+use std::ops::{Bound, Index, IndexMut, RangeBounds};
+
+use algebra::{Entries, EntriesMut, Strided, StridedMut};
+
+/// An immutable view of `dimension` entries spaced `stride` apart.
+pub struct VectorView<'a, T>
+{
+    entries: &'a [T],
+    stride: usize,
+    dimension: usize
+}
+
+impl<T> Clone for VectorView<'_, T>
+{
+    fn clone(&self) -> Self
+    {
+        *self
+    }
+}
+
+impl<T> Copy for VectorView<'_, T> { }
+
+/// A mutable view of `dimension` entries spaced `stride` apart.
+pub struct VectorViewMut<'a, T>
+{
+    entries: &'a mut [T],
+    stride: usize,
+    dimension: usize
+}
+
+/// The entries of `range` within `0..dimension`, panicking if it does not fit.
+fn bounds<R>(range: R, dimension: usize) -> (usize, usize)
+where
+    R: RangeBounds<usize>
+{
+    let start: usize = match range.start_bound() {
+        Bound::Included(&idx) => idx,
+        Bound::Excluded(&idx) => idx + 1,
+        Bound::Unbounded => 0
+    };
+    let end: usize = match range.end_bound() {
+        Bound::Included(&idx) => idx + 1,
+        Bound::Excluded(&idx) => idx,
+        Bound::Unbounded => dimension
+    };
+    assert!(start <= end && end <= dimension, "range {}..{} out of bounds for dimension {}", start, end, dimension);
+    (start, end)
+}
+
+/// The number of slice elements spanned by `dimension` entries spaced `stride` apart.
+fn span(dimension: usize, stride: usize) -> usize
+{
+    if dimension == 0 { 0 } else { (dimension - 1) * stride + 1 }
+}
+
+impl<'a, T> VectorView<'a, T>
+{
+    /// A view of `dimension` entries of `entries`, starting at the first and spaced `stride` apart.
+    pub fn new(entries: &'a [T], stride: usize, dimension: usize) -> Self
+    {
+        assert!(stride > 0, "the stride must be positive");
+        assert!(span(dimension, stride) <= entries.len(), "the view does not fit in the slice");
+        VectorView { entries: &entries[..span(dimension, stride)], stride, dimension }
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.dimension
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.dimension == 0
+    }
+
+    pub fn get(&self, idx: usize) -> Option<&'a T>
+    {
+        if idx < self.dimension { Some(&self.entries[idx * self.stride]) } else { None }
+    }
+
+    pub fn iter(&self) -> Entries<'a, T>
+    {
+        self.entries.iter().step_by(self.stride).take(self.dimension)
+    }
+
+    /// The entries in `range`.
+    pub fn slice<R>(&self, range: R) -> VectorView<'a, T>
+    where
+        R: RangeBounds<usize>
+    {
+        let (start, end) = bounds(range, self.dimension);
+        let entries: &'a [T] = self.entries;
+        VectorView::new(&entries[(start * self.stride).min(entries.len())..], self.stride, end - start)
+    }
+
+    /// Every `step`-th entry, starting with the first.
+    pub fn step_by(&self, step: usize) -> VectorView<'a, T>
+    {
+        assert!(step > 0, "the step must be positive");
+        VectorView::new(self.entries, self.stride * step, self.dimension.div_ceil(step))
+    }
+
+    /// The first `mid` entries and the rest.
+    pub fn split_at(&self, mid: usize) -> (VectorView<'a, T>, VectorView<'a, T>)
+    {
+        (self.slice(..mid), self.slice(mid..))
+    }
+
+    /// Consecutive views of `size` entries; the last may be shorter.
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item=VectorView<'a, T>>
+    {
+        assert!(size > 0, "the chunk size must be positive");
+        let view: VectorView<'a, T> = *self;
+        (0..self.dimension).step_by(size).map(move |start| view.slice(start..(start + size).min(view.dimension)))
+    }
+
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone
+    {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<'a, T> VectorViewMut<'a, T>
+{
+    /// A view of `dimension` entries of `entries`, starting at the first and spaced `stride` apart.
+    pub fn new(entries: &'a mut [T], stride: usize, dimension: usize) -> Self
+    {
+        assert!(stride > 0, "the stride must be positive");
+        assert!(span(dimension, stride) <= entries.len(), "the view does not fit in the slice");
+        VectorViewMut { entries: &mut entries[..span(dimension, stride)], stride, dimension }
+    }
+
+    pub fn len(&self) -> usize
+    {
+        self.dimension
+    }
+
+    pub fn is_empty(&self) -> bool
+    {
+        self.dimension == 0
+    }
+
+    /// Reborrows as an immutable view.
+    pub fn as_view(&self) -> VectorView<'_, T>
+    {
+        VectorView { entries: self.entries, stride: self.stride, dimension: self.dimension }
+    }
+
+    /// Reborrows for a shorter lifetime, so the view can be passed on and used again.
+    pub fn reborrow(&mut self) -> VectorViewMut<'_, T>
+    {
+        VectorViewMut { entries: self.entries, stride: self.stride, dimension: self.dimension }
+    }
+
+    pub fn get_mut(&mut self, idx: usize) -> Option<&mut T>
+    {
+        if idx < self.dimension { Some(&mut self.entries[idx * self.stride]) } else { None }
+    }
+
+    pub fn iter_mut(&mut self) -> EntriesMut<'_, T>
+    {
+        self.entries.iter_mut().step_by(self.stride).take(self.dimension)
+    }
+
+    /// The entries in `range`.
+    pub fn slice_mut<R>(self, range: R) -> VectorViewMut<'a, T>
+    where
+        R: RangeBounds<usize>
+    {
+        let (start, end) = bounds(range, self.dimension);
+        let offset: usize = (start * self.stride).min(self.entries.len());
+        VectorViewMut::new(&mut self.entries[offset..], self.stride, end - start)
+    }
+
+    /// Every `step`-th entry, starting with the first.
+    pub fn step_by_mut(self, step: usize) -> VectorViewMut<'a, T>
+    {
+        assert!(step > 0, "the step must be positive");
+        VectorViewMut::new(self.entries, self.stride * step, self.dimension.div_ceil(step))
+    }
+
+    /// Disjoint mutable views of the first `mid` entries and the rest.
+    pub fn split_at_mut(self, mid: usize) -> (VectorViewMut<'a, T>, VectorViewMut<'a, T>)
+    {
+        assert!(mid <= self.dimension, "mid {} out of bounds for dimension {}", mid, self.dimension);
+        let (stride, dimension) = (self.stride, self.dimension);
+        let (head, tail) = self.entries.split_at_mut((mid * stride).min(span(dimension, stride)));
+        (
+            VectorViewMut { entries: head, stride, dimension: mid },
+            VectorViewMut { entries: tail, stride, dimension: dimension - mid }
+        )
+    }
+
+    /// Consecutive disjoint mutable views of `size` entries; the last may be shorter.
+    pub fn chunks_mut(self, size: usize) -> ChunksMut<'a, T>
+    {
+        assert!(size > 0, "the chunk size must be positive");
+        ChunksMut { rest: self, size }
+    }
+}
+
+/// The iterator returned by `VectorViewMut::chunks_mut`.
+pub struct ChunksMut<'a, T>
+{
+    rest: VectorViewMut<'a, T>,
+    size: usize
+}
+
+impl<'a, T> Iterator for ChunksMut<'a, T>
+{
+    type Item = VectorViewMut<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.rest.dimension == 0 {
+            return None
+        }
+        let rest = std::mem::replace(&mut self.rest, VectorViewMut { entries: &mut [], stride: 1, dimension: 0 });
+        let mid: usize = self.size.min(rest.dimension);
+        let (head, tail) = rest.split_at_mut(mid);
+        self.rest = tail;
+        Some(head)
+    }
+}
+
+impl<T> Strided for VectorView<'_, T>
+{
+    type Scalar = T;
+
+    fn len(&self) -> usize
+    {
+        self.dimension
+    }
+
+    fn stride(&self) -> usize
+    {
+        self.stride
+    }
+
+    fn as_strided_slice(&self) -> &[T]
+    {
+        self.entries
+    }
+}
+
+impl<T> Strided for VectorViewMut<'_, T>
+{
+    type Scalar = T;
+
+    fn len(&self) -> usize
+    {
+        self.dimension
+    }
+
+    fn stride(&self) -> usize
+    {
+        self.stride
+    }
+
+    fn as_strided_slice(&self) -> &[T]
+    {
+        self.entries
+    }
+}
+
+impl<T> StridedMut for VectorViewMut<'_, T>
+{
+    fn as_strided_slice_mut(&mut self) -> &mut [T]
+    {
+        self.entries
+    }
+}
+
+impl<T> Index<usize> for VectorView<'_, T>
+{
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T
+    {
+        assert!(idx < self.dimension, "index {} out of bounds for dimension {}", idx, self.dimension);
+        &self.entries[idx * self.stride]
+    }
+}
+
+impl<T> Index<usize> for VectorViewMut<'_, T>
+{
+    type Output = T;
+
+    fn index(&self, idx: usize) -> &T
+    {
+        assert!(idx < self.dimension, "index {} out of bounds for dimension {}", idx, self.dimension);
+        &self.entries[idx * self.stride]
+    }
+}
+
+impl<T> IndexMut<usize> for VectorViewMut<'_, T>
+{
+    fn index_mut(&mut self, idx: usize) -> &mut T
+    {
+        assert!(idx < self.dimension, "index {} out of bounds for dimension {}", idx, self.dimension);
+        &mut self.entries[idx * self.stride]
+    }
+}
+
+impl<'a, T> IntoIterator for VectorView<'a, T>
+{
+    type Item = &'a T;
+    type IntoIter = Entries<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &VectorView<'a, T>
+{
+    type Item = &'a T;
+    type IntoIter = Entries<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.iter()
+    }
+}
+
+impl<T> fmt::Debug for VectorView<'_, T>
+where
+    T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T> fmt::Debug for VectorViewMut<'_, T>
+where
+    T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        self.as_view().fmt(f)
+    }
+}
@@ -0,0 +1,81 @@
+use std::panic;
+
+use algebra::*;
+use vector::{mspace, vspace};
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 4
+    }
+}
+
+mspace! {
+    SquareSpace {
+        matrix: Square,
+        dimension: 2
+    }
+}
+
+#[test]
+fn test_scalar_tolerances()
+{
+    let (a, b): (f64, f64) = (0.1 + 0.2, 0.3);
+    assert!(a != b);
+    assert!(a.approx_eq(&b, f64::default_tolerance()));
+    assert!(a.approx_eq(&b, Tolerance::ulps::<f64>(1)));
+    assert!(!a.approx_eq(&b, Tolerance::Ulps { epsilon: 0.0, max_ulps: 0 }));
+
+    assert!(1e-20f64.approx_eq(&-1e-20, Tolerance::absolute(1e-12)));
+    assert!(!1e-20f64.approx_eq(&-1e-20, Tolerance::Relative { epsilon: 0.0, max_relative: 1e-3 }));
+    assert!(1e6f64.approx_eq(&(1e6 + 1e-4), Tolerance::relative::<f64>(1e-9)));
+    assert!(!1e6f64.approx_eq(&(1e6 + 1e-4), Tolerance::absolute(1e-9)));
+
+    assert!(!f64::NAN.approx_eq(&f64::NAN, Tolerance::ulps::<f64>(4)));
+    assert!(f64::INFINITY.approx_eq(&f64::INFINITY, f64::default_tolerance()));
+    assert!(f64::INFINITY.approx_eq(&f64::INFINITY, Tolerance::ulps::<f64>(4)));
+    assert!(!f64::INFINITY.approx_eq(&1.0, f64::default_tolerance()));
+    assert!(!f64::INFINITY.approx_eq(&f64::NEG_INFINITY, f64::default_tolerance()));
+    assert!(!f64::INFINITY.approx_eq(&f64::MAX, f64::default_tolerance()));
+    assert!(!f64::INFINITY.approx_eq(&f64::MAX, Tolerance::ulps::<f64>(4)));
+    assert!(!f32::NEG_INFINITY.approx_eq(&f32::MIN, Tolerance::ulps::<f32>(4)));
+    assert!(0.0f32.approx_eq(&-0.0, Tolerance::Ulps { epsilon: 0.0, max_ulps: 0 }));
+
+    let z: Complex<f64> = Complex::new(0.1 + 0.2, -0.7);
+    assert!(z.approx_eq(&Complex::new(0.3, -0.7), Tolerance::ulps::<Complex<f64>>(2)));
+    assert!(!z.approx_eq(&Complex::new(0.3, -0.7 + 1e-9), Complex::<f64>::default_tolerance()));
+    let infinite: Complex<f64> = Complex::new(f64::INFINITY, 0.0);
+    assert!(!infinite.approx_eq(&Complex::new(1.0, 0.0), Complex::<f64>::default_tolerance()));
+    assert!(!infinite.approx_eq(&Complex::new(f64::MAX, 0.0), Tolerance::ulps::<Complex<f64>>(4)));
+    assert_approx_eq!(z.norm_sqr(), 0.09 + 0.49);
+    assert_approx_eq!(1.0f32, 1.001, Tolerance::absolute(1e-2));
+}
+
+#[test]
+fn test_space_approx_eq()
+{
+    let space = StateSpace::new();
+    let x: State<f64> = State::new(vec![0.1, 0.2, 0.3, 0.4]);
+    let y: State<f64> = space.vaxpy(&2.0, &x, &x);
+    let expected: State<f64> = State::new(vec![0.30000000000000004, 0.6, 0.9, 1.2]);
+    assert!(space.approx_eq(&y, &expected, Tolerance::ulps::<f64>(4)));
+    assert_vapprox_eq!(space, y, State::new(vec![0.3, 0.6, 0.9, 1.2]));
+    assert_eq!(space.mismatch(&y, &State::new(vec![0.3, 0.6, 0.91, 1.2]), Tolerance::absolute(1e-3)), Some(2));
+
+    let matrices = SquareSpace::new();
+    let a: Square<Complex<f64>> = Square::new(vec![Complex::new(1.0, 0.0); 4]);
+    let b: Square<Complex<f64>> = matrices.vscale(&a, &Complex::new(1.0 + 1e-15, 0.0));
+    assert_vapprox_eq!(matrices, a, b, Tolerance::absolute(1e-12));
+}
+
+#[test]
+fn test_assertion_reports_index()
+{
+    let result = panic::catch_unwind(|| {
+        let space = StateSpace::new();
+        assert_vapprox_eq!(space, State::new(vec![1.0, 2.0, 3.0, 4.0]), State::new(vec![1.0, 2.0, 3.5, 4.0]));
+    });
+    let message: String = *result.unwrap_err().downcast::<String>().unwrap();
+    assert!(message.contains("at index 2"), "{}", message);
+    assert!(message.contains("`3.0`") && message.contains("`3.5`"), "{}", message);
+}
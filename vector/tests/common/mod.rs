@@ -0,0 +1,63 @@
+//! Random inputs and approximate assertions shared by the integration tests.
+//!
+//! Each test crate compiles its own copy and uses only some of it.
+#![allow(dead_code)]
+
+use std::iter::FromIterator;
+
+use rand::{rngs::StdRng, Rng};
+
+use algebra::{Complex, ComplexField, RealField};
+
+/// A complex scalar whose parts are uniform in `[-1, 1)`.
+pub fn random_complex(rng: &mut StdRng) -> Complex<f64>
+{
+    Complex::new(rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0))
+}
+
+/// `len` real entries uniform in `[-1, 1)`.
+pub fn random_reals<V>(rng: &mut StdRng, len: usize) -> V
+where
+    V: FromIterator<f64>
+{
+    (0..len).map(|_| rng.gen_range(-1.0, 1.0)).collect()
+}
+
+/// `len` complex entries drawn by `random_complex`.
+pub fn random_complexes<V>(rng: &mut StdRng, len: usize) -> V
+where
+    V: FromIterator<Complex<f64>>
+{
+    (0..len).map(|_| random_complex(rng)).collect()
+}
+
+/// An `n × n` Hermitian matrix in row-major order, with a real diagonal and
+/// entries below it drawn by `random_complex`.
+pub fn random_hermitian<M>(rng: &mut StdRng, n: usize) -> M
+where
+    M: FromIterator<Complex<f64>>
+{
+    let mut a: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); n * n];
+    for i in 0..n {
+        a[i * n + i] = Complex::new(rng.gen_range(-1.0, 1.0), 0.0);
+        for j in 0..i {
+            let val: Complex<f64> = random_complex(rng);
+            a[i * n + j] = val;
+            a[j * n + i] = val.conj();
+        }
+    }
+    a.into_iter().collect()
+}
+
+/// Asserts that the entries agree to within `tolerance`, relative to the
+/// expected entry once it exceeds one.
+pub fn assert_close<'a, T, I>(lhs: I, rhs: I, tolerance: f64)
+where
+    T: ComplexField + 'a,
+    I: IntoIterator<Item=&'a T>
+{
+    for (l, r) in lhs.into_iter().zip(rhs) {
+        let scale: f64 = r.modulus().to_f64().max(1.0);
+        assert!((*l - *r).modulus().to_f64() < tolerance * scale, "Expected: {:?}, Got: {:?}", r, l);
+    }
+}
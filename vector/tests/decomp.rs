@@ -0,0 +1,237 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use algebra::*;
+use vector::{decomp, mspace, vspace, Dense};
+use vector::decomp::DecompError;
+
+mod common;
+use common::{assert_close, random_complexes, random_reals};
+
+mspace! {
+    RealMatrixSpace {
+        matrix: RealMatrix,
+        dimension: 3,
+        using: [T; 9]
+    }
+}
+
+mspace! {
+    ComplexMatrixSpace {
+        matrix: ComplexMatrix,
+        dimension: 4
+    }
+}
+
+vspace! {
+    RealVectorSpace {
+        vector: RealVector,
+        dimension: 3,
+        using: [T; 3]
+    }
+}
+
+vspace! {
+    ComplexVectorSpace {
+        vector: ComplexVector,
+        dimension: 4
+    }
+}
+
+const TOLERANCE: f64 = 1e-10;
+
+fn matmul<M>(lhs: &M, rhs: &M) -> M
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    let n: usize = lhs.rows();
+    let mut out: M = lhs.clone();
+    for i in 0..n {
+        for j in 0..n {
+            out.as_mut_slice()[i * n + j] = (0..n)
+                .map(|k| lhs.as_slice()[i * n + k] * rhs.as_slice()[k * n + j])
+                .fold(M::Scalar::additive_identity(), |acc, val| acc + val);
+        }
+    }
+    out
+}
+
+fn adjoint<M>(matrix: &M) -> M
+where
+    M: Dense + Clone,
+    M::Scalar: ComplexField
+{
+    let n: usize = matrix.rows();
+    let mut out: M = matrix.clone();
+    for i in 0..n {
+        for j in 0..n {
+            out.as_mut_slice()[i * n + j] = matrix.as_slice()[j * n + i].conjugate();
+        }
+    }
+    out
+}
+
+fn is_identity<M>(matrix: &M) -> bool
+where
+    M: Dense,
+    M::Scalar: ComplexField
+{
+    let n: usize = matrix.rows();
+    matrix.as_slice().iter().enumerate().all(|(idx, val)| {
+        let expected: M::Scalar = if idx / n == idx % n {
+            M::Scalar::multiplicative_identity()
+        } else {
+            M::Scalar::additive_identity()
+        };
+        (*val - expected).modulus().to_f64() < TOLERANCE
+    })
+}
+
+#[test]
+fn test_lu_reconstructs_permuted_matrix()
+{
+    let mut rng = StdRng::seed_from_u64(1);
+    let a: RealMatrix<f64> = random_reals(&mut rng, 9);
+    let factors = decomp::lu(&a).unwrap();
+
+    let permuted: RealMatrix<f64> = factors
+        .permutation()
+        .iter()
+        .flat_map(|&i| (0..3).map(move |j| (i, j)))
+        .map(|idx| a[idx])
+        .collect();
+    assert_close(matmul(&factors.l(), &factors.u()).as_slice(), permuted.as_slice(), TOLERANCE);
+}
+
+#[test]
+fn test_lu_pivots_on_zero_diagonal()
+{
+    let a = RealMatrix::new([0.0, 1.0, 2.0, 1.0, 0.0, 3.0, 4.0, -3.0, 8.0]);
+    let b = RealVector::new([1.0, 2.0, 3.0]);
+    let x: RealVector<f64> = decomp::solve(&a, &b).unwrap();
+
+    let residual: Vec<f64> = (0..3)
+        .map(|i| (0..3).map(|j| a[(i, j)] * x[j]).sum::<f64>() - b[i])
+        .collect();
+    assert!(residual.iter().all(|r| r.abs() < TOLERANCE), "Residual: {:?}", residual);
+}
+
+#[test]
+fn test_det_and_inverse()
+{
+    let a = RealMatrix::new([2.0, 0.0, 1.0, 1.0, 3.0, 2.0, 1.0, 1.0, 2.0]);
+    let det: f64 = decomp::det(&a).unwrap();
+    assert!((det - 6.0).abs() < TOLERANCE, "Expected: 6, Got: {}", det);
+
+    let inverse: RealMatrix<f64> = decomp::inverse(&a).unwrap();
+    assert!(is_identity(&matmul(&a, &inverse)));
+
+    let mut rng = StdRng::seed_from_u64(2);
+    let c: ComplexMatrix<Complex<f64>> = random_complexes(&mut rng, 16);
+    assert!(is_identity(&matmul(&decomp::inverse(&c).unwrap(), &c)));
+}
+
+#[test]
+fn test_singular_matrix()
+{
+    let a = RealMatrix::new([1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 1.0, 0.0, 1.0]);
+    assert_eq!(decomp::det(&a), Ok(0.0));
+    match decomp::inverse(&a) {
+        Err(DecompError::Singular { .. }) => {},
+        other => panic!("Expected a singular matrix, Got: {:?}", other)
+    }
+}
+
+#[test]
+fn test_non_finite_entries_are_rejected()
+{
+    let a = RealMatrix::new([1.0, 2.0, 3.0, 0.0, f64::NAN, 1.0, 1.0, 0.0, 1.0]);
+    assert_eq!(decomp::lu(&a).map(|_| ()), Err(DecompError::NotFinite { row: 1, column: 1 }));
+    assert_eq!(decomp::det(&a), Err(DecompError::NotFinite { row: 1, column: 1 }));
+
+    // Elimination overflows even though every entry is finite.
+    let b = RealMatrix::new([f64::MAX, f64::MAX, 0.0, -f64::MAX, f64::MAX, 0.0, 0.0, 0.0, 1.0]);
+    assert_eq!(decomp::inverse(&b).map(|_| ()), Err(DecompError::NotFinite { row: 1, column: 1 }));
+}
+
+#[test]
+fn test_qr_is_unitary_and_upper_triangular()
+{
+    let mut rng = StdRng::seed_from_u64(3);
+    let a: ComplexMatrix<Complex<f64>> = random_complexes(&mut rng, 16);
+    let factors = decomp::qr(&a).unwrap();
+
+    assert!(is_identity(&matmul(&adjoint(factors.q()), factors.q())));
+    for i in 0..4 {
+        for j in 0..i {
+            assert!(factors.r()[(i, j)].norm() < TOLERANCE);
+        }
+    }
+    assert_close(matmul(factors.q(), factors.r()).as_slice(), a.as_slice(), TOLERANCE);
+
+    let b: ComplexVector<Complex<f64>> = (0..4).map(|i| Complex::new(i as f64, 1.0)).collect();
+    let x: ComplexVector<Complex<f64>> = factors.solve(&b).unwrap();
+    let y: ComplexVector<Complex<f64>> = decomp::solve(&a, &b).unwrap();
+    for i in 0..4 {
+        assert!((x[i] - y[i]).norm() < TOLERANCE);
+    }
+}
+
+#[test]
+fn test_cholesky_of_gram_matrix()
+{
+    let mut rng = StdRng::seed_from_u64(4);
+    let b: ComplexMatrix<Complex<f64>> = random_complexes(&mut rng, 16);
+    let mut a: ComplexMatrix<Complex<f64>> = matmul(&b, &adjoint(&b));
+    for i in 0..4 {
+        a[(i, i)] += Complex::new(1.0, 0.0);
+    }
+
+    let factor = decomp::cholesky(&a).unwrap();
+    assert_close(matmul(factor.l(), &adjoint(factor.l())).as_slice(), a.as_slice(), TOLERANCE);
+
+    let det: Complex<f64> = decomp::det(&a).unwrap();
+    assert!((factor.det() - det).norm() < 1e-8);
+
+    let rhs: ComplexVector<Complex<f64>> = (0..4).map(|i| Complex::new(1.0, -(i as f64))).collect();
+    let x: ComplexVector<Complex<f64>> = factor.solve(&rhs).unwrap();
+    let y: ComplexVector<Complex<f64>> = decomp::solve(&a, &rhs).unwrap();
+    for i in 0..4 {
+        assert!((x[i] - y[i]).norm() < TOLERANCE);
+    }
+}
+
+#[test]
+fn test_cholesky_rejects_indefinite_matrix()
+{
+    let a = RealMatrix::new([1.0, 2.0, 0.0, 2.0, 1.0, 0.0, 0.0, 0.0, 1.0]);
+    match decomp::cholesky(&a) {
+        Err(DecompError::NotPositiveDefinite { pivot: 1 }) => {},
+        other => panic!("Expected an indefinite matrix, Got: {:?}", other.map(|f| f.l().clone()))
+    }
+}
+
+#[test]
+fn test_dimension_mismatch()
+{
+    let mut rng = StdRng::seed_from_u64(5);
+    let a: ComplexMatrix<Complex<f64>> = random_complexes(&mut rng, 16);
+    let b: RealVector<f64> = RealVector::new([1.0, 2.0, 3.0]);
+    let real: RealMatrix<f64> = random_reals(&mut rng, 9);
+
+    assert!(decomp::solve(&real, &b).is_ok());
+    let wide: Vec<Complex<f64>> = vec![Complex::new(1.0, 0.0); 3];
+    match decomp::lu(&a).unwrap().solve(&wide) {
+        Err(DecompError::DimensionMismatch { expected: 4, found: 3 }) => {},
+        other => panic!("Expected a dimension mismatch, Got: {:?}", other)
+    }
+}
+
+#[test]
+fn test_entrywise_space_operations()
+{
+    let space = RealMatrixSpace::new();
+    let a = RealMatrix::new([1.0; 9]);
+    let b = space.vscale(&a, &2.0);
+    assert!(space.eq(&space.vadd(&a, &a), &b));
+}
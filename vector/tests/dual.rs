@@ -0,0 +1,149 @@
+use algebra::*;
+use algebra::laws::{Approximate, Laws, Rng, StdRng};
+use vector::vspace;
+
+vspace! {
+    QubitSpace {
+        vector: Qubit,
+        dimension: 2
+    }
+}
+
+vspace! {
+    PlaneSpace {
+        vector: Plane,
+        dimension: 3
+    }
+}
+
+type D = Dual<f64>;
+
+fn close(lhs: f64, rhs: f64) -> bool
+{
+    lhs.approx_eq(&rhs, Tolerance::absolute(1e-12))
+}
+
+fn rosenbrock<T>(x: &[T]) -> T
+where
+    T: Ring + Copy
+{
+    let one: T = T::multiplicative_identity();
+    let hundred: T = (0..100).fold(T::additive_identity(), |acc, _| acc + one);
+    let (a, b): (T, T) = (one - x[0], x[1] - x[0] * x[0]);
+    a * a + hundred * b * b
+}
+
+#[test]
+fn test_dual_arithmetic()
+{
+    let x: f64 = 0.7;
+    let f = |x: D| x * x * x / (D::constant(1.0) + x * x);
+    let expected: f64 = (x * x * (3.0 + x * x)) / ((1.0 + x * x) * (1.0 + x * x));
+    assert!(close(derivative(f, x), expected));
+
+    assert!(close(derivative(|x: D| x.sin(), x), x.cos()));
+    assert!(close(derivative(|x: D| x.cos(), x), -x.sin()));
+    assert!(close(derivative(|x: D| x.exp(), x), x.exp()));
+    assert!(close(derivative(|x: D| x.sqrt(), x), 0.5 / x.sqrt()));
+    assert!(close(derivative(|x: D| x.ln(), x), 1.0 / x));
+    assert!(close(derivative(|x: D| (x.sin() * x).exp(), x), (x.cos() * x + x.sin()) * (x.sin() * x).exp()));
+
+    let z: Dual<Complex<f64>> = Dual::new(Complex::new(1.0, 2.0), Complex::new(0.0, 1.0));
+    assert_eq!(z.conjugate(), Dual::new(Complex::new(1.0, -2.0), Complex::new(0.0, -1.0)));
+    assert_eq!(z.real(), Dual::new(1.0, 0.0));
+    assert!((z.exp().derivative - Complex::new(0.0, 1.0) * z.value.exp()).norm() < 1e-12);
+}
+
+#[test]
+fn test_hyper_dual_second_derivatives()
+{
+    let x: f64 = 1.3;
+    let f: HyperDual<f64> = (HyperDual::variable(x).sin() * HyperDual::variable(x)).exp();
+    let g: f64 = x.sin() * x;
+    let dg: f64 = x.cos() * x + x.sin();
+    let ddg: f64 = 2.0 * x.cos() - x.sin() * x;
+    assert!(close(f.first, dg * g.exp()));
+    assert!(close(f.second, f.first));
+    assert!(close(f.mixed, (ddg + dg * dg) * g.exp()));
+
+    let q: HyperDual<f64> = HyperDual::constant(1.0) / HyperDual::variable(x).sqrt();
+    assert!(close(q.mixed, 0.75 * x.powf(-2.5)));
+    assert!(close(HyperDual::variable(x).ln().mixed, -1.0 / (x * x)));
+    assert!(close(HyperDual::variable(x).cos().mixed, -x.cos()));
+}
+
+#[test]
+fn test_differentiate_rotation_expectation()
+{
+    // ⟨ψ(θ)|Z|ψ(θ)⟩ = cos θ for ψ(θ) = RY(θ)|0⟩ = (cos θ/2, sin θ/2).
+    let space: QubitSpace<Dual<Complex<f64>>> = QubitSpace::new();
+    let expectation = |theta: D| -> D {
+        let half: D = theta * D::constant(0.5);
+        let psi: Qubit<Dual<Complex<f64>>> = vec![half.cos().to_complex(), half.sin().to_complex()].into_iter().collect();
+        let bra: Qubit<Dual<Complex<f64>>> = psi.into_iter().map(|amplitude| amplitude.conjugate()).collect();
+        let z_psi: Qubit<Dual<Complex<f64>>> = vec![psi[0], -psi[1]].into_iter().collect();
+        space.dotv(&bra, &z_psi).real()
+    };
+    for &theta in &[0.0, 0.4, 1.9, -2.5] {
+        let value: D = expectation(D::variable(theta));
+        assert!(close(value.value, theta.cos()));
+        assert!(close(value.derivative, -theta.sin()));
+    }
+}
+
+#[test]
+fn test_differentiate_blas_pipeline()
+{
+    // f(a) = ‖a·x + y‖², with f'(a) = 2⟨x, a·x + y⟩.
+    let space: PlaneSpace<D> = PlaneSpace::new();
+    let x: Plane<D> = vec![1.0, -2.0, 0.5].into_iter().map(D::constant).collect();
+    let y: Plane<D> = vec![3.0, 0.0, -1.0].into_iter().map(D::constant).collect();
+    let f = |a: D| {
+        let z: Plane<D> = space.vaxpy(&a, &x, &y);
+        space.dotv(&z, &z)
+    };
+
+    let a: f64 = 0.8;
+    let z: Vec<f64> = vec![1.0 * a + 3.0, -2.0 * a, 0.5 * a - 1.0];
+    let expected: f64 = 2.0 * (z[0] - 2.0 * z[1] + 0.5 * z[2]);
+    assert!(close(derivative(f, a), expected));
+}
+
+#[test]
+fn test_gradient_jacobian_hessian()
+{
+    let x: [f64; 2] = [-1.2, 1.0];
+    let grad: Vec<f64> = gradient(rosenbrock, &x);
+    assert!(close(grad[0], -2.0 * (1.0 - x[0]) - 400.0 * x[0] * (x[1] - x[0] * x[0])));
+    assert!(close(grad[1], 200.0 * (x[1] - x[0] * x[0])));
+    assert_eq!(gradient(rosenbrock, &[1.0, 1.0]), vec![0.0, 0.0]);
+
+    let hess: Vec<Vec<f64>> = hessian(rosenbrock, &x);
+    assert!(close(hess[0][0], 2.0 - 400.0 * x[1] + 1200.0 * x[0] * x[0]));
+    assert!(close(hess[0][1], -400.0 * x[0]));
+    assert_eq!(hess[1][0], hess[0][1]);
+    assert!(close(hess[1][1], 200.0));
+
+    // Polar to Cartesian coordinates, whose Jacobian has determinant r.
+    let (r, phi): (f64, f64) = (2.0, 0.3);
+    let jac: Vec<Vec<f64>> = jacobian(|p: &[D]| vec![p[0] * p[1].cos(), p[0] * p[1].sin()], &[r, phi]);
+    assert!(close(jac[0][0], phi.cos()));
+    assert!(close(jac[0][1], -r * phi.sin()));
+    assert!(close(jac[1][0], phi.sin()));
+    assert!(close(jac[1][1], r * phi.cos()));
+    assert!(close(jac[0][0] * jac[1][1] - jac[0][1] * jac[1][0], r));
+    assert_eq!(jacobian(|_: &[D]| vec![D::constant(1.0)], &[]), vec![Vec::<f64>::new()]);
+}
+
+#[test]
+fn test_dual_laws()
+{
+    let dual = |rng: &mut StdRng| Dual::new(rng.gen_range(-10.0, 10.0), rng.gen_range(-10.0, 10.0));
+    Laws::default().ring(dual, Approximate(Tolerance::absolute(1e-9))).unwrap();
+
+    let hyper = |rng: &mut StdRng| {
+        let mut part = || rng.gen_range(-10.0, 10.0);
+        HyperDual::new(part(), part(), part(), part())
+    };
+    Laws::default().ring(hyper, Approximate(Tolerance::absolute(1e-9))).unwrap();
+}
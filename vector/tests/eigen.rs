@@ -0,0 +1,143 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use algebra::*;
+use vector::{decomp, mspace, vspace, Dense};
+use vector::decomp::DecompError;
+
+mod common;
+use common::random_hermitian;
+
+const N: usize = 5;
+const TOLERANCE: f64 = 1e-9;
+
+mspace! {
+    MatrixSpace {
+        matrix: Matrix,
+        dimension: 5,
+        using: [T; 25]
+    }
+}
+
+vspace! {
+    VectorSpace5 {
+        vector: Vector,
+        dimension: 5,
+        using: [T; 5]
+    }
+}
+
+fn random_symmetric(rng: &mut StdRng) -> Matrix<f64>
+{
+    let mut a: Matrix<f64> = (0..N * N).map(|_| 0.0).collect();
+    for i in 0..N {
+        for j in 0..=i {
+            let val: f64 = rng.gen_range(-1.0, 1.0);
+            a[(i, j)] = val;
+            a[(j, i)] = val;
+        }
+    }
+    a
+}
+
+/// Checks `Av = λv` for every pair and `V*V = I`.
+fn assert_decomposition<T>(a: &Matrix<T>, eigen: &decomp::Eigen<Vector<T>, T::Real>)
+where
+    T: ComplexField,
+    for<'a> &'a T: std::ops::Mul<&'a T, Output=T>
+{
+    let space = VectorSpace5::new();
+    for (value, vector) in eigen.values().iter().zip(eigen.vectors()) {
+        for i in 0..N {
+            let av: T = (0..N)
+                .map(|j| a[(i, j)] * vector[j])
+                .fold(T::additive_identity(), |acc, val| acc + val);
+            let residual = (av - vector[i].scale(*value)).modulus().to_f64();
+            assert!(residual < TOLERANCE, "Residual: {}", residual);
+        }
+    }
+
+    for (i, x) in eigen.vectors().iter().enumerate() {
+        for (j, y) in eigen.vectors().iter().enumerate() {
+            let conj: Vector<T> = x.into_iter().map(|val| val.conjugate()).collect();
+            let inner: T = space.dotv(&conj, y);
+            let expected: f64 = if i == j { 1.0 } else { 0.0 };
+            assert!((inner - T::from_real(T::Real::from_f64(expected))).modulus().to_f64() < TOLERANCE);
+        }
+    }
+
+    for pair in eigen.values().windows(2) {
+        assert!(pair[0] <= pair[1]);
+    }
+}
+
+#[test]
+fn test_real_symmetric_against_nalgebra()
+{
+    let mut rng = StdRng::seed_from_u64(10);
+    for _ in 0..10 {
+        let a: Matrix<f64> = random_symmetric(&mut rng);
+        let eigen: decomp::Eigen<Vector<f64>, f64> = decomp::eigh(&a).unwrap();
+        assert_decomposition(&a, &eigen);
+
+        let reference = nalgebra::DMatrix::from_row_slice(N, N, a.as_slice()).symmetric_eigen();
+        let mut expected: Vec<f64> = reference.eigenvalues.iter().copied().collect();
+        expected.sort_by(|l, r| l.partial_cmp(r).unwrap());
+        for (l, r) in eigen.values().iter().zip(expected.iter()) {
+            assert!((l - r).abs() < TOLERANCE, "Expected: {:?}, Got: {:?}", expected, eigen.values());
+        }
+    }
+}
+
+#[test]
+fn test_complex_hermitian_against_nalgebra()
+{
+    let mut rng = StdRng::seed_from_u64(11);
+    for _ in 0..10 {
+        let a: Matrix<Complex<f64>> = random_hermitian(&mut rng, N);
+        let eigen: decomp::Eigen<Vector<Complex<f64>>, f64> = decomp::eigh(&a).unwrap();
+        assert_decomposition(&a, &eigen);
+
+        let reference = nalgebra::DMatrix::from_row_slice(N, N, a.as_slice()).symmetric_eigen();
+        let mut expected: Vec<f64> = reference.eigenvalues.iter().copied().collect();
+        expected.sort_by(|l, r| l.partial_cmp(r).unwrap());
+        for (l, r) in eigen.values().iter().zip(expected.iter()) {
+            assert!((l - r).abs() < TOLERANCE, "Expected: {:?}, Got: {:?}", expected, eigen.values());
+        }
+    }
+}
+
+#[test]
+fn test_degenerate_spectrum()
+{
+    let mut a: Matrix<f64> = (0..N * N).map(|_| 0.0).collect();
+    for i in 0..N {
+        a[(i, i)] = if i < 2 { 1.0 } else { 3.0 };
+    }
+    let eigen: decomp::Eigen<Vector<f64>, f64> = decomp::eigh(&a).unwrap();
+    assert_eq!(eigen.values(), &[1.0, 1.0, 3.0, 3.0, 3.0]);
+    assert_decomposition(&a, &eigen);
+}
+
+#[test]
+fn test_rejects_non_hermitian()
+{
+    let mut rng = StdRng::seed_from_u64(12);
+    let mut a: Matrix<Complex<f64>> = random_hermitian(&mut rng, N);
+    a[(3, 1)] += Complex::new(0.5, 0.0);
+    match decomp::eigh::<_, Vector<Complex<f64>>>(&a) {
+        Err(DecompError::NotHermitian { row: 3, column: 1 }) => {},
+        other => panic!("Expected a non-Hermitian error, Got: {:?}", other.map(|e| e.into_parts().0))
+    }
+}
+
+#[test]
+fn test_rejects_non_finite()
+{
+    let mut rng = StdRng::seed_from_u64(13);
+    let mut a: Matrix<Complex<f64>> = random_hermitian(&mut rng, N);
+    a[(2, 2)] = Complex::new(f64::NAN, 0.0);
+    match decomp::eigh::<_, Vector<Complex<f64>>>(&a) {
+        Err(DecompError::NotFinite { row: 2, column: 2 }) => {},
+        other => panic!("Expected a non-finite error, Got: {:?}", other.map(|e| e.into_parts().0))
+    }
+}
@@ -0,0 +1,172 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use algebra::*;
+use vector::{decomp, mspace, vspace, Dense};
+use vector::decomp::DecompError;
+
+mod common;
+use common::{assert_close, random_complexes, random_hermitian};
+
+const TOLERANCE: f64 = 1e-9;
+const N: usize = 6;
+const LARGE: usize = 64;
+
+mspace! {
+    MatrixSpace {
+        matrix: Matrix,
+        dimension: 6
+    }
+}
+
+mspace! {
+    LargeMatrixSpace {
+        matrix: LargeMatrix,
+        dimension: 64
+    }
+}
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 64
+    }
+}
+
+/// `f(A) = V f(Λ) V*` for a Hermitian `A`.
+fn spectral<F>(a: &Matrix<Complex<f64>>, f: F) -> Matrix<Complex<f64>>
+where
+    F: Fn(f64) -> Complex<f64>
+{
+    let eigen: decomp::Eigen<Vec<Complex<f64>>, f64> = decomp::eigh(a).unwrap();
+    (0..N * N)
+        .map(|idx| {
+            let (i, j) = (idx / N, idx % N);
+            eigen.values()
+                .iter()
+                .zip(eigen.vectors())
+                .map(|(&lambda, v)| f(lambda) * v[i] * v[j].conj())
+                .sum()
+        })
+        .collect()
+}
+
+#[test]
+fn test_expm_across_pade_degrees()
+{
+    let mut rng = StdRng::seed_from_u64(30);
+    let h: Matrix<Complex<f64>> = random_hermitian(&mut rng, N);
+
+    // Norms small enough for each low degree, then large enough to need squaring.
+    for &scale in &[1e-3, 0.04, 0.15, 0.35, 2.0, 8.0] {
+        let a: Matrix<Complex<f64>> = h.as_slice().iter().map(|val| val * scale).collect();
+        let exponential: Matrix<Complex<f64>> = decomp::expm(&a).unwrap();
+        let expected = spectral(&h, |lambda| Complex::new(scale * lambda, 0.0).exp());
+        assert_close(exponential.as_slice(), expected.as_slice(), TOLERANCE);
+    }
+}
+
+#[test]
+fn test_expm_of_nilpotent_matrix()
+{
+    // e^N for the shift N has entries 1/(j - i)! on and above the diagonal.
+    let shift: Matrix<f64> = (0..N * N).map(|idx| if idx % N == idx / N + 1 { 1.0 } else { 0.0 }).collect();
+    let exponential: Matrix<f64> = decomp::expm(&shift).unwrap();
+    for i in 0..N {
+        for j in 0..N {
+            let expected: f64 = if j < i { 0.0 } else { 1.0 / (1..=j - i).product::<usize>() as f64 };
+            assert!((exponential[(i, j)] - expected).abs() < TOLERANCE);
+        }
+    }
+}
+
+#[test]
+fn test_expm_rejects_non_finite_norms()
+{
+    let mut a: Matrix<f64> = (0..N * N).map(|_| 0.0).collect();
+    a[(4, 2)] = f64::NAN;
+    assert_eq!(decomp::expm(&a).map(|_| ()), Err(DecompError::NotFinite { row: 4, column: 2 }));
+
+    // Finite entries whose column sum overflows.
+    a[(4, 2)] = f64::MAX;
+    a[(5, 2)] = f64::MAX;
+    assert_eq!(decomp::expm(&a).map(|_| ()), Err(DecompError::NotFinite { row: 5, column: 2 }));
+}
+
+#[test]
+fn test_unitary_evolution()
+{
+    let mut rng = StdRng::seed_from_u64(31);
+    let h: Matrix<Complex<f64>> = random_hermitian(&mut rng, N);
+    let t: f64 = 12.5;
+
+    let generator: Matrix<Complex<f64>> = h.as_slice().iter().map(|val| val * Complex::new(0.0, -t)).collect();
+    let u: Matrix<Complex<f64>> = decomp::expm(&generator).unwrap();
+    assert_close(u.as_slice(), spectral(&h, |lambda| Complex::new(0.0, -t * lambda).exp()).as_slice(), 1e-8);
+
+    for i in 0..N {
+        for j in 0..N {
+            let entry: Complex<f64> = (0..N).map(|k| u[(k, i)].conj() * u[(k, j)]).sum();
+            let expected = if i == j { 1.0 } else { 0.0 };
+            assert!((entry - expected).norm() < 1e-8);
+        }
+    }
+}
+
+#[test]
+fn test_krylov_matches_dense_exponential()
+{
+    let mut rng = StdRng::seed_from_u64(32);
+    let h: LargeMatrix<Complex<f64>> = random_hermitian(&mut rng, LARGE);
+    let psi: State<Complex<f64>> = random_complexes(&mut rng, LARGE);
+    let space = StateSpace::new();
+    let operator = FnOperator::hermitian(|x: &State<Complex<f64>>| -> State<Complex<f64>> {
+        (0..LARGE).map(|i| (0..LARGE).map(|j| h[(i, j)] * x[j]).sum()).collect()
+    });
+
+    for &t in &[0.5, 20.0] {
+        let tau = Complex::new(0.0, -t);
+        let evolved: State<Complex<f64>> = decomp::expm_multiply(&space, &operator, &psi, tau, 20).unwrap();
+
+        let generator: LargeMatrix<Complex<f64>> = h.as_slice().iter().map(|val| val * tau).collect();
+        let u: LargeMatrix<Complex<f64>> = decomp::expm(&generator).unwrap();
+        let expected: Vec<Complex<f64>> = (0..LARGE).map(|i| (0..LARGE).map(|j| u[(i, j)] * psi[j]).sum()).collect();
+        let evolved: Vec<Complex<f64>> = evolved.into_iter().copied().collect();
+        assert_close(&evolved, &expected, 1e-8);
+
+        let norm = |v: &[Complex<f64>]| v.iter().map(|val| val.norm_sqr()).sum::<f64>();
+        let psi: Vec<Complex<f64>> = psi.into_iter().copied().collect();
+        assert!((norm(&evolved) - norm(&psi)).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn test_krylov_keeps_the_basis_of_a_large_state()
+{
+    let mut rng = StdRng::seed_from_u64(33);
+    let h: LargeMatrix<Complex<f64>> = random_hermitian(&mut rng, LARGE);
+    let psi: State<Complex<f64>> = (0..LARGE)
+        .map(|_| Complex::new(rng.gen_range(-1e20, 1e20), rng.gen_range(-1e20, 1e20)))
+        .collect();
+    let space = StateSpace::new();
+    let operator = FnOperator::hermitian(|x: &State<Complex<f64>>| -> State<Complex<f64>> {
+        (0..LARGE).map(|i| (0..LARGE).map(|j| h[(i, j)] * x[j]).sum()).collect()
+    });
+
+    let tau = Complex::new(0.0, -2.0);
+    let evolved: State<Complex<f64>> = decomp::expm_multiply(&space, &operator, &psi, tau, 20).unwrap();
+    let generator: LargeMatrix<Complex<f64>> = h.as_slice().iter().map(|val| val * tau).collect();
+    let u: LargeMatrix<Complex<f64>> = decomp::expm(&generator).unwrap();
+    let expected: Vec<Complex<f64>> = (0..LARGE).map(|i| (0..LARGE).map(|j| u[(i, j)] * psi[j]).sum()).collect();
+    let evolved: Vec<Complex<f64>> = evolved.into_iter().copied().collect();
+    assert_close(&evolved, &expected, 1e-8);
+}
+
+#[test]
+#[should_panic(expected = "Krylov dimension of at least 2")]
+fn test_krylov_rejects_a_single_vector()
+{
+    let space = StateSpace::new();
+    let operator = FnOperator::hermitian(|x: &State<Complex<f64>>| -> State<Complex<f64>> { x.clone() });
+    let psi: State<Complex<f64>> = (0..LARGE).map(|_| Complex::new(1.0, 0.0)).collect();
+    let _ = decomp::expm_multiply(&space, &operator, &psi, Complex::new(0.0, -1.0), 1);
+}
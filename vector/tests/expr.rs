@@ -0,0 +1,97 @@
+use algebra::*;
+use vector::vspace;
+use vector::expr::{lazy, Expression};
+
+vspace! {
+    Space {
+        vector: Vector,
+        dimension: 16
+    }
+}
+
+vspace! {
+    ArraySpace {
+        vector: ArrayVector,
+        dimension: 5,
+        using: [T; 5]
+    }
+}
+
+vspace! {
+    SmallSpace {
+        vector: Small,
+        dimension: 4
+    }
+}
+
+fn vector(offset: f64) -> Vector<f64>
+{
+    (0..16).map(|i| offset + (i as f64).sin()).collect()
+}
+
+#[test]
+fn test_fused_matches_unfused()
+{
+    let space = Space::new();
+    let (x, y, z) = (vector(0.5), vector(-1.0), vector(2.0));
+    let (a, b) = (1.5, -0.25);
+
+    let mut unfused: Vector<f64> = space.vscale(&x, &a);
+    space.vadd_mut(&mut unfused, &space.vscale(&y, &b));
+    space.vadd_mut(&mut unfused, &space.additive_inv(&z));
+
+    let mut fused: Vector<f64> = vector(0.0);
+    (a * lazy(&x) + b * lazy(&y) - lazy(&z)).assign(&mut fused);
+    for i in 0..16 {
+        assert!((fused[i] - unfused[i]).abs() < 1e-15);
+    }
+
+    let collected: Vector<f64> = (lazy(&x) * a + lazy(&y) * b + -lazy(&z)).eval();
+    for i in 0..16 {
+        assert!((collected[i] - unfused[i]).abs() < 1e-15);
+    }
+}
+
+#[test]
+fn test_add_assign_accumulates()
+{
+    let x: ArrayVector<isize> = ArrayVector::new([1, 2, 3, 4, 5]);
+    let y: ArrayVector<isize> = ArrayVector::new([5, 4, 3, 2, 1]);
+    let mut w: ArrayVector<isize> = ArrayVector::new([1; 5]);
+
+    (2 * (lazy(&x) - lazy(&y))).add_assign(&mut w);
+    assert_eq!(w.into_iter().copied().collect::<Vec<isize>>(), vec![-7, -3, 1, 5, 9]);
+}
+
+#[test]
+fn test_complex_expression()
+{
+    let i = Complex::new(0.0, 1.0);
+    let x: Small<Complex<f64>> = (0..4).map(|k| Complex::new(k as f64, 0.0)).collect();
+    let y: Small<Complex<f64>> = (0..4).map(|k| Complex::new(0.0, k as f64)).collect();
+
+    let sum: Small<Complex<f64>> = (i * lazy(&x) - lazy(&y)).eval();
+    assert!(sum.into_iter().all(|val| val.norm() == 0.0));
+}
+
+#[test]
+fn test_unsigned_and_user_scalars()
+{
+    let x: Small<u32> = Small::new(vec![1, 2, 3, 4]);
+    let scaled: Small<u32> = (3 * lazy(&x) + lazy(&x) * 2).eval();
+    assert_eq!(scaled.view().to_vec(), vec![5, 10, 15, 20]);
+
+    // Scalars without a left `*` go through `scale`.
+    let y: Small<Gf<7>> = (1..5).map(Gf::new).collect();
+    let sum: Small<Gf<7>> = (lazy(&y).scale(Gf::new(3)) - lazy(&y)).eval();
+    assert_eq!(sum.view().to_vec(), vec![2, 4, 6, 1].into_iter().map(Gf::new).collect::<Vec<_>>());
+}
+
+#[test]
+#[should_panic]
+fn test_dimension_mismatch_is_rejected()
+{
+    let x: Vector<f64> = vector(0.0);
+    let y: Small<f64> = Small::new(vec![1.0; 4]);
+    let _ = lazy(&x) + lazy(&y);
+}
@@ -0,0 +1,193 @@
+use algebra::*;
+use algebra::laws::{Exact, Laws, Rng, StdRng};
+use vector::vspace;
+
+vspace! {
+    ModularSpace {
+        vector: Modular,
+        dimension: 3
+    }
+}
+
+vspace! {
+    HammingSpace {
+        vector: Hamming,
+        dimension: 7,
+        packed: Gf2
+    }
+}
+
+vspace! {
+    WideSpace {
+        vector: Wide,
+        dimension: 130,
+        packed: Gf2
+    }
+}
+
+type Gf7 = Gf<7>;
+
+/// The Mersenne prime `2⁶¹ - 1`, whose products overflow `u64`.
+type Gf61 = Gf<{ (1 << 61) - 1 }>;
+
+#[test]
+fn test_prime_field_arithmetic()
+{
+    assert_eq!(Gf7::new(5) + Gf7::new(4), Gf7::new(2));
+    assert_eq!(Gf7::new(2) - Gf7::new(5), Gf7::new(4));
+    assert_eq!(-Gf7::new(3), Gf7::new(4));
+    assert_eq!(Gf7::from(-1i64), Gf7::new(6));
+    assert_eq!(Gf7::new(3) * Gf7::new(5), Gf7::new(1));
+    assert_eq!(Gf7::new(3).pow(6), Gf7::ONE);
+    assert_eq!(Gf7::ZERO.inverse(), None);
+    for k in 1..7 {
+        let a: Gf7 = Gf7::new(k);
+        assert_eq!(a * a.inverse().unwrap(), Gf7::ONE);
+        assert_eq!(Gf7::ONE / a, a.inverse().unwrap());
+    }
+
+    let a: Gf61 = Gf61::new(u64::MAX);
+    assert_eq!(a.value(), u64::MAX % Gf61::modulus());
+    assert_eq!(a * a.inverse().unwrap(), Gf61::ONE);
+    assert_eq!(a.pow(Gf61::modulus() - 1), Gf61::ONE);
+}
+
+#[test]
+fn test_binary_extension_field()
+{
+    assert_eq!(Gf256::degree(), 8);
+    assert_eq!(Gf256::new(0x57) + Gf256::new(0x83), Gf256::new(0xd4));
+    assert_eq!(Gf256::new(0x57) * Gf256::new(0x83), Gf256::new(0xc1));
+    assert_eq!(Gf256::new(0x53).inverse(), Some(Gf256::new(0xca)));
+    assert_eq!(Gf256::new(0x11b), Gf256::ZERO);
+    assert_eq!(Gf256::new(0x100), Gf256::new(0x1b));
+    assert_eq!(Gf256::ZERO.inverse(), None);
+    for bits in 1..256 {
+        let a: Gf256 = Gf256::new(bits);
+        assert_eq!(a * a.inverse().unwrap(), Gf256::ONE);
+        assert_eq!(a.pow(255), Gf256::ONE);
+    }
+
+    // x² + 1 = (x + 1)² is reducible, so x + 1 has no inverse.
+    type Reducible = Gf2n<0b101>;
+    assert_eq!(Reducible::new(0b11).inverse(), None);
+    assert_eq!(Reducible::new(0b10).inverse(), Some(Reducible::new(0b10)));
+}
+
+#[test]
+fn test_field_laws()
+{
+    Laws::default().field(|rng| Gf7::new(rng.gen()), Exact).unwrap();
+    Laws::default().field(|rng| Gf61::new(rng.gen()), Exact).unwrap();
+    Laws::default().field(|rng| Gf256::new(rng.gen_range(0, 256)), Exact).unwrap();
+    Laws::default().field(|rng| Gf2n::<{ (1 << 63) | 0b11 }>::new(rng.gen()), Exact).unwrap();
+}
+
+#[test]
+fn test_prime_field_vspace()
+{
+    let space: ModularSpace<Gf7> = ModularSpace::new();
+    let x: Modular<Gf7> = vec![Gf7::new(1), Gf7::new(5), Gf7::new(6)].into_iter().collect();
+    let y: Modular<Gf7> = vec![Gf7::new(6), Gf7::new(3), Gf7::new(2)].into_iter().collect();
+
+    assert!(space.eq(&space.vadd(&x, &y), &vec![Gf7::new(0), Gf7::new(1), Gf7::new(1)].into_iter().collect()));
+    assert_eq!(space.dotv(&x, &y), Gf7::new(6 + 15 + 12));
+
+    let vectors = |rng: &mut StdRng| (0..3).map(|_| Gf7::new(rng.gen())).collect();
+    Laws::default().vector_space(&space, vectors, |rng| Gf7::new(rng.gen()), Exact).unwrap();
+}
+
+#[test]
+fn test_hamming_code()
+{
+    let space: HammingSpace = HammingSpace::new();
+    let bits = |word: [u64; 7]| -> Hamming { word.iter().map(|&b| Gf2::new(b)).collect() };
+
+    // Column `k` of the parity-check matrix is `k + 1` in binary.
+    let checks: Vec<Hamming> = (0..3)
+        .map(|row| (1..=7).map(|k| Gf2::new((k >> row) & 1)).collect())
+        .collect();
+    let codeword: Hamming = bits([1, 0, 1, 0, 1, 0, 1]);
+    assert!(checks.iter().all(|check| space.dotv(check, &codeword) == Gf2::ZERO));
+
+    let mut received: Hamming = codeword;
+    received.flip(4);
+    let syndrome: u64 = checks
+        .iter()
+        .enumerate()
+        .map(|(row, check)| space.dotv(check, &received).value() << row)
+        .sum();
+    assert_eq!(syndrome, 5);
+
+    assert_eq!(space.vadd(&codeword, &received), bits([0, 0, 0, 0, 1, 0, 0]));
+    assert_eq!(space.vscale(&codeword, &Gf2::ZERO), Hamming::zeros());
+    assert_eq!(space.additive_inv(&codeword), codeword);
+    assert_eq!(codeword.weight(), 4);
+    assert_eq!(format!("{:?}", codeword), "1010101");
+}
+
+#[test]
+fn test_packed_words()
+{
+    let space: WideSpace = WideSpace::new();
+    let mut x: Wide = Wide::zeros();
+    x.set(0, Gf2::ONE);
+    x.set(64, Gf2::ONE);
+    x.set(129, Gf2::ONE);
+    assert_eq!(x.words(), &[1, 1, 2]);
+    assert_eq!(x[129], Gf2::ONE);
+    assert_eq!(x.get(128), Gf2::ZERO);
+    assert_eq!(x.into_iter().filter(|&&b| b == Gf2::ONE).count(), 3);
+
+    let y: Wide = (0..130).map(|k| Gf2::new(k % 2)).collect();
+    assert_eq!(space.dotv(&x, &y), Gf2::ONE);
+    assert_eq!(space.dotv(&y, &y), Gf2::ONE);
+
+    let vectors = |rng: &mut StdRng| (0..130).map(|_| Gf2::new(rng.gen())).collect();
+    Laws::default().vector_space(&space, vectors, |rng| Gf2::new(rng.gen()), Exact).unwrap();
+}
+
+/// `x·y` and `y + x` through the BLAS traits alone.
+fn blas<S, V>(space: &S, x: &V, y: &V) -> (Gf2, V)
+where
+    S: DotV<Scalar=Gf2, Vector=V> + VAXPY<Scalar=Gf2, Vector=V>,
+    V: Entrywise<Scalar=Gf2>
+{
+    (space.dotv(x, y), space.vaxpy(&Gf2::ONE, x, y))
+}
+
+#[test]
+fn test_packed_blas_traits()
+{
+    let space: WideSpace = WideSpace::new();
+    let x: Wide = (0..130).map(|k| Gf2::new((k % 3 == 0) as u64)).collect();
+    let y: Wide = (0..130).map(|k| Gf2::new(k % 2)).collect();
+
+    let (dot, sum): (Gf2, Wide) = blas(&space, &x, &y);
+    assert_eq!(dot, Gf2::new((0..130).filter(|k| k % 3 == 0 && k % 2 == 1).count() as u64));
+    assert_eq!(sum, space.vadd(&x, &y));
+
+    // The word-wise and the entry-by-entry paths agree.
+    let (xs, ys): (Vec<Gf2>, Vec<Gf2>) = (x.into_iter().copied().collect(), y.into_iter().copied().collect());
+    assert_eq!(space.dotv(&xs[..], &ys[..]), dot);
+    assert_eq!(space.vaxpy(&Gf2::ONE, &xs[..], &ys[..]), sum);
+
+    let mut z: Wide = y;
+    space.vaxpy_mut(&Gf2::ZERO, &x, &mut z);
+    assert_eq!(z, y);
+    space.vaxpy_mut(&Gf2::ONE, &x, &mut z);
+    assert_eq!(z, sum);
+
+    // Unpacked entries mix with packed ones.
+    let ones: Vec<Gf2> = vec![Gf2::ONE; 130];
+    let mut parity: Gf2 = Gf2::ONE;
+    space.dotv_mut(&x, &ones[..], &mut parity);
+    assert_eq!(parity, Gf2::new(1 + x.weight() as u64));
+}
+
+#[test]
+#[should_panic]
+fn test_division_by_zero()
+{
+    let _ = Gf7::ONE / Gf7::ZERO;
+}
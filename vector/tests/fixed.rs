@@ -0,0 +1,199 @@
+use algebra::*;
+use algebra::laws::{Exact, Laws, Rng, SeedableRng, StdRng};
+use vector::vspace;
+
+vspace! {
+    SignalSpace {
+        vector: Signal,
+        dimension: 8
+    }
+}
+
+type Q8 = Fixed<i16, 8>;
+type W8 = Fixed<i16, 8, Wrapping>;
+type Q16 = Fixed<i32, 16, Wrapping>;
+
+fn q8(value: f64) -> Q8
+{
+    Q8::from_f64(value, Rounding::Nearest)
+}
+
+#[test]
+fn test_fixed_arithmetic()
+{
+    assert_eq!(q8(1.5) + q8(2.25), q8(3.75));
+    assert_eq!(q8(1.5) - q8(2.25), q8(-0.75));
+    assert_eq!(q8(1.5) * q8(-2.25), q8(-3.375));
+    assert_eq!(q8(3.0) / q8(4.0), q8(0.75));
+    assert_eq!(q8(-3.0) / q8(-4.0), q8(0.75));
+    assert_eq!(Q8::from_integer(-7).to_bits(), -7 * 256);
+    assert_eq!(Q8::epsilon().to_bits(), 1);
+    assert_eq!(format!("{} {:?} {:.2}", q8(-0.5), q8(2.0), q8(1.0 / 3.0)), "-0.5 2 0.33");
+
+    // Products and quotients round to nearest, ties to even.
+    let e: Q8 = Q8::epsilon();
+    assert_eq!(e * q8(0.5), Q8::from_bits(0));
+    assert_eq!(Q8::from_bits(3) * q8(0.5), Q8::from_bits(2));
+    assert_eq!(Q8::from_bits(3) * q8(0.25), Q8::from_bits(1));
+    assert_eq!(Q8::from_bits(-3) * q8(0.5), Q8::from_bits(-2));
+    assert_eq!(q8(1.0) / q8(3.0), Q8::from_bits(85));
+    assert_eq!(q8(2.0) / q8(3.0), Q8::from_bits(171));
+    assert_eq!(q8(-2.0) / q8(3.0), Q8::from_bits(-171));
+
+    let mut accumulator: Q8 = q8(1.0);
+    accumulator += q8(0.5);
+    accumulator *= &q8(2.0);
+    accumulator -= q8(1.0);
+    accumulator /= q8(4.0);
+    assert_eq!(accumulator, q8(0.5));
+}
+
+#[test]
+fn test_fixed_overflow_modes()
+{
+    assert_eq!(Q8::max_value().to_f64(), 32767.0 / 256.0);
+    assert_eq!(Q8::max_value() + Q8::epsilon(), Q8::max_value());
+    assert_eq!(Q8::min_value() - Q8::epsilon(), Q8::min_value());
+    assert_eq!(-Q8::min_value(), Q8::max_value());
+    assert_eq!(q8(100.0) * q8(100.0), Q8::max_value());
+    assert_eq!(q8(100.0) / q8(-0.01), Q8::min_value());
+    assert_eq!(Q8::from_integer(1000), Q8::max_value());
+
+    assert_eq!(W8::max_value() + W8::epsilon(), W8::min_value());
+    assert_eq!(-W8::min_value(), W8::min_value());
+    assert_eq!(W8::from_integer(129), W8::from_integer(-127));
+    assert_eq!((W8::from_integer(100) * W8::from_integer(3)).to_bits(), (300i32 * 256) as i16);
+
+    // One does not fit without an integer bit, so it saturates.
+    assert_eq!(Fixed::<i8, 7>::multiplicative_identity(), Fixed::<i8, 7>::max_value());
+    assert_eq!(Fixed::<i8, 7, Wrapping>::multiplicative_identity().to_f64(), -1.0);
+}
+
+#[test]
+fn test_float_conversions()
+{
+    let cases: [(f64, i16, i16, i16, i16); 6] = [
+        // value, nearest, down, up, toward zero, in units of 2^-8
+        (0.1, 26, 25, 26, 25),
+        (-0.1, -26, -26, -25, -25),
+        (1.0 / 512.0, 0, 0, 1, 0),
+        (3.0 / 512.0, 2, 1, 2, 1),
+        (-3.0 / 512.0, -2, -2, -1, -1),
+        (2.5, 640, 640, 640, 640)
+    ];
+    for &(value, nearest, down, up, toward_zero) in cases.iter() {
+        assert_eq!(Q8::from_f64(value, Rounding::Nearest).to_bits(), nearest, "{}", value);
+        assert_eq!(Q8::from_f64(value, Rounding::Down).to_bits(), down, "{}", value);
+        assert_eq!(Q8::from_f64(value, Rounding::Up).to_bits(), up, "{}", value);
+        assert_eq!(Q8::from_f64(value, Rounding::TowardZero).to_bits(), toward_zero, "{}", value);
+        assert_eq!(Q8::from_f32(value as f32, Rounding::Nearest).to_bits(), nearest, "{}", value);
+    }
+
+    assert_eq!(Q8::from_f64(1e300, Rounding::Nearest), Q8::max_value());
+    assert_eq!(Q8::from_f64(-1e300, Rounding::Nearest), Q8::min_value());
+    assert_eq!(W8::from_f64(1e300, Rounding::Nearest), W8::from_bits(0));
+    assert_eq!(W8::from_f64(128.5, Rounding::Nearest), W8::from_f64(-127.5, Rounding::Nearest));
+
+    // Every value round-trips through both float types.
+    let mut rng: StdRng = SeedableRng::seed_from_u64(3);
+    for _ in 0..256 {
+        let value: Q8 = Q8::from_bits(rng.gen());
+        assert_eq!(Q8::from_f64(value.to_f64(), Rounding::Nearest), value);
+        assert_eq!(Q8::from_f32(value.to_f32(), Rounding::Down), value);
+    }
+    let wide: Fixed<i64, 60> = Fixed::from_bits(i64::MAX);
+    assert_eq!(wide.to_f64(), 8.0);
+    assert_eq!(Fixed::<i64, 60>::from_f64(0.75, Rounding::Up).to_f64(), 0.75);
+}
+
+#[test]
+fn test_inexact_float_conversions()
+{
+    // 2^53 + 1 and 2^24 + 1 units lie halfway between two floats.
+    let wide: Fixed<i64, 8> = Fixed::from_bits((1 << 53) + 1);
+    let unit: f64 = 2f64.powi(-8);
+    let (below, above): (f64, f64) = (2f64.powi(45), 2f64.powi(45) + 2.0 * unit);
+    assert_eq!(wide.to_f64(), below);
+    assert_eq!(wide.to_f64_rounded(Rounding::Nearest), below);
+    assert_eq!(wide.to_f64_rounded(Rounding::Down), below);
+    assert_eq!(wide.to_f64_rounded(Rounding::Up), above);
+    assert_eq!(wide.to_f64_rounded(Rounding::TowardZero), below);
+    assert_eq!((-wide).to_f64_rounded(Rounding::Down), -above);
+    assert_eq!((-wide).to_f64_rounded(Rounding::Up), -below);
+    assert_eq!((-wide).to_f64_rounded(Rounding::TowardZero), -below);
+
+    let narrow: Fixed<i32, 4> = Fixed::from_bits((1 << 24) + 1);
+    let unit: f32 = 2f32.powi(-4);
+    let (below, above): (f32, f32) = (2f32.powi(20), 2f32.powi(20) + 2.0 * unit);
+    assert_eq!(narrow.to_f32(), below);
+    assert_eq!(narrow.to_f32_rounded(Rounding::Down), below);
+    assert_eq!(narrow.to_f32_rounded(Rounding::Up), above);
+    assert_eq!((-narrow).to_f32_rounded(Rounding::Down), -above);
+    assert_eq!((-narrow).to_f32_rounded(Rounding::TowardZero), -below);
+
+    // Exact conversions ignore the rounding.
+    let exact: Fixed<i64, 8> = Fixed::from_bits((1 << 53) + 2);
+    for &rounding in &[Rounding::Nearest, Rounding::Down, Rounding::Up, Rounding::TowardZero] {
+        assert_eq!(exact.to_f64_rounded(rounding), 2f64.powi(45) + 2f64.powi(-7));
+        assert_eq!(Q8::from_bits(-300).to_f32_rounded(rounding), -300.0 / 256.0);
+    }
+}
+
+#[test]
+#[should_panic(expected = "not a finite number")]
+fn test_from_f64_rejects_nan()
+{
+    let _ = Q8::from_f64(f64::NAN, Rounding::Nearest);
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_division_by_zero()
+{
+    let _ = q8(1.0) / Q8::additive_identity();
+}
+
+#[test]
+fn test_bit_exact_blas()
+{
+    let space: SignalSpace<Q16> = SignalSpace::new();
+    let mut rng: StdRng = SeedableRng::seed_from_u64(17);
+    for _ in 0..64 {
+        let x: Vec<Q16> = (0..8).map(|_| Q16::from_bits(rng.gen())).collect();
+        let y: Vec<Q16> = (0..8).map(|_| Q16::from_bits(rng.gen())).collect();
+        let alpha: Q16 = Q16::from_bits(rng.gen());
+
+        // The dot product is the wrapped sum of the rounded integer products,
+        // whatever the order of summation.
+        let expected: i64 = x
+            .iter()
+            .zip(&y)
+            .map(|(a, b)| {
+                let product: i64 = a.to_bits() as i64 * b.to_bits() as i64;
+                let (quotient, remainder): (i64, i64) = (product >> 16, product & 0xffff);
+                quotient + i64::from(remainder > 0x8000 || (remainder == 0x8000 && quotient % 2 != 0))
+            })
+            .fold(0i64, |acc, term| acc.wrapping_add(term));
+        let (u, v): (Signal<Q16>, Signal<Q16>) = (x.iter().copied().collect(), y.iter().copied().collect());
+        let (u_reversed, v_reversed): (Signal<Q16>, Signal<Q16>) = (x.iter().rev().copied().collect(), y.iter().rev().copied().collect());
+        assert_eq!(space.dotv(&u, &v).to_bits(), expected as i32);
+        assert_eq!(space.dotv(&u_reversed, &v_reversed), space.dotv(&u, &v));
+
+        let axpy: Signal<Q16> = space.vaxpy(&alpha, &u, &v);
+        for (k, (a, b)) in x.iter().zip(&y).enumerate() {
+            assert_eq!(axpy[k], alpha * *a + *b);
+        }
+        let mut in_place: Signal<Q16> = v.clone();
+        space.vaxpy_mut(&alpha, &u, &mut in_place);
+        assert!(space.eq(&in_place, &axpy));
+    }
+}
+
+#[test]
+fn test_fixed_ring_laws()
+{
+    // Integer values never round, and wrapping arithmetic is arithmetic
+    // modulo 2^(BITS - FRAC), so the ring laws hold exactly.
+    Laws::default().ring(|rng| Q16::from_integer(rng.gen()), Exact).unwrap();
+    Laws::default().ring(|rng| Fixed::<i8, 3, Wrapping>::from_integer(rng.gen_range(-16, 16)), Exact).unwrap();
+}
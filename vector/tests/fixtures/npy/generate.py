@@ -0,0 +1,33 @@
+"""Regenerates the .npy/.npz fixtures with NumPy itself.
+
+Run from any directory with `python3 generate.py`; the files are written next
+to this script. The writer tests compare against these bytes, so rerun this
+after a NumPy release that changes the header layout.
+"""
+
+import os
+
+import numpy as np
+
+HERE = os.path.dirname(os.path.abspath(__file__))
+
+
+def path(name):
+    return os.path.join(HERE, name)
+
+
+VECTOR = np.array([1.5, -2.0, 3.25, 0.0])
+PSI = np.array([1 + 2j, -0.5 + 0j, -1j, 3 + 4j])
+MATRIX = np.array([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]])
+
+np.save(path("f64_le.npy"), VECTOR.astype("<f8"))
+np.save(path("f64_be.npy"), VECTOR.astype(">f8"))
+np.save(path("f32.npy"), np.array([0.5, 1.5, -2.5, 4.0], dtype="<f4"))
+np.save(path("i64_be.npy"), np.array([1, -2, 3, 1 << 40], dtype=">i8"))
+np.save(path("c128.npy"), PSI.astype("<c16"))
+np.save(path("matrix_c.npy"), MATRIX.astype("<f8"))
+np.save(path("matrix_fortran.npy"), np.asfortranarray(MATRIX.astype("<f8")))
+np.save(path("matrix_c128_be_fortran.npy"), np.asfortranarray((MATRIX - 1j * MATRIX).astype(">c16")))
+
+np.savez(path("savez.npz"), psi=PSI.astype("<c16"), hamiltonian=MATRIX.astype("<f8"))
+np.savez_compressed(path("savez_compressed.npz"), psi=PSI.astype("<c16"), hamiltonian=MATRIX.astype("<f8"))
@@ -0,0 +1,159 @@
+use algebra::*;
+use algebra::laws::{Laws, Rng, StdRng};
+use vector::vspace;
+
+vspace! {
+    QubitSpace {
+        vector: Qubit,
+        dimension: 2
+    }
+}
+
+vspace! {
+    DecimalSpace {
+        vector: Decimal,
+        dimension: 4
+    }
+}
+
+type I = Interval<f64>;
+
+/// The exact rational value of a finite float.
+fn exact(x: f64) -> BigRational
+{
+    let bits: u64 = x.to_bits();
+    let sign: i64 = if bits >> 63 == 1 { -1 } else { 1 };
+    let exponent: i64 = ((bits >> 52) & 0x7ff) as i64;
+    let fraction: u64 = bits & ((1 << 52) - 1);
+    let (mantissa, exponent): (u64, i64) = if exponent == 0 { (fraction, -1074) } else { (fraction | (1 << 52), exponent - 1075) };
+    let power: BigInt = (0..exponent.abs()).fold(BigInt::from(1), |acc, _| acc * 2);
+    let mantissa: BigInt = BigInt::from(sign) * BigInt::from(mantissa);
+    if exponent >= 0 {
+        BigRational::integer(mantissa * power)
+    } else {
+        BigRational::new(mantissa, power)
+    }
+}
+
+fn encloses(interval: &I, value: &BigRational) -> bool
+{
+    exact(interval.lower()) <= *value && *value <= exact(interval.upper())
+}
+
+fn decimal(numerator: i64) -> BigRational
+{
+    BigRational::new(BigInt::from(numerator), BigInt::from(10))
+}
+
+#[test]
+fn test_interval_queries()
+{
+    let x: I = Interval::new(-1.0, 3.0);
+    assert_eq!((x.lower(), x.upper()), (-1.0, 3.0));
+    assert!(x.contains(0.0) && x.contains(3.0) && !x.contains(3.5));
+    assert!(x.width() >= 4.0 && x.width() < 4.0 + 1e-12);
+    assert_eq!(x.midpoint(), 1.0);
+    assert_eq!(x.magnitude(), 3.0);
+    assert!(x.encloses(&Interval::new(0.0, 1.0)));
+    assert!(!x.encloses(&Interval::new(0.0, 4.0)));
+    assert_eq!(x.intersection(&Interval::new(2.0, 5.0)), Some(Interval::new(2.0, 3.0)));
+    assert_eq!(x.intersection(&Interval::new(4.0, 5.0)), None);
+    assert_eq!(x.hull(&Interval::new(4.0, 5.0)), Interval::new(-1.0, 5.0));
+    assert!(I::point(2.0).is_point());
+    assert_eq!(-x, Interval::new(-3.0, 1.0));
+    assert_eq!(format!("{:?}", x), "[-1.0, 3.0]");
+}
+
+#[test]
+fn test_outward_rounding()
+{
+    // In floats 0.1 + 0.2 != 0.3, but the enclosures of the decimals agree.
+    let sum: I = I::around(0.1) + I::around(0.2);
+    assert!(encloses(&sum, &decimal(3)));
+    assert!(sum.contains(0.1 + 0.2));
+    assert!(sum.intersects(&I::around(0.3)));
+
+    // Catastrophic cancellation: the float result is 0, the exact one 1.
+    let big: I = I::point(1e16);
+    let cancelled: I = big + I::point(1.0) - big;
+    assert!(cancelled.contains(1.0));
+    assert_eq!(1e16 + 1.0 - 1e16, 0.0);
+
+    let third: I = I::point(1.0) / I::point(3.0);
+    assert!(encloses(&third, &BigRational::new(BigInt::from(1), BigInt::from(3))));
+    assert!(third.width() < 1e-15);
+    assert!((third * I::point(3.0)).contains(1.0));
+
+    let product: I = Interval::new(-2.0, 3.0) * Interval::new(-5.0, 4.0);
+    assert!(product.encloses(&Interval::new(-15.0, 12.0)));
+    assert!(product.width() < 27.0 + 1e-12);
+
+    // Zero times an unbounded interval is zero, not NaN.
+    let unbounded: I = (I::point(f64::MAX) + I::point(f64::MAX)) - (I::point(f64::MAX) + I::point(f64::MAX));
+    assert_eq!((unbounded.lower(), unbounded.upper()), (f64::NEG_INFINITY, f64::INFINITY));
+    assert!((unbounded * I::point(0.0)).contains(0.0));
+    assert!((I::point(0.0) * unbounded).width() < 1e-300);
+
+    let root: I = I::point(2.0).sqrt();
+    assert!(encloses(&(root * root), &BigRational::integer(BigInt::from(2))));
+    assert_eq!(Interval::new(-1.0, 4.0).sqrt().lower(), 0.0);
+}
+
+#[test]
+fn test_dotv_encloses_exact_dot_product()
+{
+    let space: DecimalSpace<I> = DecimalSpace::new();
+    let xs: [i64; 4] = [1, -7, 3, 9];
+    let ys: [i64; 4] = [11, 2, -13, 5];
+    let x: Decimal<I> = xs.iter().map(|&k| I::around(k as f64 / 10.0)).collect();
+    let y: Decimal<I> = ys.iter().map(|&k| I::around(k as f64 / 10.0)).collect();
+
+    let bound: I = space.dotv(&x, &y);
+    let expected: BigRational = xs
+        .iter()
+        .zip(ys.iter())
+        .fold(BigRational::integer(BigInt::from(0)), |acc, (&a, &b)| acc + decimal(a) * decimal(b));
+    assert_eq!(expected, BigRational::new(BigInt::from(3), BigInt::from(100)));
+    assert!(encloses(&bound, &expected));
+    assert!(bound.width() < 1e-14);
+
+    // The interval dot product also encloses every float evaluation of it.
+    let floats: f64 = xs.iter().zip(ys.iter()).map(|(&a, &b)| (a as f64 / 10.0) * (b as f64 / 10.0)).sum();
+    assert!(bound.contains(floats));
+}
+
+#[test]
+fn test_state_norm_bound()
+{
+    // |+⟩ = (1/√2, 1/√2) has norm exactly one, though 1/√2 is irrational.
+    let space: QubitSpace<I> = QubitSpace::new();
+    let amplitude: I = I::point(0.5).sqrt();
+    let plus: Qubit<I> = vec![amplitude, amplitude].into_iter().collect();
+    let norm: I = space.dotv(&plus, &plus).sqrt();
+    assert!(norm.contains(1.0));
+    assert!(norm.width() < 1e-14);
+
+    let scaled: Qubit<I> = space.vscale(&plus, &I::point(3.0));
+    assert!(space.dotv(&scaled, &scaled).contains(9.0));
+}
+
+#[test]
+fn test_ring_laws_hold_as_enclosures()
+{
+    let interval = |rng: &mut StdRng| {
+        let lower: f64 = rng.gen_range(-10.0, 10.0);
+        Interval::new(lower, lower + rng.gen_range(0.0, 1.0))
+    };
+    Laws::default().ring(interval, |l: &I, r: &I| l.intersects(r)).unwrap();
+
+    let x: I = Interval::new(1.0, 2.0);
+    assert!((x - x).contains(0.0));
+    assert!(!(x - x).is_point());
+}
+
+#[test]
+#[should_panic(expected = "contains zero")]
+fn test_division_by_interval_containing_zero()
+{
+    let _ = I::point(1.0) / Interval::new(-1.0, 1.0);
+}
@@ -0,0 +1,206 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use algebra::*;
+use vector::{decomp, mspace, vspace};
+use vector::iterative::{self, Convergence, Identity, Jacobi, Status};
+use vector::operator::MatrixOperator;
+use vector::sparse::{CooMatrix, CsrMatrix};
+
+mod common;
+use common::{random_complex, random_complexes};
+
+const N: usize = 48;
+
+mspace! {
+    MatrixSpace {
+        matrix: Matrix,
+        dimension: 48
+    }
+}
+
+vspace! {
+    Space {
+        vector: Vector,
+        dimension: 48
+    }
+}
+
+/// A 1D Laplacian with a varying diagonal shift, which is symmetric positive definite.
+fn laplacian() -> CsrMatrix<f64>
+{
+    let mut coo: CooMatrix<f64> = CooMatrix::new(N, N);
+    for i in 0..N {
+        coo.push(i, i, 2.0 + (i as f64) / 4.0).unwrap();
+        if i > 0 {
+            coo.push(i, i - 1, -1.0).unwrap();
+            coo.push(i - 1, i, -1.0).unwrap();
+        }
+    }
+    coo.to_csr()
+}
+
+/// Convection-diffusion with upwinding, which is not symmetric.
+fn convection() -> CsrMatrix<f64>
+{
+    let mut coo: CooMatrix<f64> = CooMatrix::new(N, N);
+    for i in 0..N {
+        coo.push(i, i, 3.0 + (i % 5) as f64).unwrap();
+        if i > 0 {
+            coo.push(i, i - 1, -1.8).unwrap();
+        }
+        if i + 1 < N {
+            coo.push(i, i + 1, -0.4).unwrap();
+        }
+    }
+    coo.to_csr()
+}
+
+/// A diagonally dominant complex matrix.
+fn random_dominant(rng: &mut StdRng) -> Matrix<Complex<f64>>
+{
+    (0..N * N)
+        .map(|idx| {
+            let diagonal = if idx / N == idx % N { 8.0 } else { 0.0 };
+            Complex::new(diagonal, 0.0) + random_complex(rng) * 0.5
+        })
+        .collect()
+}
+
+fn residual<M, V, T>(matrix: &M, x: &V, b: &V) -> f64
+where
+    M: GEMV<V, Scalar=T>,
+    V: Clone + std::ops::IndexMut<usize, Output=T>,
+    T: ComplexField
+{
+    let one = T::multiplicative_identity();
+    let r: V = matrix.gemv(&-one, x, &one, b);
+    (0..N).map(|i| r[i].modulus_squared().to_f64()).sum::<f64>().sqrt()
+}
+
+#[test]
+fn test_cg_with_and_without_jacobi()
+{
+    let a: CsrMatrix<f64> = laplacian();
+    let b: Vector<f64> = (0..N).map(|i| (i as f64).sin()).collect();
+    let space = Space::new();
+    let operator = FnOperator::hermitian(|x: &Vector<f64>| a.gemv(&1.0, x, &0.0, x));
+    let convergence = Convergence::new(1e-10, 200);
+
+    let plain = iterative::cg(&space, &operator, &b, &Identity, &convergence);
+    assert!(plain.converged());
+    assert!(residual(&a, plain.solution(), &b) < 1e-9);
+    assert_eq!(plain.residuals().len(), plain.iterations() + 1);
+
+    let jacobi = iterative::cg(&space, &operator, &b, &Jacobi::from_csr(&a).unwrap(), &convergence);
+    assert!(jacobi.converged());
+    assert!(jacobi.iterations() < plain.iterations());
+
+    let dense: Matrix<f64> = a.to_dense();
+    let direct: Vector<f64> = decomp::solve(&dense, &b).unwrap();
+    for i in 0..N {
+        assert!((jacobi.solution()[i] - direct[i]).abs() < 1e-8);
+    }
+}
+
+#[test]
+fn test_gmres_on_complex_dense_matrix()
+{
+    let mut rng = StdRng::seed_from_u64(50);
+    let a: Matrix<Complex<f64>> = random_dominant(&mut rng);
+    let b: Vector<Complex<f64>> = random_complexes(&mut rng, N);
+    let space = Space::new();
+    let operator: MatrixOperator<_, Space<_>, Space<_>> = MatrixOperator::new(a.clone());
+
+    let report = iterative::gmres(&space, &operator, &b, &Jacobi::from_dense(&a).unwrap(), 10, &Convergence::new(1e-12, 200));
+    assert!(report.converged(), "Residuals: {:?}", report.residuals());
+    assert!(residual(&a, report.solution(), &b) < 1e-10);
+    for pair in report.residuals().windows(2) {
+        assert!(pair[1] <= pair[0] * (1.0 + 1e-10));
+    }
+
+    let direct: Vector<Complex<f64>> = decomp::solve(&a, &b).unwrap();
+    for i in 0..N {
+        assert!((report.solution()[i] - direct[i]).norm() < 1e-9);
+    }
+}
+
+#[test]
+fn test_bicgstab_on_nonsymmetric_sparse_matrix()
+{
+    let a: CsrMatrix<f64> = convection();
+    let b: Vector<f64> = (0..N).map(|i| 1.0 + (i % 3) as f64).collect();
+    let space = Space::new();
+    let operator: MatrixOperator<_, Space<_>, Space<_>> = MatrixOperator::from_csr(a.clone());
+    let convergence = Convergence::new(1e-11, 200);
+
+    let report = iterative::bicgstab(&space, &operator, &b, &Identity, &convergence);
+    assert!(report.converged());
+    assert!(residual(&a, report.solution(), &b) < 1e-9);
+
+    let preconditioned = iterative::bicgstab(&space, &operator, &b, &Jacobi::from_csr(&a).unwrap(), &convergence);
+    assert!(preconditioned.converged());
+    assert!(residual(&a, preconditioned.solution(), &b) < 1e-9);
+
+    let restarted = iterative::gmres(&space, &operator, &b, &Identity, 5, &convergence);
+    assert!(restarted.converged());
+    assert!(residual(&a, restarted.solution(), &b) < 1e-9);
+}
+
+#[test]
+fn test_user_supplied_preconditioner()
+{
+    // The exact inverse makes every Krylov method converge in one iteration.
+    let a: CsrMatrix<f64> = convection();
+    let dense: Matrix<f64> = a.to_dense();
+    let factors = decomp::lu(&dense).unwrap();
+    let inverse = |r: &Vector<f64>| -> Vector<f64> { factors.solve(r).unwrap() };
+
+    let b: Vector<f64> = (0..N).map(|i| i as f64).collect();
+    let space = Space::new();
+    let operator: MatrixOperator<_, Space<_>, Space<_>> = MatrixOperator::from_csr(a.clone());
+    let convergence = Convergence::new(1e-10, 10);
+
+    let report = iterative::gmres(&space, &operator, &b, &inverse, 10, &convergence);
+    assert!(report.converged());
+    assert_eq!(report.iterations(), 1);
+
+    let report = iterative::bicgstab(&space, &operator, &b, &inverse, &convergence);
+    assert!(report.converged());
+    assert_eq!(report.iterations(), 1);
+}
+
+#[test]
+fn test_iteration_budget()
+{
+    let a: CsrMatrix<f64> = laplacian();
+    let b: Vector<f64> = (0..N).map(|i| (i as f64).cos()).collect();
+    let space = Space::new();
+    let operator: MatrixOperator<_, Space<_>, Space<_>> = MatrixOperator::from_csr(a.clone());
+
+    let report = iterative::cg(&space, &operator, &b, &Identity, &Convergence::new(1e-14, 3));
+    assert_eq!(report.status(), Status::MaxIterations);
+    assert_eq!(report.iterations(), 3);
+    assert_eq!(report.residuals().len(), 4);
+
+    // The budget counts iterations, each of which applies the operator twice.
+    let report = iterative::bicgstab(&space, &operator, &b, &Identity, &Convergence::new(1e-14, 3));
+    assert_eq!(report.status(), Status::MaxIterations);
+    assert_eq!(report.iterations(), 3);
+    assert_eq!(report.residuals().len(), 4);
+
+    let zero: Vector<f64> = (0..N).map(|_| 0.0).collect();
+    let report = iterative::gmres(&space, &operator, &zero, &Identity, 5, &Convergence::new(1e-14, 3));
+    assert!(report.converged());
+    assert_eq!(report.iterations(), 0);
+}
+
+#[test]
+fn test_jacobi_rejects_zero_diagonal()
+{
+    let mut coo: CooMatrix<f64> = CooMatrix::new(3, 3);
+    coo.push(0, 0, 2.0).unwrap();
+    coo.push(1, 2, 1.0).unwrap();
+    coo.push(2, 2, 4.0).unwrap();
+    assert!(matches!(Jacobi::from_csr(&coo.to_csr()), Err(decomp::DecompError::Singular { pivot: 1 })));
+    assert!(matches!(Jacobi::new(vec![1.0, 2.0, 0.0]), Err(decomp::DecompError::Singular { pivot: 2 })));
+}
@@ -0,0 +1,180 @@
+use algebra::*;
+use algebra::laws::{Approximate, Counterexample, Exact, Laws, Rng, Shrink, StdRng};
+use vector::vspace;
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 4
+    }
+}
+
+fn complex(rng: &mut StdRng) -> Complex<f64>
+{
+    Complex::new(rng.gen_range(-10.0, 10.0), rng.gen_range(-10.0, 10.0))
+}
+
+#[test]
+fn test_primitive_laws()
+{
+    Laws::default().ring(|rng| rng.gen_range(-1000i64, 1000), Exact).unwrap();
+    Laws::default().field(|rng| rng.gen_range(-10.0f64, 10.0), Approximate(Tolerance::absolute(1e-9))).unwrap();
+    Laws::default().field(complex, Approximate(Tolerance::absolute(1e-9))).unwrap();
+}
+
+#[test]
+fn test_complex_state_space()
+{
+    let space: StateSpace<Complex<f64>> = StateSpace::new();
+    let states = |rng: &mut StdRng| (0..4).map(|_| complex(rng)).collect();
+    Laws::default()
+        .vector_space(&space, states, complex, Approximate(Tolerance::absolute(1e-9)))
+        .unwrap();
+}
+
+#[test]
+fn test_rounding_breaks_exact_laws()
+{
+    let error: Counterexample<f64, ()> = Laws::default()
+        .field(|rng| rng.gen_range(-10.0f64, 10.0), Exact)
+        .unwrap_err();
+    assert_eq!(error.law, "multiplication is associative");
+    let (a, b, c): (f64, f64, f64) = (error.scalars[0], error.scalars[1], error.scalars[2]);
+    assert!((a * b) * c != a * (b * c));
+}
+
+#[test]
+fn test_counterexamples_are_minimal()
+{
+    // Identifies everything outside of `[-4, 4]` with nothing, so every law
+    // breaks as soon as a side leaves that range.
+    let bounded = |l: &i64, r: &i64| l == r && l.abs() < 5;
+    let error: Counterexample<i64, ()> = Laws::default()
+        .ring(|rng| rng.gen_range(-1000i64, 1000), bounded)
+        .unwrap_err();
+
+    assert_eq!(error.law, "addition is associative");
+    assert_eq!(error.scalars.iter().sum::<i64>().abs(), 5);
+    for (idx, scalar) in error.scalars.iter().enumerate() {
+        for smaller in scalar.shrink() {
+            let mut s: Vec<i64> = error.scalars.clone();
+            s[idx] = smaller;
+            assert!(s.iter().sum::<i64>().abs() < 5);
+        }
+    }
+}
+
+#[test]
+fn test_float_shrinks_stay_finite()
+{
+    assert_eq!(f64::MAX.shrink(), vec![0.0]);
+    for value in [-1.5e303, 1.25e300, 2.5].iter() {
+        let candidates: Vec<f64> = value.shrink();
+        assert!(candidates.iter().all(|val| val.is_finite()), "Got: {:?}", candidates);
+    }
+}
+
+#[test]
+fn test_vector_counterexamples_shrink_entries()
+{
+    let space: StateSpace<i64> = StateSpace::new();
+    let states = |rng: &mut StdRng| (0..4).map(|_| rng.gen_range(-1000i64, 1000)).collect();
+    let bounded = |l: &i64, r: &i64| l == r && l.abs() < 5;
+    // The scalars stay small enough for their own ring axioms to hold.
+    let error: Counterexample<i64, State<i64>> = Laws::default()
+        .module(&space, states, |rng| rng.gen_range(-1i64, 2), bounded)
+        .unwrap_err();
+
+    assert_eq!(error.law, "vector addition is associative");
+    assert_eq!(error.vectors.len(), 3);
+    let sums: Vec<i64> = (0..4).map(|k| error.vectors.iter().map(|v| v[k]).sum()).collect();
+    assert_eq!(sums.iter().filter(|sum| sum.abs() >= 5).count(), 1);
+    assert_eq!(sums.iter().map(|sum| sum.abs()).max(), Some(5));
+}
+
+#[test]
+fn test_vector_spaces_check_their_scalars()
+{
+    let space: StateSpace<f64> = StateSpace::new();
+    let states = |rng: &mut StdRng| (0..4).map(|_| rng.gen_range(-10.0f64, 10.0)).collect();
+    let error: Counterexample<f64, State<f64>> = Laws::default()
+        .vector_space(&space, states, |rng| rng.gen_range(-10.0f64, 10.0), Exact)
+        .unwrap_err();
+    assert_eq!(error.law, "multiplication is associative");
+    assert!(error.vectors.is_empty());
+}
+
+#[test]
+fn test_vector_spaces_check_the_field_axioms()
+{
+    // Small integers keep every ring axiom exact, but not every reciprocal.
+    let space: StateSpace<f64> = StateSpace::new();
+    let small = |rng: &mut StdRng| rng.gen_range(-60i64, 60) as f64;
+    let states = move |rng: &mut StdRng| (0..4).map(|_| small(rng)).collect();
+    let error: Counterexample<f64, State<f64>> = Laws::default()
+        .vector_space(&space, states, small, Exact)
+        .unwrap_err();
+    assert_eq!(error.law, "non-zero scalars have a multiplicative inverse");
+    assert!(error.scalars[0] * (1.0 / error.scalars[0]) != 1.0);
+}
+
+/// A space whose addition drops the entries past the shorter operand.
+struct Ragged;
+
+impl VectorSpace for Ragged
+{
+    type Scalar = i64;
+
+    type Vector = Vec<i64>;
+}
+
+impl FiniteDimensional for Ragged
+{
+    fn dimension(&self) -> usize
+    {
+        3
+    }
+}
+
+impl VAdd for Ragged
+{
+    type Vector = Vec<i64>;
+
+    fn vadd(&self, lhs: &Vec<i64>, rhs: &Vec<i64>) -> Vec<i64>
+    {
+        lhs.iter().zip(rhs).map(|(l, r)| l + r).collect()
+    }
+}
+
+impl VScale for Ragged
+{
+    type Scalar = i64;
+
+    type Vector = Vec<i64>;
+
+    fn vscale(&self, vector: &Vec<i64>, scalar: &i64) -> Vec<i64>
+    {
+        vector.iter().map(|val| val * scalar).collect()
+    }
+}
+
+impl VAdditiveInverse for Ragged
+{
+    type Vector = Vec<i64>;
+
+    fn additive_inv(&self, vector: &Vec<i64>) -> Vec<i64>
+    {
+        vector.iter().map(|val| -val).collect()
+    }
+}
+
+#[test]
+fn test_vectors_of_different_lengths_differ()
+{
+    let vectors = |rng: &mut StdRng| (0..rng.gen_range(2, 4)).map(|_| rng.gen_range(-9i64, 9)).collect();
+    let error: Counterexample<i64, Vec<i64>> = Laws::default()
+        .module(&Ragged, vectors, |rng| rng.gen_range(-9i64, 9), Exact)
+        .unwrap_err();
+    assert_eq!(error.law, "vectors have an additive inverse");
+    assert_eq!(error.vectors[0].len(), 2);
+}
@@ -0,0 +1,205 @@
+use algebra::*;
+use vector::{mspace, vspace, Dense};
+use vector::mtx::{self, Field, MtxError, Symmetry};
+use vector::sparse::{CooMatrix, CsrMatrix};
+
+mspace! {
+    SquareSpace {
+        matrix: Square,
+        dimension: 3
+    }
+}
+
+mspace! {
+    WideSpace {
+        matrix: Wide,
+        rows: 2,
+        columns: 3
+    }
+}
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 3
+    }
+}
+
+fn parse_error<T>(result: Result<T, MtxError>) -> usize
+{
+    match result {
+        Err(MtxError::Parse { line, .. }) => line,
+        Err(other) => panic!("Unexpected error: {}", other),
+        Ok(_) => panic!("Parsed an invalid file")
+    }
+}
+
+#[test]
+fn test_read_coordinate_symmetries()
+{
+    let symmetric: &str = "\
+%%MatrixMarket matrix coordinate real symmetric
+% A comment, followed by a blank line
+
+3 3 4
+1 1 4.0
+2 1 -1.5
+3 2 2e-1
+3 3 1
+";
+    let a: Square<f64> = mtx::read_dense(&SquareSpace::new(), symmetric.as_bytes()).unwrap();
+    assert_eq!(a.as_slice(), &[4.0, -1.5, 0.0, -1.5, 0.0, 0.2, 0.0, 0.2, 1.0]);
+    let csr: CsrMatrix<f64> = mtx::read_csr(symmetric.as_bytes()).unwrap();
+    assert_eq!(csr.nnz(), 6);
+
+    let hermitian: &str = "\
+%%MatrixMarket matrix coordinate complex hermitian
+2 2 2
+1 1 2.0 0.0
+2 1 1.0 -3.0
+";
+    let h: CooMatrix<Complex<f64>> = mtx::read_coo(hermitian.as_bytes()).unwrap();
+    let h: Vec<Complex<f64>> = h.to_csr().to_dense();
+    assert_eq!(h, vec![Complex::new(2.0, 0.0), Complex::new(1.0, 3.0), Complex::new(1.0, -3.0), Complex::new(0.0, 0.0)]);
+
+    let skew: &str = "\
+%%MatrixMarket matrix coordinate integer skew-symmetric
+3 3 2
+2 1 5
+3 2 -7
+";
+    let s: Vec<i64> = mtx::read_csc::<_, i64>(skew.as_bytes()).unwrap().to_dense();
+    assert_eq!(s, vec![0, -5, 0, 5, 0, 7, 0, -7, 0]);
+
+    let pattern: &str = "\
+%%MatrixMarket matrix coordinate pattern general
+2 3 3
+1 3
+2 1
+2 2
+";
+    let p: Wide<f64> = mtx::read_dense(&WideSpace::new(), pattern.as_bytes()).unwrap();
+    assert_eq!(p.as_slice(), &[0.0, 0.0, 1.0, 1.0, 1.0, 0.0]);
+}
+
+#[test]
+fn test_read_array_format()
+{
+    let general: &str = "\
+%%MatrixMarket matrix array real general
+2 3
+1
+4
+2
+5
+3
+6
+";
+    let a: Wide<f64> = mtx::read_dense(&WideSpace::new(), general.as_bytes()).unwrap();
+    assert_eq!(a.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+    // Only the lower triangle, column by column.
+    let symmetric: &str = "\
+%%MatrixMarket matrix array complex symmetric
+2 2
+1 1
+2 0
+3 -1
+";
+    let s: Vec<Complex<f64>> = mtx::read_csr(symmetric.as_bytes()).unwrap().to_dense();
+    assert_eq!(s, vec![Complex::new(1.0, 1.0), Complex::new(2.0, 0.0), Complex::new(2.0, 0.0), Complex::new(3.0, -1.0)]);
+
+    // Integers and reals are read into complex and real elements alike.
+    let column: &str = "%%MatrixMarket matrix array integer general\n3 1\n1\n-2\n3\n";
+    let x: State<Complex<f64>> = mtx::read_vector(&StateSpace::new(), column.as_bytes()).unwrap();
+    assert_eq!(x.view().to_vec(), vec![Complex::new(1.0, 0.0), Complex::new(-2.0, 0.0), Complex::new(3.0, 0.0)]);
+    let row: &str = "%%MatrixMarket matrix coordinate real general\n1 3 1\n1 2 0.5\n";
+    let y: State<f64> = mtx::read_vector(&StateSpace::new(), row.as_bytes()).unwrap();
+    assert_eq!(y.view().to_vec(), vec![0.0, 0.5, 0.0]);
+}
+
+#[test]
+fn test_errors_carry_line_numbers()
+{
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>("3 3 1\n1 1 1\n".as_bytes())), 1);
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>("%%MatrixMarket matrix coordinate real hermitian\n".as_bytes())), 1);
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>("%%MatrixMarket matrix array pattern general\n".as_bytes())), 1);
+
+    let file: &str = "%%MatrixMarket matrix coordinate real general\n% comment\n2 2 3\n1 1 1.0\n2 x 1.0\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 5);
+    let file: &str = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n3 1 1.0\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 4);
+    let file: &str = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 one\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 3);
+    let file: &str = "%%MatrixMarket matrix coordinate real general\n2 2 2\n0 1 1.0\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 3);
+    let file: &str = "%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 4);
+    let file: &str = "%%MatrixMarket matrix array real general\n1 2\n1.0\n2.0\n3.0\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 5);
+    let file: &str = "%%MatrixMarket matrix array real symmetric\n2 3\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 2);
+
+    // Oversized headers fail on their sizes or their missing entries, not on allocation.
+    let file: &str = "%%MatrixMarket matrix array real general\n18446744073709551615 2\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 2);
+    let file: &str = "%%MatrixMarket matrix array real general\n1000000 1000000\n1.0\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, f64>(file.as_bytes())), 4);
+    let file: &str = "%%MatrixMarket matrix array complex hermitian\n2 2\n1 0\n2 1\n3 1\n";
+    assert_eq!(parse_error(mtx::read_coo::<_, Complex<f64>>(file.as_bytes())), 5);
+
+    let file: &str = "%%MatrixMarket matrix coordinate complex general\n1 1 1\n1 1 1.0 2.0\n";
+    assert!(matches!(mtx::read_coo::<_, f64>(file.as_bytes()), Err(MtxError::FieldMismatch { field: Field::Complex, .. })));
+    let file: &str = "%%MatrixMarket matrix coordinate real general\n1 1 1\n1 1 1.5\n";
+    assert!(matches!(mtx::read_coo::<_, i64>(file.as_bytes()), Err(MtxError::FieldMismatch { field: Field::Real, .. })));
+
+    let file: &str = "%%MatrixMarket matrix array real general\n3 2\n1\n2\n3\n4\n5\n6\n";
+    match mtx::read_dense::<_, _, Wide<f64>, f64>(&WideSpace::new(), file.as_bytes()) {
+        Err(MtxError::DimensionMismatch { expected, found }) => assert_eq!((expected, found), ((2, 3), (3, 2))),
+        other => panic!("Unexpected: {:?}", other.map(|_| ()))
+    }
+    assert!(mtx::read_vector::<_, _, State<f64>, f64>(&StateSpace::new(), file.as_bytes()).is_err());
+
+    let mut output: Vec<u8> = Vec::new();
+    match mtx::write_coordinate(&mut output, 2, 3, Symmetry::General, vec![(0, 1, 1.0), (2, 0, 2.0)]) {
+        Err(MtxError::EntryOutOfBounds { entry, shape }) => assert_eq!((entry, shape), ((2, 0), (2, 3))),
+        other => panic!("Unexpected: {:?}", other)
+    }
+    assert!(output.is_empty());
+}
+
+#[test]
+fn test_round_trips()
+{
+    let a: Wide<Complex<f64>> = (1..=6).map(|k| Complex::new(k as f64 / 3.0, -(k as f64))).collect();
+    let mut output: Vec<u8> = Vec::new();
+    mtx::write_dense(&mut output, &a).unwrap();
+    assert!(output.starts_with(b"%%MatrixMarket matrix array complex general\n2 3\n"));
+    let b: Wide<Complex<f64>> = mtx::read_dense(&WideSpace::new(), output.as_slice()).unwrap();
+    assert_eq!(a.as_slice(), b.as_slice());
+
+    let x: State<f64> = State::new(vec![0.1, 1e-300, -7.0]);
+    let mut output: Vec<u8> = Vec::new();
+    mtx::write_vector(&mut output, &x).unwrap();
+    let y: State<f64> = mtx::read_vector(&StateSpace::new(), output.as_slice()).unwrap();
+    assert_eq!(x.view().to_vec(), y.view().to_vec());
+
+    let csr: CsrMatrix<i64> = CooMatrix::from_triplets(3, 4, vec![(0, 3, 7), (2, 0, -1), (1, 1, 2)]).unwrap().to_csr();
+    let mut output: Vec<u8> = Vec::new();
+    mtx::write_csr(&mut output, &csr).unwrap();
+    assert_eq!(String::from_utf8(output.clone()).unwrap(), "%%MatrixMarket matrix coordinate integer general\n3 4 3\n1 4 7\n2 2 2\n3 1 -1\n");
+    assert_eq!(mtx::read_csr::<_, i64>(output.as_slice()).unwrap(), csr);
+
+    // Only the lower triangle of a Hermitian matrix is written.
+    let h: Vec<(usize, usize, Complex<f64>)> = vec![
+        (0, 0, Complex::new(1.0, 0.0)),
+        (0, 1, Complex::new(2.0, 1.0)),
+        (1, 0, Complex::new(2.0, -1.0))
+    ];
+    let mut output: Vec<u8> = Vec::new();
+    mtx::write_coordinate(&mut output, 2, 2, Symmetry::Hermitian, h.clone()).unwrap();
+    assert!(output.starts_with(b"%%MatrixMarket matrix coordinate complex hermitian\n2 2 2\n"));
+    let read: Vec<Complex<f64>> = mtx::read_csr(output.as_slice()).unwrap().to_dense();
+    let expected: Vec<Complex<f64>> = CooMatrix::from_triplets(2, 2, h).unwrap().to_csr().to_dense();
+    assert_eq!(read, expected);
+}
@@ -0,0 +1,159 @@
+use algebra::*;
+use algebra::laws::{Laws, Rng, StdRng};
+use vector::vspace;
+
+vspace! {
+    TripleSpace {
+        vector: Triple,
+        dimension: 3
+    }
+}
+
+type Wide = BigFloat<256>;
+
+fn quad(s: &str) -> Quad
+{
+    s.parse().unwrap()
+}
+
+fn power_of_two<const P: usize>(exponent: i64) -> BigFloat<P>
+{
+    let two: BigFloat<P> = BigFloat::from(2);
+    let power: BigFloat<P> = (0..exponent.abs()).fold(BigFloat::from(1), |acc, _| acc * two.clone());
+    if exponent >= 0 { power } else { BigFloat::from(1) / power }
+}
+
+/// Equal up to a few ulps of the larger operand, for the field laws.
+fn close<const P: usize>(lhs: &BigFloat<P>, rhs: &BigFloat<P>) -> bool
+{
+    let scale: BigFloat<P> = std::cmp::max(lhs.clone().abs(), rhs.clone().abs());
+    (lhs.clone() - rhs.clone()).abs() <= scale * BigFloat::epsilon() * BigFloat::from(16)
+}
+
+#[test]
+fn test_correct_rounding()
+{
+    assert_eq!(Quad::from(1) + Quad::from(2), Quad::from(3));
+    assert_eq!(Quad::from(1) / Quad::from(4), Quad::from(0.25));
+    assert_eq!(Quad::from(-7) * Quad::from(6), Quad::from(-42));
+    assert!(close(&(Quad::from(2).sqrt() * Quad::from(2).sqrt()), &Quad::from(2)));
+    assert_eq!(Quad::from(144).sqrt(), Quad::from(12));
+    assert_eq!(Quad::epsilon(), power_of_two(-112));
+
+    // Ties round to even.
+    let one: BigFloat<4> = BigFloat::from(1);
+    let half_ulp: BigFloat<4> = power_of_two(-4);
+    assert_eq!(one.clone() + half_ulp.clone(), one);
+    assert_eq!(BigFloat::<4>::from(9) / BigFloat::from(8) + half_ulp.clone(), BigFloat::from(10) / BigFloat::from(8));
+    // Far smaller addends are absorbed on either side.
+    let nudge: BigFloat<4> = power_of_two(-200);
+    assert_eq!(one.clone() + nudge.clone(), one);
+    assert_eq!(one.clone() - nudge.clone(), one);
+    assert_eq!((one.clone() - half_ulp - nudge).to_f64(), 0.9375);
+
+    // One third, to 113 bits and back to the nearest f64.
+    let third: Quad = Quad::from(1) / Quad::from(3);
+    assert_eq!(third.to_f64(), 1.0 / 3.0);
+    assert_eq!(third, Quad::from_ratio(BigInt::from(1), BigInt::from(3)));
+    assert_eq!(format!("{}", third), "3.33333333333333333333333333333333317e-1");
+    assert_eq!(format!("{:.4}", -third), "-3.3333e-1");
+    assert_eq!(format!("{:?}", Quad::from(1e300) * Quad::from(1e300)), "1.00000000000000010500952051040884325e600");
+    assert_eq!(Quad::from(f64::MIN_POSITIVE / 8.0).to_f64(), f64::MIN_POSITIVE / 8.0);
+}
+
+#[test]
+fn test_parse_and_order()
+{
+    assert_eq!(quad("0.1"), Quad::from(1) / Quad::from(10));
+    assert_eq!(quad("-12.5e-1"), Quad::from(-1.25));
+    assert_eq!(quad("+3E2"), Quad::from(300));
+    assert_eq!(quad("0"), Quad::zero());
+    assert_eq!("1.2.3".parse::<Quad>(), Err(ParseBigFloatError));
+    assert_eq!("".parse::<Quad>(), Err(ParseBigFloatError));
+    assert_eq!("e5".parse::<Quad>(), Err(ParseBigFloatError));
+    assert_ne!(quad("0.1").to_f64(), 0.1 + 1e-17);
+
+    assert_eq!("1e1000000000".parse::<Quad>(), Err(ParseBigFloatError));
+    assert_eq!("1e-9223372036854775808".parse::<Quad>(), Err(ParseBigFloatError));
+    assert_eq!("0.5e-9223372036854775808".parse::<Quad>(), Err(ParseBigFloatError));
+    assert_eq!("1e9223372036854775808".parse::<Quad>(), Err(ParseBigFloatError));
+    assert_eq!(quad("1e100000").to_f64(), f64::INFINITY);
+    assert!(quad("9.99e99999") < quad("1e100000") && quad("1e100000") < quad("1.01e100000"));
+    assert!(Quad::zero() < quad("1e-100000") && quad("1e-100000") < quad("1e-99999"));
+
+    let mut values: Vec<Quad> = vec![quad("1e-40"), quad("-2"), Quad::zero(), quad("3.5"), quad("-1e-40"), quad("3")];
+    values.sort();
+    assert_eq!(values, vec![quad("-2"), quad("-1e-40"), Quad::zero(), quad("1e-40"), quad("3"), quad("3.5")]);
+}
+
+#[test]
+fn test_catastrophic_cancellation()
+{
+    // (1 + 2^-100) - 1 is lost in f64 but exact at quadruple precision.
+    let tiny: Quad = power_of_two(-100);
+    assert_eq!((Quad::from(1) + tiny.clone()) - Quad::from(1), tiny);
+    assert_eq!((1.0 + 2f64.powi(-100)) - 1.0, 0.0);
+
+    // (1 - cos x) / x² near zero, with cos x from its Taylor series: the
+    // leading ten digits cancel.
+    let expected: f64 = 0.5 - 1e-10 / 24.0;
+    let x: Quad = quad("1e-5");
+    let square: Quad = x.clone() * x;
+    let cos: Quad = Quad::from(1) - square.clone() / Quad::from(2) + square.clone() * square.clone() / Quad::from(24);
+    let ratio: f64 = ((Quad::from(1) - cos) / square).to_f64();
+    assert!((ratio - expected).abs() < 1e-15);
+    let naive: f64 = (1.0 - 1e-5f64.cos()) / 1e-10;
+    assert!((naive - expected).abs() > 1e-9);
+
+    // Rump's polynomial, where f64 has no correct digits.
+    let rump = |a: Wide, b: Wide| -> Wide {
+        let (a2, b2): (Wide, Wide) = (a.clone() * a.clone(), b.clone() * b.clone());
+        let (b4, b6, b8): (Wide, Wide, Wide) = (b2.clone() * b2.clone(), b2.clone() * b2.clone() * b2.clone(), b2.clone() * b2.clone() * b2.clone() * b2.clone());
+        "333.75".parse::<Wide>().unwrap() * b6.clone()
+            + a2.clone() * (Wide::from(11) * a2 * b2 - b6 - Wide::from(121) * b4 - Wide::from(2))
+            + "5.5".parse::<Wide>().unwrap() * b8
+            + a / (Wide::from(2) * b)
+    };
+    let value: Wide = rump(Wide::from(77617), Wide::from(33096));
+    assert!((value.clone() - "-0.827396059946821368141165095479816".parse().unwrap()).abs() < "1e-30".parse().unwrap());
+    assert_eq!(format!("{:.17}", value), "-8.27396059946821368e-1");
+
+    let (a, b): (f64, f64) = (77617.0, 33096.0);
+    let naive: f64 = 333.75 * b.powi(6) + a * a * (11.0 * a * a * b * b - b.powi(6) - 121.0 * b.powi(4) - 2.0) + 5.5 * b.powi(8) + a / (2.0 * b);
+    assert!((naive - value.to_f64()).abs() > 1.0);
+}
+
+#[test]
+fn test_extended_precision_spaces()
+{
+    let space: TripleSpace<Quad> = TripleSpace::new();
+    let large: Triple<Quad> = vec![quad("1e20"), Quad::from(1), quad("-1e20")].into_iter().collect();
+    let ones: Triple<Quad> = vec![Quad::from(1), Quad::from(1), Quad::from(1)].into_iter().collect();
+    assert_eq!(space.dotv(&large, &ones), Quad::from(1));
+
+    let floats: TripleSpace<f64> = TripleSpace::new();
+    let (x, y): (Triple<f64>, Triple<f64>) = (vec![1e20, 1.0, -1e20].into_iter().collect(), vec![1.0, 1.0, 1.0].into_iter().collect());
+    assert_eq!(floats.dotv(&x, &y), 0.0);
+
+    let sum: Triple<Quad> = space.vaxpy(&Quad::from(-1), &large, &large);
+    assert!(space.eq(&sum, &vec![Quad::zero(), Quad::zero(), Quad::zero()].into_iter().collect()));
+    assert!(space.eq(&space.vscale(&ones, &Quad::from(2)), &space.vaxpy(&Quad::from(1), &ones, &ones)));
+}
+
+#[test]
+fn test_bigfloat_field_laws()
+{
+    // Integers keep sums and products exact, so cancellation cannot magnify
+    // the rounding of the inverses.
+    let value = |rng: &mut StdRng| Quad::from(rng.gen_range(-1_000_000, 1_000_000));
+    Laws::default().field(value, close).unwrap();
+    let value = |rng: &mut StdRng| BigFloat::<24>::from(rng.gen_range(-1000, 1000));
+    Laws::default().field(value, close).unwrap();
+}
+
+#[test]
+#[should_panic(expected = "division by zero")]
+fn test_division_by_zero()
+{
+    let _ = Quad::from(1) / Quad::zero();
+}
@@ -0,0 +1,169 @@
+#![cfg(feature = "npy")]
+
+use std::fs::File;
+use std::io::Cursor;
+
+use algebra::*;
+use vector::{mspace, vspace, Dense};
+use vector::npy::{self, NpyError, NpzReader, NpzWriter};
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 4
+    }
+}
+
+vspace! {
+    ShortSpace {
+        vector: Short,
+        dimension: 3
+    }
+}
+
+mspace! {
+    WideSpace {
+        matrix: Wide,
+        rows: 2,
+        columns: 3
+    }
+}
+
+mspace! {
+    TallSpace {
+        matrix: Tall,
+        rows: 3,
+        columns: 2
+    }
+}
+
+fn fixture(name: &str) -> File
+{
+    File::open(format!("{}/tests/fixtures/npy/{}", env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+}
+
+fn bytes(name: &str) -> Vec<u8>
+{
+    std::fs::read(format!("{}/tests/fixtures/npy/{}", env!("CARGO_MANIFEST_DIR"), name)).unwrap()
+}
+
+fn psi() -> Vec<Complex<f64>>
+{
+    vec![Complex::new(1.0, 2.0), Complex::new(-0.5, 0.0), Complex::new(0.0, -1.0), Complex::new(3.0, 4.0)]
+}
+
+#[test]
+fn test_read_vectors_in_both_byte_orders()
+{
+    let space = StateSpace::new();
+    let expected: Vec<f64> = vec![1.5, -2.0, 3.25, 0.0];
+
+    let little: State<f64> = npy::read_vector(&space, fixture("f64_le.npy")).unwrap();
+    let big: State<f64> = npy::read_vector(&space, fixture("f64_be.npy")).unwrap();
+    assert_eq!(little.view().to_vec(), expected);
+    assert_eq!(big.view().to_vec(), expected);
+
+    let single: State<f32> = npy::read_vector(&StateSpace::new(), fixture("f32.npy")).unwrap();
+    assert_eq!(single.view().to_vec(), vec![0.5, 1.5, -2.5, 4.0]);
+
+    let integers: State<i64> = npy::read_vector(&StateSpace::new(), fixture("i64_be.npy")).unwrap();
+    assert_eq!(integers.view().to_vec(), vec![1, -2, 3, 1 << 40]);
+
+    let state: State<Complex<f64>> = npy::read_vector(&StateSpace::new(), fixture("c128.npy")).unwrap();
+    assert_eq!(state.view().to_vec(), psi());
+}
+
+#[test]
+fn test_read_matrices_in_both_orders()
+{
+    let space = WideSpace::new();
+    let row_major: Wide<f64> = npy::read_matrix(&space, fixture("matrix_c.npy")).unwrap();
+    let column_major: Wide<f64> = npy::read_matrix(&space, fixture("matrix_fortran.npy")).unwrap();
+    assert_eq!(row_major.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    assert_eq!(column_major.as_slice(), row_major.as_slice());
+
+    let complex: Wide<Complex<f64>> = npy::read_matrix(&WideSpace::new(), fixture("matrix_c128_be_fortran.npy")).unwrap();
+    assert_eq!(complex[(1, 2)], Complex::new(6.0, -6.0));
+    assert_eq!(complex[(0, 1)], Complex::new(2.0, -2.0));
+}
+
+#[test]
+fn test_mismatches_are_errors()
+{
+    match npy::read_vector::<_, _, Short<f64>, f64>(&ShortSpace::new(), fixture("f64_le.npy")) {
+        Err(NpyError::DimensionMismatch { expected, found }) => assert_eq!((expected, found), (vec![3], vec![4])),
+        other => panic!("Unexpected: {:?}", other.map(|_| ()))
+    }
+    match npy::read_vector::<_, _, State<f32>, f32>(&StateSpace::new(), fixture("f64_le.npy")) {
+        Err(NpyError::DtypeMismatch { expected, found }) => assert_eq!((expected.as_str(), found.as_str()), ("<f4", "<f8")),
+        other => panic!("Unexpected: {:?}", other.map(|_| ()))
+    }
+    match npy::read_matrix::<_, _, Tall<f64>, f64>(&TallSpace::new(), fixture("matrix_c.npy")) {
+        Err(NpyError::DimensionMismatch { expected, found }) => assert_eq!((expected, found), (vec![3, 2], vec![2, 3])),
+        other => panic!("Unexpected: {:?}", other.map(|_| ()))
+    }
+    assert!(npy::read_matrix::<_, _, Wide<f64>, f64>(&WideSpace::new(), fixture("f64_le.npy")).is_err());
+
+    let truncated: Vec<u8> = bytes("f64_le.npy")[..150].to_vec();
+    assert!(matches!(npy::read::<_, f64>(Cursor::new(truncated)), Err(NpyError::Io(_))));
+    assert!(matches!(npy::read::<_, f64>(Cursor::new(b"not an array".to_vec())), Err(NpyError::InvalidHeader(_))));
+
+    // A shape whose size overflows is rejected before anything is allocated.
+    let header: &[u8] = b"{'descr': '<f8', 'fortran_order': False, 'shape': (4294967296, 4294967296), }\n";
+    let mut huge: Vec<u8> = b"\x93NUMPY\x01\x00".to_vec();
+    huge.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    huge.extend_from_slice(header);
+    assert!(matches!(npy::read::<_, f64>(Cursor::new(huge)), Err(NpyError::InvalidHeader(_))));
+}
+
+#[test]
+fn test_written_files_match_numpy()
+{
+    let x: State<f64> = State::new(vec![1.5, -2.0, 3.25, 0.0]);
+    let mut output: Vec<u8> = Vec::new();
+    npy::write_vector(&mut output, &x).unwrap();
+    assert_eq!(output, bytes("f64_le.npy"));
+
+    let a: Wide<f64> = Wide::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    let mut output: Vec<u8> = Vec::new();
+    npy::write_matrix(&mut output, &a).unwrap();
+    assert_eq!(output, bytes("matrix_c.npy"));
+
+    // A strided view of every other entry.
+    let mut output: Vec<u8> = Vec::new();
+    npy::write_vector(&mut output, &x.view().step_by(2)).unwrap();
+    assert_eq!(npy::read::<_, f64>(Cursor::new(output)).unwrap().data(), &[1.5, 3.25]);
+}
+
+#[test]
+fn test_npz_archives()
+{
+    for name in &["savez.npz", "savez_compressed.npz"] {
+        let mut archive = NpzReader::new(fixture(name)).unwrap();
+        let mut names: Vec<String> = archive.names();
+        names.sort();
+        assert_eq!(names, vec!["hamiltonian", "psi"]);
+
+        let state: State<Complex<f64>> = archive.vector(&StateSpace::new(), "psi").unwrap();
+        assert_eq!(state.view().to_vec(), psi());
+        let hamiltonian: Wide<f64> = archive.matrix(&WideSpace::new(), "hamiltonian").unwrap();
+        assert_eq!(hamiltonian.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+        assert!(matches!(archive.array::<f64>("missing"), Err(NpyError::MissingArray(_))));
+    }
+
+    let state: State<Complex<f64>> = psi().into_iter().collect();
+    let a: Wide<f64> = Wide::new(vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+    for &compressed in &[false, true] {
+        let cursor: Cursor<Vec<u8>> = Cursor::new(Vec::new());
+        let mut writer = if compressed { NpzWriter::compressed(cursor) } else { NpzWriter::new(cursor) };
+        writer.add_vector("psi", &state).unwrap();
+        writer.add_matrix("hamiltonian", &a).unwrap();
+        let archive: Vec<u8> = writer.finish().unwrap().into_inner();
+
+        let mut reader = NpzReader::new(Cursor::new(archive)).unwrap();
+        let read: State<Complex<f64>> = reader.vector(&StateSpace::new(), "psi").unwrap();
+        assert_eq!(read.view().to_vec(), psi());
+        let read: Wide<f64> = reader.matrix(&WideSpace::new(), "hamiltonian").unwrap();
+        assert_eq!(read.as_slice(), a.as_slice());
+    }
+}
@@ -0,0 +1,193 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use algebra::*;
+use vector::{mspace, vspace};
+use vector::operator::{Kronecker, MatrixOperator};
+use vector::sparse::CooMatrix;
+
+mod common;
+use common::{assert_close, random_complex, random_complexes};
+
+mspace! {
+    WideSpace {
+        matrix: Wide,
+        rows: 3,
+        columns: 4
+    }
+}
+
+mspace! {
+    SquareSpace {
+        matrix: Square,
+        dimension: 4
+    }
+}
+
+mspace! {
+    QubitSpace {
+        matrix: Qubit,
+        dimension: 2
+    }
+}
+
+mspace! {
+    ProductSpace {
+        matrix: Product,
+        rows: 6,
+        columns: 8
+    }
+}
+
+vspace! {
+    TwoSpace {
+        vector: Two,
+        dimension: 2
+    }
+}
+
+vspace! {
+    ThreeSpace {
+        vector: Three,
+        dimension: 3
+    }
+}
+
+vspace! {
+    FourSpace {
+        vector: Four,
+        dimension: 4
+    }
+}
+
+vspace! {
+    SixSpace {
+        vector: Six,
+        dimension: 6
+    }
+}
+
+vspace! {
+    EightSpace {
+        vector: Eight,
+        dimension: 8
+    }
+}
+
+type C64 = Complex<f64>;
+
+/// The Hermitian inner product `⟨x, y⟩`.
+fn inner<'a, I>(x: I, y: I) -> C64
+where
+    I: IntoIterator<Item=&'a C64>
+{
+    x.into_iter().zip(y).map(|(l, r)| l.conj() * r).sum()
+}
+
+#[test]
+fn test_dense_adjoint_identity()
+{
+    let mut rng = StdRng::seed_from_u64(34);
+    let a: Wide<C64> = random_complexes(&mut rng, 12);
+    let operator: MatrixOperator<_, FourSpace<C64>, ThreeSpace<C64>> = MatrixOperator::new(a);
+    let x: Four<C64> = random_complexes(&mut rng, 4);
+    let y: Three<C64> = random_complexes(&mut rng, 3);
+
+    let lhs: C64 = inner(&y, &operator.apply(&x));
+    let rhs: C64 = inner(&operator.adjoint_apply(&y), &x);
+    assert!((lhs - rhs).norm() < 1e-12);
+
+    let mut output: Three<C64> = random_complexes(&mut rng, 3);
+    operator.apply_mut(&x, &mut output);
+    assert_close(&output, &operator.apply(&x), 1e-12);
+}
+
+#[test]
+fn test_sparse_operators_match_dense()
+{
+    let mut rng = StdRng::seed_from_u64(35);
+    let triplets: Vec<(usize, usize, C64)> = (0..7)
+        .map(|_| (rng.gen_range(0, 3), rng.gen_range(0, 4), random_complex(&mut rng)))
+        .collect();
+    let coo: CooMatrix<C64> = CooMatrix::from_triplets(3, 4, triplets).unwrap();
+    let dense: MatrixOperator<Wide<C64>, FourSpace<C64>, ThreeSpace<C64>> = MatrixOperator::new(coo.to_csr().to_dense());
+    let csr: MatrixOperator<_, FourSpace<C64>, ThreeSpace<C64>> = MatrixOperator::from_csr(coo.to_csr());
+    let csc: MatrixOperator<_, FourSpace<C64>, ThreeSpace<C64>> = MatrixOperator::from_csc(coo.to_csc());
+
+    let x: Four<C64> = random_complexes(&mut rng, 4);
+    let y: Three<C64> = random_complexes(&mut rng, 3);
+    assert_close(&csr.apply(&x), &dense.apply(&x), 1e-12);
+    assert_close(&csc.apply(&x), &dense.apply(&x), 1e-12);
+    assert_close(&csr.adjoint_apply(&y), &dense.adjoint_apply(&y), 1e-12);
+    assert_close(&csc.adjoint_apply(&y), &dense.adjoint_apply(&y), 1e-12);
+}
+
+#[test]
+#[should_panic]
+fn test_mismatched_dimensions_are_rejected()
+{
+    let a: Wide<f64> = (0..12).map(|val| val as f64).collect();
+    let _: MatrixOperator<_, ThreeSpace<f64>, FourSpace<f64>> = MatrixOperator::new(a);
+}
+
+#[test]
+fn test_combinators()
+{
+    let mut rng = StdRng::seed_from_u64(36);
+    let a: MatrixOperator<Wide<C64>, FourSpace<C64>, ThreeSpace<C64>> = MatrixOperator::new(random_complexes(&mut rng, 12));
+    let b: MatrixOperator<Square<C64>, FourSpace<C64>, FourSpace<C64>> = MatrixOperator::new(random_complexes(&mut rng, 16));
+    let alpha: C64 = random_complex(&mut rng);
+    let x: Four<C64> = random_complexes(&mut rng, 4);
+    let y: Three<C64> = random_complexes(&mut rng, 3);
+
+    let composition = (&a).compose(&b);
+    assert_close(&composition.apply(&x), &a.apply(&b.apply(&x)), 1e-12);
+    assert_close(&composition.adjoint_apply(&y), &b.adjoint_apply(&a.adjoint_apply(&y)), 1e-12);
+
+    let expected: Three<C64> = a.apply(&x).into_iter().map(|val| val * (alpha + 1.0)).collect();
+    let sum = (&a).sum((&a).scale(alpha));
+    assert_close(&sum.apply(&x), &expected, 1e-12);
+
+    let expected: Four<C64> = a.adjoint_apply(&y).into_iter().map(|val| val * (alpha.conj() + 1.0)).collect();
+    assert_close(&sum.adjoint_apply(&y), &expected, 1e-12);
+}
+
+#[test]
+fn test_kronecker_matches_explicit_product()
+{
+    let mut rng = StdRng::seed_from_u64(37);
+    let a: Wide<C64> = random_complexes(&mut rng, 12);
+    let b: Qubit<C64> = random_complexes(&mut rng, 4);
+    let explicit: Product<C64> = (0..48)
+        .map(|idx| {
+            let (row, column) = (idx / 8, idx % 8);
+            a[(row / 2, column / 2)] * b[(row % 2, column % 2)]
+        })
+        .collect();
+
+    let outer: MatrixOperator<_, FourSpace<C64>, ThreeSpace<C64>> = MatrixOperator::new(a);
+    let inner: MatrixOperator<_, TwoSpace<C64>, TwoSpace<C64>> = MatrixOperator::new(b);
+    let product: Kronecker<_, _, EightSpace<C64>, SixSpace<C64>> = Kronecker::new(outer, inner);
+    let explicit: MatrixOperator<_, EightSpace<C64>, SixSpace<C64>> = MatrixOperator::new(explicit);
+
+    let x: Eight<C64> = random_complexes(&mut rng, 8);
+    let y: Six<C64> = random_complexes(&mut rng, 6);
+    assert_close(&product.apply(&x), &explicit.apply(&x), 1e-12);
+    assert_close(&product.adjoint_apply(&y), &explicit.adjoint_apply(&y), 1e-12);
+}
+
+#[test]
+fn test_closures_as_operators()
+{
+    // A cyclic shift and its inverse.
+    let shift = FnOperator::<_, _, FourSpace<f64>, FourSpace<f64>>::new(
+        |x: &Four<f64>| -> Four<f64> { (0..4).map(|i| x[(i + 3) % 4]).collect() },
+        |y: &Four<f64>| -> Four<f64> { (0..4).map(|i| y[(i + 1) % 4]).collect() }
+    );
+    let x: Four<f64> = (1..=4).map(|val| val as f64).collect();
+    let shifted: Four<f64> = shift.apply(&x);
+    assert_eq!(shifted.into_iter().copied().collect::<Vec<f64>>(), vec![4.0, 1.0, 2.0, 3.0]);
+    assert_eq!(shift.adjoint_apply(&shifted).into_iter().copied().collect::<Vec<f64>>(), vec![1.0, 2.0, 3.0, 4.0]);
+
+    let doubled = shift.clone().compose(shift).scale(2.0);
+    assert_eq!(doubled.apply(&x).into_iter().copied().collect::<Vec<f64>>(), vec![6.0, 8.0, 2.0, 4.0]);
+}
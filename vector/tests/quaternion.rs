@@ -0,0 +1,190 @@
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+use algebra::*;
+use algebra::laws::{Approximate, Laws, Rng, StdRng};
+use vector::vspace;
+
+vspace! {
+    EuclideanSpace {
+        vector: Euclidean,
+        dimension: 3
+    }
+}
+
+vspace! {
+    QuaternionSpace {
+        vector: Coordinates,
+        dimension: 4
+    }
+}
+
+type Q = Quaternion<f64>;
+
+fn euclidean(x: f64, y: f64, z: f64) -> Euclidean<f64>
+{
+    vec![x, y, z].into_iter().collect()
+}
+
+fn close(lhs: &Q, rhs: &Q) -> bool
+{
+    lhs.approx_eq(rhs, Tolerance::absolute(1e-12))
+}
+
+fn random_rotation(rng: &mut StdRng) -> Q
+{
+    Q::new(rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0), rng.gen_range(-1.0, 1.0))
+        .normalize()
+        .unwrap()
+}
+
+#[test]
+fn test_hamilton_product()
+{
+    let (one, i, j, k): (Q, Q, Q, Q) = (
+        Q::multiplicative_identity(),
+        Q::new(0.0, 1.0, 0.0, 0.0),
+        Q::new(0.0, 0.0, 1.0, 0.0),
+        Q::new(0.0, 0.0, 0.0, 1.0)
+    );
+    assert_eq!(i * i, -one);
+    assert_eq!(j * j, -one);
+    assert_eq!(k * k, -one);
+    assert_eq!(i * j * k, -one);
+    assert_eq!(i * j, k);
+    assert_eq!(j * i, -k);
+    assert_eq!(j * k, i);
+    assert_eq!(k * i, j);
+
+    let q: Q = Q::new(1.0, -2.0, 3.0, 0.5);
+    assert_eq!(q.conjugate(), Q::new(1.0, 2.0, -3.0, -0.5));
+    assert_eq!(q * q.conjugate(), Q::real(q.norm_squared()));
+    assert!(close(&(q * q.inverse().unwrap()), &one));
+    assert!(close(&(q / q), &one));
+    assert!((q.normalize().unwrap().norm() - 1.0).abs() < 1e-15);
+    assert_eq!(Q::additive_identity().inverse(), None);
+    assert_eq!(Q::additive_identity().normalize(), None);
+}
+
+#[test]
+fn test_quaternion_ring_laws()
+{
+    let quaternion = |rng: &mut StdRng| {
+        let mut part = || rng.gen_range(-10.0, 10.0);
+        Q::new(part(), part(), part(), part())
+    };
+    Laws::default().ring(quaternion, Approximate(Tolerance::absolute(1e-9))).unwrap();
+}
+
+#[test]
+fn test_cross_product()
+{
+    let space: EuclideanSpace<f64> = EuclideanSpace::new();
+    let (x, y, z) = (euclidean(1.0, 0.0, 0.0), euclidean(0.0, 1.0, 0.0), euclidean(0.0, 0.0, 1.0));
+    assert_vapprox_eq!(space, space.cross(&x, &y), z);
+    assert_vapprox_eq!(space, space.cross(&y, &z), x);
+    assert_vapprox_eq!(space, space.cross(&y, &x), space.additive_inv(&z));
+
+    let a: Euclidean<f64> = euclidean(1.0, 2.0, 3.0);
+    let b: Euclidean<f64> = euclidean(-4.0, 0.5, 2.0);
+    let c: Euclidean<f64> = space.cross(&a, &b);
+    assert_vapprox_eq!(space, c, euclidean(2.5, -14.0, 8.5));
+    assert_eq!(space.dotv(&a, &c), 0.0);
+    assert_eq!(space.dotv(&b, &c), 0.0);
+
+    // Over the integers too, as only the ring operations are needed.
+    let integers: EuclideanSpace<i64> = EuclideanSpace::new();
+    let product: Euclidean<i64> = integers.cross(&vec![2, 0, 0].into_iter().collect(), &vec![0, 3, 0].into_iter().collect());
+    assert!(integers.eq(&product, &vec![0, 0, 6].into_iter().collect()));
+}
+
+#[test]
+fn test_rotations()
+{
+    let space: EuclideanSpace<f64> = EuclideanSpace::new();
+    let quarter: Q = Q::from_axis_angle(&space, &euclidean(0.0, 0.0, 2.0), FRAC_PI_2).unwrap();
+    assert_vapprox_eq!(space, quarter.rotate(&space, &euclidean(1.0, 0.0, 0.0)), euclidean(0.0, 1.0, 0.0), Tolerance::absolute(1e-15));
+    assert_eq!(Q::from_axis_angle(&space, &euclidean(0.0, 0.0, 0.0), 1.0), None);
+
+    let (axis, angle): (Euclidean<f64>, f64) = quarter.to_axis_angle(&space);
+    assert_vapprox_eq!(space, axis, euclidean(0.0, 0.0, 1.0));
+    assert!((angle - FRAC_PI_2).abs() < 1e-15);
+    let (axis, angle): (Euclidean<f64>, f64) = Q::multiplicative_identity().to_axis_angle(&space);
+    assert_vapprox_eq!(space, axis, euclidean(1.0, 0.0, 0.0));
+    assert_eq!(angle, 0.0);
+
+    // Composition, rotation matrices and cross products agree.
+    let mut rng: StdRng = algebra::laws::SeedableRng::seed_from_u64(7);
+    for _ in 0..32 {
+        let (p, q): (Q, Q) = (random_rotation(&mut rng), random_rotation(&mut rng));
+        let a: Euclidean<f64> = euclidean(rng.gen(), rng.gen(), rng.gen());
+        let b: Euclidean<f64> = euclidean(rng.gen(), rng.gen(), rng.gen());
+        let tolerance: Tolerance<f64> = Tolerance::absolute(1e-12);
+
+        assert_vapprox_eq!(space, (p * q).rotate(&space, &a), p.rotate(&space, &q.rotate(&space, &a)), tolerance);
+        assert_vapprox_eq!(space, q.rotate(&space, &space.cross(&a, &b)), space.cross(&q.rotate(&space, &a), &q.rotate(&space, &b)), tolerance);
+        assert!((space.dotv(&q.rotate(&space, &a), &q.rotate(&space, &a)) - space.dotv(&a, &a)).abs() < 1e-12);
+
+        let matrix: [[f64; 3]; 3] = q.to_rotation_matrix();
+        let multiplied: Euclidean<f64> = matrix.iter().map(|row| row.iter().zip(&a).map(|(m, v)| m * v).sum()).collect();
+        assert_vapprox_eq!(space, multiplied, q.rotate(&space, &a), tolerance);
+        assert_vapprox_eq!(space, (-q).rotate(&space, &a), q.rotate(&space, &a), tolerance);
+
+        let (axis, angle): (Euclidean<f64>, f64) = q.to_axis_angle(&space);
+        assert!(close(&Q::from_axis_angle(&space, &axis, angle).unwrap(), &q));
+    }
+}
+
+#[test]
+fn test_euler_angles()
+{
+    let (roll, pitch, yaw): (f64, f64, f64) = (0.3, -0.7, 2.1);
+    let q: Q = Q::from_euler(roll, pitch, yaw);
+    let space: EuclideanSpace<f64> = EuclideanSpace::new();
+    let about = |x: f64, y: f64, z: f64, angle: f64| Q::from_axis_angle(&space, &euclidean(x, y, z), angle).unwrap();
+    assert!(close(&q, &(about(0.0, 0.0, 1.0, yaw) * about(0.0, 1.0, 0.0, pitch) * about(1.0, 0.0, 0.0, roll))));
+
+    let (r, p, y): (f64, f64, f64) = q.to_euler();
+    assert!((r - roll).abs() < 1e-12 && (p - pitch).abs() < 1e-12 && (y - yaw).abs() < 1e-12);
+
+    // Gimbal lock: only the pitch is determined.
+    let (_, p, _): (f64, f64, f64) = Q::from_euler(0.4, FRAC_PI_2, -0.2).to_euler();
+    assert!((p - FRAC_PI_2).abs() < 1e-6);
+}
+
+#[test]
+fn test_slerp()
+{
+    let space: EuclideanSpace<f64> = EuclideanSpace::new();
+    let start: Q = Q::multiplicative_identity();
+    let end: Q = Q::from_axis_angle(&space, &euclidean(0.0, 0.0, 1.0), FRAC_PI_2).unwrap();
+
+    assert!(close(&start.slerp(&end, 0.0), &start));
+    assert!(close(&start.slerp(&end, 1.0), &end));
+    let half: Q = start.slerp(&end, 0.5);
+    assert!(close(&half, &Q::from_axis_angle(&space, &euclidean(0.0, 0.0, 1.0), FRAC_PI_4).unwrap()));
+    for &t in &[0.1, 0.25, 0.8] {
+        let (_, angle): (Euclidean<f64>, f64) = start.slerp(&end, t).to_axis_angle(&space);
+        assert!((angle - t * FRAC_PI_2).abs() < 1e-12);
+    }
+
+    // The shorter arc is taken even when the signs disagree.
+    let (_, angle): (Euclidean<f64>, f64) = start.slerp(&-end, 0.5).to_axis_angle(&space);
+    assert!((angle - FRAC_PI_4).abs() < 1e-12 || (angle - (2.0 * PI - FRAC_PI_4)).abs() < 1e-12);
+    assert_vapprox_eq!(space, start.slerp(&-end, 0.5).rotate(&space, &euclidean(1.0, 0.0, 0.0)), half.rotate(&space, &euclidean(1.0, 0.0, 0.0)));
+
+    let nearby: Q = Q::from_axis_angle(&space, &euclidean(1.0, 0.0, 0.0), 1e-9).unwrap();
+    assert!((start.slerp(&nearby, 0.5).norm() - 1.0).abs() < 1e-15);
+}
+
+#[test]
+fn test_four_vectors()
+{
+    let space: QuaternionSpace<f64> = QuaternionSpace::new();
+    let coordinates: Coordinates<f64> = vec![1.0, 2.0, 3.0, 4.0].into_iter().collect();
+    let q: Q = Q::from_vector(&space, &coordinates);
+    assert_eq!(q, Q::new(1.0, 2.0, 3.0, 4.0));
+    assert!(space.eq(&q.to_vector(&space), &coordinates));
+    assert_eq!(space.dotv(&coordinates, &coordinates), q.norm_squared());
+    assert_eq!(q.vector_part(&EuclideanSpace::new())[2], 4.0);
+    assert_eq!(Q::pure(&EuclideanSpace::new(), &euclidean(2.0, 3.0, 4.0)), Q::new(0.0, 2.0, 3.0, 4.0));
+}
@@ -0,0 +1,175 @@
+use algebra::*;
+use algebra::laws::{Exact, Laws, Rng, StdRng};
+use vector::decomp::{self, DecompError, Rref};
+use vector::vspace;
+
+vspace! {
+    RowSpace {
+        vector: Row,
+        dimension: 4
+    }
+}
+
+vspace! {
+    WideSpace {
+        vector: Wide,
+        dimension: 5
+    }
+}
+
+type Q = Rational<i64>;
+
+fn q(numerator: i64, denominator: i64) -> Q
+{
+    Rational::new(numerator, denominator)
+}
+
+fn small(rng: &mut StdRng) -> Q
+{
+    q(rng.gen_range(-20, 20), rng.gen_range(1, 20))
+}
+
+/// The Hilbert matrix `1 / (i + j + 1)`, notoriously ill-conditioned.
+fn hilbert() -> Vec<Row<Q>>
+{
+    (0..4).map(|i| (0..4).map(|j| q(1, i + j + 1)).collect()).collect()
+}
+
+#[test]
+fn test_rational_arithmetic()
+{
+    assert_eq!(q(1, 2) + q(1, 3), q(5, 6));
+    assert_eq!(q(1, 2) - q(5, 6), q(-1, 3));
+    assert_eq!(q(4, -6), q(-2, 3));
+    assert_eq!(*q(4, -6).denominator(), 3);
+    assert_eq!(q(3, 4) * q(2, 9), q(1, 6));
+    assert_eq!(q(3, 4) / q(-9, 8), q(-2, 3));
+    assert_eq!(q(7, 2).trunc(), q(3, 1));
+    assert_eq!(q(-7, 2).trunc(), q(-3, 1));
+    assert_eq!(format!("{} {:?}", q(-6, 4), q(8, 4)), "-3/2 2");
+
+    assert!(q(1, 3) < q(1, 2));
+    assert!(q(-1, 2) < q(-1, 3));
+    assert!(q(355, 113) < q(22, 7));
+    assert!(q(i64::MAX, i64::MAX - 1) < q(i64::MAX - 1, i64::MAX - 2));
+
+    // Cross-cancelling keeps the intermediate products in range.
+    let r: Rational<i8> = Rational::new(100, 3) * Rational::new(3, 100);
+    assert_eq!(r, Rational::integer(1));
+    assert_eq!(Rational::<i8>::new(100, 1).checked_add(&Rational::new(100, 1)), None);
+    assert_eq!(Rational::<u8>::new(1, 2).checked_neg(), None);
+    assert_eq!(q(1, 2).checked_div(&Q::additive_identity()), None);
+
+    let big: BigRational = (0..100).fold(BigRational::integer(BigInt::from(1)), |acc, _| acc * BigRational::new(BigInt::from(3), BigInt::from(2)));
+    let power = |base: i64| (0..100).fold(BigInt::from(1), |acc, _| acc * base);
+    assert_eq!(*big.numerator(), power(3));
+    assert_eq!(*big.denominator(), power(2));
+}
+
+#[test]
+#[should_panic(expected = "overflow")]
+fn test_rational_overflow_panics()
+{
+    let _ = Rational::<i8>::new(1, 127) + Rational::new(1, 126);
+}
+
+#[test]
+fn test_rational_laws()
+{
+    Laws::default().field(small, Exact).unwrap();
+    Laws::default()
+        .field(|rng| BigRational::new(BigInt::from(rng.gen_range(-1000i64, 1000)), BigInt::from(rng.gen_range(1i64, 1000))), Exact)
+        .unwrap();
+
+    let space: RowSpace<Q> = RowSpace::new();
+    Laws::default().vector_space(&space, |rng| (0..4).map(|_| small(rng)).collect(), small, Exact).unwrap();
+}
+
+#[test]
+fn test_unsigned_rationals_are_a_semiring()
+{
+    // No `Ring` bound here: unsigned rationals have no additive inverses.
+    let space: RowSpace<Rational<u32>> = RowSpace::new();
+    let x: Row<Rational<u32>> = (1..=4).map(|k| Rational::new(1, k)).collect();
+    let y: Row<Rational<u32>> = (1..=4).map(Rational::integer).collect();
+    assert_eq!(space.dotv(&x, &y), Rational::integer(4));
+    assert_eq!(space.vscale(&x, &Rational::integer(12)).view().to_vec(), vec![12, 6, 4, 3].into_iter().map(Rational::integer).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_rref_rank_and_nullspace()
+{
+    let space: WideSpace<Q> = WideSpace::new();
+    let rows: Vec<Wide<Q>> = [
+        [1, 2, 0, 3, -1],
+        [2, 4, 1, 7, 0],
+        [-1, -2, 1, -2, 3]
+    ]
+    .iter()
+    .map(|row| row.iter().map(|&k| q(k, 1)).collect())
+    .collect();
+
+    let reduced: Rref<Q> = decomp::rref(&rows).unwrap();
+    assert_eq!(reduced.rank(), 2);
+    assert_eq!(reduced.pivots(), &[0, 2]);
+    let echelon: Vec<Wide<Q>> = reduced.rows();
+    assert!(space.eq(&echelon[0], &[1, 2, 0, 3, -1].iter().map(|&k| q(k, 1)).collect()));
+    assert!(echelon[2].into_iter().all(|val| *val == Q::additive_identity()));
+
+    let kernel: Vec<Wide<Q>> = decomp::nullspace(&rows).unwrap();
+    assert_eq!(kernel.len(), 3);
+    for x in &kernel {
+        assert!(rows.iter().all(|row| space.dotv(row, x) == Q::additive_identity()));
+    }
+}
+
+#[test]
+fn test_exact_inverse()
+{
+    let space: RowSpace<Q> = RowSpace::new();
+    let a: Vec<Row<Q>> = hilbert();
+    let inverse: Vec<Row<Q>> = decomp::exact_inverse(&a).unwrap();
+    assert_eq!(inverse[0][0], q(16, 1));
+    assert_eq!(inverse[3][3], q(2800, 1));
+
+    // Column `j` of the product is the dot product of each row with column `j` of the inverse.
+    for (i, row) in a.iter().enumerate() {
+        for j in 0..4 {
+            let column: Row<Q> = inverse.iter().map(|r| r[j].clone()).collect();
+            let expected: Q = if i == j { Q::multiplicative_identity() } else { Q::additive_identity() };
+            assert_eq!(space.dotv(row, &column), expected);
+        }
+    }
+
+    let singular: Vec<Row<Q>> = vec![a[0].clone(), a[1].clone(), a[0].clone(), a[3].clone()];
+    assert_eq!(decomp::exact_inverse(&singular).unwrap_err(), DecompError::Singular { pivot: 3 });
+    assert_eq!(decomp::exact_inverse(&a[..3]).unwrap_err(), DecompError::NotSquare { rows: 3, columns: 4 });
+}
+
+#[test]
+fn test_fraction_free_determinant()
+{
+    assert_eq!(decomp::exact_det(&hilbert()).unwrap(), q(1, 6_048_000));
+
+    let integers: Vec<Row<i64>> = [
+        [0, 2, 1, 3],
+        [4, -1, 0, 2],
+        [1, 1, 1, 1],
+        [2, 0, -3, 5]
+    ]
+    .iter()
+    .map(|row| row.iter().copied().collect())
+    .collect();
+    assert_eq!(decomp::exact_det(&integers).unwrap(), 26);
+
+    let residues = |rows: &[Row<i64>]| -> Vec<Row<Gf<7>>> {
+        rows.iter().map(|row| row.into_iter().map(|&k| Gf::from(k)).collect()).collect()
+    };
+    assert_eq!(decomp::exact_det(&residues(&integers)).unwrap(), Gf::new(5));
+
+    // Invertible over the rationals, but singular modulo 7.
+    let scaled: Vec<Row<i64>> = (0..4).map(|i| (0..4).map(|j| if i != j { 0 } else if i == 3 { 7 } else { 1 }).collect()).collect();
+    assert_eq!(decomp::exact_det(&scaled).unwrap(), 7);
+    assert_eq!(decomp::exact_det(&residues(&scaled)).unwrap(), Gf::new(0));
+    assert_eq!(decomp::rref(&residues(&scaled)).unwrap().rank(), 3);
+}
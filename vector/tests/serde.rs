@@ -0,0 +1,73 @@
+#![cfg(feature = "serde")]
+
+use algebra::*;
+use vector::{mspace, vspace, Dense};
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 4
+    }
+}
+
+vspace! {
+    ArraySpace {
+        vector: ArrayState,
+        dimension: 40,
+        using: [T; 40]
+    }
+}
+
+mspace! {
+    MatrixSpace {
+        matrix: Matrix,
+        rows: 2,
+        columns: 3
+    }
+}
+
+#[test]
+fn test_json_round_trip()
+{
+    let x: State<f64> = State::new(vec![1.0, -2.5, 0.0, 4.0]);
+    let json: String = serde_json::to_string(&x).unwrap();
+    assert_eq!(json, "[1.0,-2.5,0.0,4.0]");
+    let y: State<f64> = serde_json::from_str(&json).unwrap();
+    assert_eq!(y.view().to_vec(), x.view().to_vec());
+
+    let psi: State<Complex<f64>> = (0..4).map(|k| Complex::new(k as f64, -(k as f64))).collect();
+    let json: String = serde_json::to_string(&psi).unwrap();
+    let phi: State<Complex<f64>> = serde_json::from_str(&json).unwrap();
+    assert_eq!(phi.view().to_vec(), psi.view().to_vec());
+
+    let a: Matrix<i32> = Matrix::new(vec![1, 2, 3, 4, 5, 6]);
+    let b: Matrix<i32> = serde_json::from_str(&serde_json::to_string(&a).unwrap()).unwrap();
+    assert_eq!(b.as_slice(), a.as_slice());
+
+    let space: StateSpace<f64> = serde_json::from_str(&serde_json::to_string(&StateSpace::<f64>::new()).unwrap()).unwrap();
+    assert_eq!(space.dotv(&x, &x), 1.0 + 6.25 + 16.0);
+}
+
+#[test]
+fn test_bincode_round_trip()
+{
+    let x: ArrayState<u64> = (0..40).map(|k| k * k).collect();
+    let bytes: Vec<u8> = bincode::serialize(&x).unwrap();
+    let y: ArrayState<u64> = bincode::deserialize(&bytes).unwrap();
+    assert_eq!(y.view().to_vec(), x.view().to_vec());
+
+    let psi: State<Complex<f64>> = (0..4).map(|k| Complex::new(0.5 * k as f64, 1.0)).collect();
+    let phi: State<Complex<f64>> = bincode::deserialize(&bincode::serialize(&psi).unwrap()).unwrap();
+    assert_eq!(phi.view().to_vec(), psi.view().to_vec());
+}
+
+#[test]
+fn test_wrong_dimension_is_an_error()
+{
+    let error = serde_json::from_str::<State<f64>>("[1.0, 2.0, 3.0]").unwrap_err();
+    assert!(error.to_string().contains("a sequence of 4 entries"), "{}", error);
+    assert!(serde_json::from_str::<Matrix<f64>>("[1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0]").is_err());
+
+    let bytes: Vec<u8> = bincode::serialize(&vec![1u64; 39]).unwrap();
+    assert!(bincode::deserialize::<ArrayState<u64>>(&bytes).is_err());
+}
@@ -0,0 +1,182 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use algebra::*;
+use vector::{mspace, vspace};
+use vector::sparse::{CooMatrix, CscMatrix, CsrMatrix, SparseError};
+
+mod common;
+use common::{assert_close, random_reals};
+
+mspace! {
+    RectangularSpace {
+        matrix: Rectangular,
+        rows: 8,
+        columns: 6
+    }
+}
+
+mspace! {
+    TransposeSpace {
+        matrix: Transposed,
+        rows: 6,
+        columns: 8
+    }
+}
+
+vspace! {
+    InputSpace {
+        vector: Input,
+        dimension: 6
+    }
+}
+
+vspace! {
+    OutputSpace {
+        vector: Output,
+        dimension: 8
+    }
+}
+
+fn random_coo(rng: &mut StdRng) -> CooMatrix<f64>
+{
+    let triplets: Vec<(usize, usize, f64)> = (0..20)
+        .map(|_| (rng.gen_range(0, 8), rng.gen_range(0, 6), rng.gen_range(-1.0, 1.0)))
+        .collect();
+    CooMatrix::from_triplets(8, 6, triplets).unwrap()
+}
+
+/// Sums duplicate triplets by hand.
+fn dense_reference(coo: &CooMatrix<f64>) -> Rectangular<f64>
+{
+    let mut entries: Vec<f64> = vec![0.0; 48];
+    for &(i, j, val) in coo.entries() {
+        entries[i * 6 + j] += val;
+    }
+    Rectangular::new(entries)
+}
+
+#[test]
+fn test_conversions_sum_duplicates()
+{
+    let coo = CooMatrix::from_triplets(3, 4, vec![(0, 1, 1.0), (2, 3, 2.0), (0, 1, 4.0), (1, 0, -1.0)]).unwrap();
+    let csr: CsrMatrix<f64> = coo.to_csr();
+    assert_eq!(csr.nnz(), 3);
+    assert_eq!(csr.offsets(), &[0, 1, 2, 3]);
+    assert_eq!(csr.indices(), &[1, 0, 3]);
+    assert_eq!(csr.values(), &[5.0, -1.0, 2.0]);
+    assert_eq!(csr.get(0, 1), Some(&5.0));
+    assert_eq!(csr.get(2, 2), None);
+
+    let csc: CscMatrix<f64> = coo.to_csc();
+    assert_eq!(csc.offsets(), &[0, 1, 2, 2, 3]);
+    assert_eq!(csc.indices(), &[1, 0, 2]);
+    assert_eq!(csc, csr.to_csc());
+    assert_eq!(csc.to_csr(), csr);
+    assert_eq!(csr.to_coo().to_csr(), csr);
+    assert_eq!(csc.to_coo().to_csc(), csc);
+}
+
+#[test]
+fn test_out_of_bounds_entry()
+{
+    let mut coo: CooMatrix<f64> = CooMatrix::new(2, 3);
+    assert!(coo.push(1, 2, 1.0).is_ok());
+    assert_eq!(coo.push(2, 0, 1.0), Err(SparseError::OutOfBounds { row: 2, column: 0 }));
+    assert_eq!(coo.nnz(), 1);
+}
+
+#[test]
+fn test_gemv_matches_dense()
+{
+    let mut rng = StdRng::seed_from_u64(40);
+    let coo: CooMatrix<f64> = random_coo(&mut rng);
+    let dense: Rectangular<f64> = dense_reference(&coo);
+    let x: Input<f64> = random_reals(&mut rng, 6);
+    let y: Output<f64> = random_reals(&mut rng, 8);
+    let (alpha, beta) = (1.5, -0.5);
+
+    let expected: Output<f64> = dense.gemv(&alpha, &x, &beta, &y);
+    let csr: Output<f64> = coo.to_csr().gemv(&alpha, &x, &beta, &y);
+    let csc: Output<f64> = coo.to_csc().gemv(&alpha, &x, &beta, &y);
+    assert_close(&csr, &expected, 1e-12);
+    assert_close(&csc, &expected, 1e-12);
+
+    let mut output: Output<f64> = y.clone();
+    coo.to_csr().gemv_mut(&1.0, &x, &0.0, &mut output);
+    let product: Output<f64> = (0..8).map(|i| (0..6).map(|j| dense[(i, j)] * x[j]).sum()).collect();
+    assert_close(&output, &product, 1e-12);
+}
+
+#[test]
+fn test_transpose()
+{
+    let mut rng = StdRng::seed_from_u64(41);
+    let coo: CooMatrix<f64> = random_coo(&mut rng);
+    let dense: Rectangular<f64> = dense_reference(&coo);
+    let expected: Transposed<f64> = (0..48).map(|idx| dense[(idx % 8, idx / 8)]).collect();
+
+    let csr: Transposed<f64> = coo.to_csr().transpose().to_dense();
+    let csc: Transposed<f64> = coo.to_csc().transpose().to_dense();
+    assert_close(&csr, &expected, 1e-12);
+    assert_close(&csc, &expected, 1e-12);
+    assert_eq!(coo.to_csr().transpose().transpose(), coo.to_csr());
+
+    // Aᵀ maps the output space back to the input space.
+    let y: Output<f64> = (0..8).map(|i| i as f64).collect();
+    let x: Input<f64> = (0..6).map(|_| 0.0).collect();
+    let back: Input<f64> = coo.to_csr().transpose().gemv(&1.0, &y, &0.0, &x);
+    let reference: Input<f64> = expected.gemv(&1.0, &y, &0.0, &x);
+    assert_close(&back, &reference, 1e-12);
+}
+
+#[test]
+fn test_add_and_scale()
+{
+    let mut rng = StdRng::seed_from_u64(42);
+    let (lhs, rhs) = (random_coo(&mut rng), random_coo(&mut rng));
+    let space = RectangularSpace::new();
+    let expected: Rectangular<f64> = space.vadd(&dense_reference(&lhs), &space.vscale(&dense_reference(&rhs), &3.0));
+
+    let sum: CsrMatrix<f64> = lhs.to_csr().add(&rhs.to_csr().scale(&3.0)).unwrap();
+    assert_close(&sum.to_dense::<Rectangular<f64>>(), &expected, 1e-12);
+    assert!(sum.indices().len() <= lhs.nnz() + rhs.nnz());
+
+    let sum: CscMatrix<f64> = lhs.to_csc().add(&rhs.to_csc().scale(&3.0)).unwrap();
+    assert_close(&sum.to_dense::<Rectangular<f64>>(), &expected, 1e-12);
+
+    let square: CsrMatrix<f64> = CooMatrix::new(6, 6).to_csr();
+    assert_eq!(
+        lhs.to_csr().add(&square),
+        Err(SparseError::DimensionMismatch { expected: (8, 6), found: (6, 6) })
+    );
+}
+
+#[test]
+fn test_complex_gemv()
+{
+    // The Pauli Y matrix on the second of two qubits.
+    let i = Complex::new(0.0, 1.0);
+    let coo = CooMatrix::from_triplets(4, 4, vec![(0, 2, -i), (1, 3, -i), (2, 0, i), (3, 1, i)]).unwrap();
+    let x: Vec<Complex<f64>> = (0..4).map(|k| Complex::new(k as f64, 1.0)).collect();
+    let zero: Vec<Complex<f64>> = vec![Complex::new(0.0, 0.0); 4];
+    let y: Vec<Complex<f64>> = coo.to_csr().gemv(&Complex::new(1.0, 0.0), &x, &Complex::new(0.0, 0.0), &zero);
+    assert_eq!(y, vec![-i * x[2], -i * x[3], i * x[0], i * x[1]]);
+}
+
+#[test]
+#[should_panic(expected = "a 8×6 matrix cannot map a vector of length 8 to one of length 8")]
+fn test_gemv_rejects_mismatched_lengths()
+{
+    let coo: CooMatrix<f64> = random_coo(&mut StdRng::seed_from_u64(41));
+    let (x, y): (Vec<f64>, Vec<f64>) = (vec![1.0; 8], vec![0.0; 8]);
+    coo.to_csc().gemv(&1.0, &x, &0.0, &y);
+}
+
+#[test]
+#[should_panic(expected = "x needs an entry for every column")]
+fn test_dense_gemv_rejects_mismatched_lengths()
+{
+    let coo: CooMatrix<f64> = random_coo(&mut StdRng::seed_from_u64(42));
+    let (x, y): (Vec<f64>, Vec<f64>) = (vec![1.0; 8], vec![0.0; 8]);
+    coo.to_csr().to_dense::<Rectangular<f64>>().gemv(&1.0, &x, &0.0, &y);
+}
@@ -0,0 +1,131 @@
+use std::ops::{Add, Mul, Neg, Sub};
+
+use algebra::*;
+use vector::vspace;
+
+vspace! {
+    LatticeSpace {
+        vector: Lattice,
+        dimension: 3
+    }
+}
+
+/// The Gaussian integers `a + bi`, a ring that is not one of the primitives.
+#[derive(Clone, Debug, PartialEq)]
+struct Gaussian(i64, i64);
+
+impl Add for Gaussian
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self
+    {
+        Gaussian(self.0 + rhs.0, self.1 + rhs.1)
+    }
+}
+
+impl Sub for Gaussian
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self
+    {
+        self + -rhs
+    }
+}
+
+impl Mul for Gaussian
+{
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self
+    {
+        Gaussian(self.0 * rhs.0 - self.1 * rhs.1, self.0 * rhs.1 + self.1 * rhs.0)
+    }
+}
+
+impl Neg for Gaussian
+{
+    type Output = Self;
+
+    fn neg(self) -> Self
+    {
+        Gaussian(-self.0, -self.1)
+    }
+}
+
+impl AdditiveIdentity for Gaussian
+{
+    type Output = Self;
+
+    fn additive_identity() -> Self
+    {
+        Gaussian(0, 0)
+    }
+}
+
+impl MultiplicativeIdentity for Gaussian
+{
+    type Output = Self;
+
+    fn multiplicative_identity() -> Self
+    {
+        Gaussian(1, 0)
+    }
+}
+
+structure!{clone [] Gaussian: Ring}
+
+fn sum<T: Monoid>(values: &[T]) -> T
+{
+    values.iter().fold(T::additive_identity(), |mut acc, val| { acc += val; acc })
+}
+
+#[test]
+fn test_primitive_structures()
+{
+    assert_eq!(sum(&[1u8, 2, 3]), 6);
+    assert_eq!(sum(&[1.5f64, -0.5]), 1.0);
+    assert_eq!(sum(&[Complex::new(1.0f32, 2.0), Complex::new(-1.0, 1.0)]), Complex::new(0.0, 3.0));
+}
+
+#[test]
+fn test_user_ring_in_vspace()
+{
+    let space: LatticeSpace<Gaussian> = LatticeSpace::new();
+    let x: Lattice<Gaussian> = vec![Gaussian(1, 0), Gaussian(0, 1), Gaussian(2, -1)].into_iter().collect();
+    let y: Lattice<Gaussian> = vec![Gaussian(0, 1), Gaussian(1, 1), Gaussian(-1, 0)].into_iter().collect();
+
+    let z: Lattice<Gaussian> = space.vadd(&x, &y);
+    assert!(space.eq(&z, &vec![Gaussian(1, 1), Gaussian(1, 2), Gaussian(1, -1)].into_iter().collect()));
+
+    let i: Lattice<Gaussian> = space.vscale(&x, &Gaussian(0, 1));
+    assert!(space.eq(&i, &vec![Gaussian(0, 1), Gaussian(-1, 0), Gaussian(1, 2)].into_iter().collect()));
+
+    let neg: Lattice<Gaussian> = space.additive_inv(&x);
+    assert!(space.eq(&space.vadd(&neg, &x), &vec![Gaussian(0, 0); 3].into_iter().collect()));
+
+    // (1)(i) + (i)(1 + i) + (2 - i)(-1) = i + (i - 1) + (-2 + i) = -3 + 3i
+    assert_eq!(space.dotv(&x, &y), Gaussian(-3, 3));
+
+    let axpy: Lattice<Gaussian> = space.vaxpy(&Gaussian(2, 0), &x, &y);
+    assert!(space.eq(&axpy, &vec![Gaussian(2, 1), Gaussian(1, 3), Gaussian(3, -2)].into_iter().collect()));
+}
+
+#[test]
+fn test_unsigned_semiring_in_vspace()
+{
+    // Unsigned primitives have no negation, but scaling and dot products only
+    // need a semiring.
+    let space: LatticeSpace<u64> = LatticeSpace::new();
+    let x: Lattice<u64> = vec![1, 2, 3].into_iter().collect();
+    let y: Lattice<u64> = vec![4, 5, 6].into_iter().collect();
+
+    assert_eq!(space.dotv(&x, &y), 32);
+    assert!(space.eq(&space.vscale(&x, &3), &vec![3, 6, 9].into_iter().collect()));
+    assert!(space.eq(&space.vaxpy(&2, &x, &y), &vec![6, 9, 12].into_iter().collect()));
+
+    let mut output: u64 = 1;
+    space.dotv_mut(&x, &y, &mut output);
+    assert_eq!(output, 33);
+}
@@ -0,0 +1,191 @@
+use rand::{rngs::StdRng, SeedableRng};
+
+use algebra::*;
+use vector::{decomp, mspace, Dense};
+use vector::decomp::DecompError;
+
+mod common;
+use common::{random_complexes, random_reals};
+
+const TOLERANCE: f64 = 1e-9;
+
+type Factors<T> = decomp::SVD<Vec<T>, Vec<T>, <T as ComplexField>::Real>;
+
+mspace! {
+    TallSpace {
+        matrix: Tall,
+        rows: 5,
+        columns: 3,
+        using: [T; 15]
+    }
+}
+
+mspace! {
+    WideSpace {
+        matrix: Wide,
+        rows: 3,
+        columns: 5
+    }
+}
+
+mspace! {
+    SquareSpace {
+        matrix: Square,
+        dimension: 4
+    }
+}
+
+fn assert_orthonormal<T>(vectors: &[Vec<T>])
+where
+    T: ComplexField
+{
+    for (i, x) in vectors.iter().enumerate() {
+        for (j, y) in vectors.iter().enumerate() {
+            let inner: T = x.iter()
+                .zip(y)
+                .map(|(a, b)| a.conjugate() * *b)
+                .fold(T::additive_identity(), |acc, val| acc + val);
+            let expected: f64 = if i == j { 1.0 } else { 0.0 };
+            assert!((inner - T::from_real(T::Real::from_f64(expected))).modulus().to_f64() < TOLERANCE);
+        }
+    }
+}
+
+/// Checks `A = UΣV*` over the leading singular triplets.
+fn assert_reconstructs<M>(a: &M, svd: &Factors<M::Scalar>)
+where
+    M: Dense,
+    M::Scalar: ComplexField
+{
+    let (m, n) = (a.rows(), a.columns());
+    for i in 0..m {
+        for j in 0..n {
+            let entry: M::Scalar = svd.values()
+                .iter()
+                .zip(svd.left().iter().zip(svd.right()))
+                .map(|(val, (u, v))| (u[i] * v[j].conjugate()).scale(*val))
+                .fold(M::Scalar::additive_identity(), |acc, val| acc + val);
+            let residual = (entry - a.as_slice()[i * n + j]).modulus().to_f64();
+            assert!(residual < TOLERANCE, "Residual: {}", residual);
+        }
+    }
+}
+
+fn assert_values(values: &[f64], expected: &nalgebra::DVector<f64>)
+{
+    let mut expected: Vec<f64> = expected.iter().copied().collect();
+    expected.sort_by(|l, r| r.partial_cmp(l).unwrap());
+    assert_eq!(values.len(), expected.len());
+    for (l, r) in values.iter().zip(expected.iter()) {
+        assert!((l - r).abs() < TOLERANCE, "Expected: {:?}, Got: {:?}", expected, values);
+    }
+}
+
+#[test]
+fn test_real_tall_against_nalgebra()
+{
+    let mut rng = StdRng::seed_from_u64(20);
+    for _ in 0..10 {
+        let a: Tall<f64> = random_reals(&mut rng, 15);
+        let svd: decomp::SVD<Vec<f64>, Vec<f64>, f64> = decomp::svd(&a).unwrap();
+        assert_eq!((svd.left().len(), svd.right().len()), (3, 3));
+        assert_orthonormal(svd.left());
+        assert_orthonormal(svd.right());
+        assert_reconstructs(&a, &svd);
+
+        let reference = nalgebra::DMatrix::from_row_slice(5, 3, a.as_slice()).svd(false, false);
+        assert_values(svd.values(), &reference.singular_values);
+    }
+}
+
+#[test]
+fn test_complex_wide_against_nalgebra()
+{
+    let mut rng = StdRng::seed_from_u64(21);
+    for _ in 0..10 {
+        let a: Wide<Complex<f64>> = random_complexes(&mut rng, 15);
+        let svd: decomp::SVD<Vec<Complex<f64>>, Vec<Complex<f64>>, f64> = decomp::svd(&a).unwrap();
+        assert_eq!((svd.left().len(), svd.right().len()), (3, 3));
+        assert_orthonormal(svd.left());
+        assert_orthonormal(svd.right());
+        assert_reconstructs(&a, &svd);
+
+        let reference = nalgebra::DMatrix::from_row_slice(3, 5, a.as_slice()).svd(false, false);
+        assert_values(svd.values(), &reference.singular_values);
+    }
+}
+
+#[test]
+fn test_full_completes_bases()
+{
+    let mut rng = StdRng::seed_from_u64(22);
+    let tall: Tall<Complex<f64>> = random_complexes(&mut rng, 15);
+    let svd: decomp::SVD<Vec<Complex<f64>>, Vec<Complex<f64>>, f64> = decomp::svd_full(&tall).unwrap();
+    assert_eq!((svd.values().len(), svd.left().len(), svd.right().len()), (3, 5, 3));
+    assert_orthonormal(svd.left());
+    assert_reconstructs(&tall, &svd);
+
+    let wide: Wide<f64> = random_reals(&mut rng, 15);
+    let svd: decomp::SVD<Vec<f64>, Vec<f64>, f64> = decomp::svd_full(&wide).unwrap();
+    assert_eq!((svd.values().len(), svd.left().len(), svd.right().len()), (3, 3, 5));
+    assert_orthonormal(svd.right());
+    assert_reconstructs(&wide, &svd);
+}
+
+#[test]
+fn test_rank_deficient_matrix()
+{
+    // Rows 2 and 3 are combinations of rows 0 and 1.
+    let mut rng = StdRng::seed_from_u64(23);
+    let mut entries: Vec<f64> = random_reals(&mut rng, 16);
+    for j in 0..4 {
+        entries[8 + j] = 2.0 * entries[j];
+        entries[12 + j] = entries[j] - 3.0 * entries[4 + j];
+    }
+    let a: Square<f64> = entries.into_iter().collect();
+
+    let svd: decomp::SVD<Vec<f64>, Vec<f64>, f64> = decomp::svd(&a).unwrap();
+    assert_eq!(svd.rank(1e-10), 2);
+    assert_eq!(decomp::rank(&a, 1e-10), Ok(2));
+    assert_orthonormal(svd.left());
+    assert_reconstructs(&a, &svd);
+    assert!(decomp::cond(&a).unwrap() > 1e10);
+
+    let reference = nalgebra::DMatrix::from_row_slice(4, 4, a.as_slice()).svd(false, false);
+    assert_values(svd.values(), &reference.singular_values);
+}
+
+#[test]
+fn test_pinv_and_cond()
+{
+    let mut rng = StdRng::seed_from_u64(24);
+    let a: Tall<Complex<f64>> = random_complexes(&mut rng, 15);
+    let pinv: Wide<Complex<f64>> = decomp::pinv(&a, 1e-12).unwrap();
+
+    // A⁺A = I for a matrix of full column rank.
+    for i in 0..3 {
+        for j in 0..3 {
+            let entry: Complex<f64> = (0..5).map(|k| pinv[(i, k)] * a[(k, j)]).sum();
+            let expected = if i == j { Complex::new(1.0, 0.0) } else { Complex::new(0.0, 0.0) };
+            assert!((entry - expected).norm() < TOLERANCE);
+        }
+    }
+
+    let reference = nalgebra::DMatrix::from_row_slice(5, 3, a.as_slice()).svd(false, false);
+    let max = reference.singular_values.max();
+    let min = reference.singular_values.min();
+    let cond: f64 = decomp::cond(&a).unwrap();
+    assert!((cond - max / min).abs() < 1e-8, "Expected: {}, Got: {}", max / min, cond);
+
+    let identity: Square<f64> = (0..16).map(|idx| if idx / 4 == idx % 4 { 1.0 } else { 0.0 }).collect();
+    assert_eq!(decomp::cond(&identity), Ok(1.0));
+}
+
+#[test]
+fn test_rejects_non_finite()
+{
+    let mut rng = StdRng::seed_from_u64(25);
+    let mut a: Wide<f64> = random_reals(&mut rng, 15);
+    a[(1, 4)] = f64::INFINITY;
+    assert_eq!(decomp::cond(&a), Err(DecompError::NotFinite { row: 1, column: 4 }));
+}
@@ -1,3 +1,5 @@
+#![allow(clippy::let_with_type_underscore)]
+
 macro_rules! test {
     ($name:ident, $object:ty, $space:ty) => {
         mod $name {
@@ -118,14 +120,24 @@ macro_rules! test {
                 let exp: _ = 55;
                 assert_eq!(&test, &exp);
             }
+
+            #[test]
+            fn test_laws()
+            {
+                let vector_space = <$space>::new();
+                Laws::default()
+                    .module(&vector_space, |rng| (0..3).map(|_| rng.gen_range(-99, 99)).collect(), |rng| rng.gen_range(-99, 99), Exact)
+                    .unwrap();
+            }
         }
     };
 }
 
-use vector::{vspace, BinOps, Implements};
+use vector::vspace;
 
 use algebra::*;
 use algebra::{vadd, vscale};
+use algebra::laws::{Exact, Laws, Rng};
 
 vspace! {
     VectorSpaceArray {
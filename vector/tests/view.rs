@@ -0,0 +1,106 @@
+use algebra::*;
+use vector::{vspace, VectorView, VectorViewMut};
+
+vspace! {
+    StateSpace {
+        vector: State,
+        dimension: 8
+    }
+}
+
+vspace! {
+    BlockSpace {
+        vector: Block,
+        dimension: 4
+    }
+}
+
+fn state() -> State<f64>
+{
+    (0..8).map(|i| i as f64).collect()
+}
+
+#[test]
+fn test_slicing_and_strides()
+{
+    let x: State<f64> = state();
+
+    assert_eq!(x.slice(2..5).to_vec(), vec![2.0, 3.0, 4.0]);
+    assert_eq!(x.slice(..).step_by(3).to_vec(), vec![0.0, 3.0, 6.0]);
+    assert_eq!(x.slice(1..).step_by(2).slice(1..=2).to_vec(), vec![3.0, 5.0]);
+    assert!(x.slice(8..).is_empty());
+
+    let odd: VectorView<f64> = x.view().slice(1..).step_by(2);
+    assert_eq!(odd.len(), 4);
+    assert_eq!(odd[3], 7.0);
+    assert_eq!(odd.get(4), None);
+
+    let (head, tail) = odd.split_at(1);
+    assert_eq!((head.to_vec(), tail.to_vec()), (vec![1.0], vec![3.0, 5.0, 7.0]));
+
+    let chunks: Vec<Vec<f64>> = x.chunks(3).map(|chunk| chunk.to_vec()).collect();
+    assert_eq!(chunks, vec![vec![0.0, 1.0, 2.0], vec![3.0, 4.0, 5.0], vec![6.0, 7.0]]);
+}
+
+#[test]
+#[should_panic]
+fn test_out_of_range_slice()
+{
+    let x: State<f64> = state();
+    let _ = x.slice(4..9);
+}
+
+#[test]
+fn test_disjoint_mutable_halves()
+{
+    let mut x: State<f64> = state();
+    {
+        let (mut low, mut high) = x.split_at_mut(4);
+        for (l, h) in low.iter_mut().zip(high.iter_mut()) {
+            std::mem::swap(l, h);
+        }
+    }
+    assert_eq!(x.view().to_vec(), vec![4.0, 5.0, 6.0, 7.0, 0.0, 1.0, 2.0, 3.0]);
+
+    for (k, mut chunk) in x.chunks_mut(3).enumerate() {
+        chunk[0] = -(k as f64);
+    }
+    assert_eq!(x.view().to_vec(), vec![0.0, 5.0, 6.0, -1.0, 0.0, 1.0, -2.0, 3.0]);
+
+    let mut even: VectorViewMut<f64> = x.view_mut().step_by_mut(2);
+    even[1] = 10.0;
+    assert_eq!(x[2], 10.0);
+}
+
+#[test]
+fn test_blas_on_views()
+{
+    let space = StateSpace::new();
+    let blocks = BlockSpace::new();
+    let x: State<f64> = state();
+    let mut y: State<f64> = (0..8).map(|_| 1.0).collect();
+
+    // Operate on the upper block with the space of the smaller vectors.
+    blocks.vaxpy_mut(&2.0, &x.slice(..4), &mut y.slice_mut(4..));
+    assert_eq!(y.view().to_vec(), vec![1.0, 1.0, 1.0, 1.0, 1.0, 3.0, 5.0, 7.0]);
+
+    // Owned vectors, views and strided views mix freely.
+    assert_eq!(space.dotv(&x, &y), space.dotv(&x.view(), &y.view()));
+    assert_eq!(blocks.dotv(&x.view().step_by(2), &x.slice(1..).step_by(2)), 0.0 * 1.0 + 2.0 * 3.0 + 4.0 * 5.0 + 6.0 * 7.0);
+
+    let mut output: f64 = 1.0;
+    blocks.dotv_mut(&x.slice(4..), &[1.0, 1.0, 1.0, 1.0][..], &mut output);
+    assert_eq!(output, 1.0 + 4.0 + 5.0 + 6.0 + 7.0);
+
+    let sum: Block<f64> = blocks.vaxpy(&-1.0, &x.slice(..4), &x.slice(4..));
+    assert_eq!(sum.view().to_vec(), vec![4.0, 4.0, 4.0, 4.0]);
+}
+
+#[test]
+#[should_panic]
+fn test_blas_rejects_mismatched_views()
+{
+    let space = StateSpace::new();
+    let x: State<f64> = state();
+    let _ = space.dotv(&x, &x.slice(1..));
+}